@@ -0,0 +1,219 @@
+use crate::analyses::project_map::{ProjectCatalog, ProjectInfo};
+use crate::analyses::translations::CATALOG_DIRS;
+use crate::analysis::resolvers::style_resolver::{css_urls, resolve_url};
+use crate::analysis::utils::path_utils::{is_ignored_dir_component, normalize_path};
+use crate::ng::models::NgAnalysisResults;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    Template,
+    Style,
+    Image,
+    Font,
+    Media,
+    /// A JSON module under the project's `src/`.
+    Json,
+}
+
+impl AssetKind {
+    fn of(path: &Path) -> Option<AssetKind> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "html" => Some(AssetKind::Template),
+            "css" | "scss" | "sass" | "less" => Some(AssetKind::Style),
+            "png" | "jpg" | "jpeg" | "gif" | "svg" | "webp" | "avif" | "ico" | "bmp" => {
+                Some(AssetKind::Image)
+            }
+            "woff" | "woff2" | "ttf" | "otf" | "eot" => Some(AssetKind::Font),
+            "mp4" | "webm" | "mp3" | "wav" | "ogg" => Some(AssetKind::Media),
+            "json" => Some(AssetKind::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrphanAsset {
+    pub file: PathBuf,
+    pub project: String,
+    pub kind: AssetKind,
+}
+
+/// Templates, stylesheets, images, fonts, media files and JSON modules
+/// nothing references.
+///
+/// References start at component `templateUrl`/`styleUrl(s)`, the `index`,
+/// `styles` and `assets` of `project.json` targets (a directory covers
+/// everything inside), `index.html` files, `public/` directories and script
+/// imports of such files. From there SCSS `@use`/`@forward`/`@import` and
+/// URLs in templates (`src`, `href`, `srcset`) and styles (`url(...)`) are
+/// followed — only out of files that are themselves referenced, so a
+/// stylesheet kept alive by an orphan is an orphan too.
+pub fn find_orphan_assets(
+    results: &NgAnalysisResults,
+    catalog: &ProjectCatalog,
+) -> Vec<OrphanAsset> {
+    let mut candidates: BTreeMap<PathBuf, (String, AssetKind)> = BTreeMap::new();
+    let mut covered_dirs: Vec<PathBuf> = Vec::new();
+    let mut work: Vec<PathBuf> = Vec::new();
+
+    for project in catalog.projects() {
+        covered_dirs.push(project.root.join("public"));
+        for reference in &project.asset_refs {
+            if reference.is_dir() {
+                covered_dirs.push(reference.clone());
+            } else {
+                work.push(reference.clone());
+            }
+        }
+        for file in project_assets(project) {
+            if catalog.project_of(&file).map(|p| &p.name) != Some(&project.name) {
+                continue; // A nested project's file.
+            }
+            if file.file_name().is_some_and(|name| name == "index.html") {
+                work.push(file.clone());
+            }
+            match AssetKind::of(&file) {
+                Some(AssetKind::Json) if !is_json_module(project, &file) => {}
+                Some(kind) => {
+                    candidates.insert(file, (project.name.clone(), kind));
+                }
+                None => {}
+            }
+        }
+    }
+
+    for component in &results.components {
+        let dir = component.base.source_path.parent().unwrap_or(Path::new(""));
+        if !component.template_path.is_empty() {
+            work.push(normalize_path(dir.join(&component.template_path)));
+        }
+        for style in &component.style_paths {
+            work.push(normalize_path(dir.join(style)));
+        }
+        if let Some(template) = &component.template_inline {
+            work.extend(html_references(template, dir));
+        }
+    }
+    for file in &results.source_files {
+        let dir = file.path.parent().unwrap_or(Path::new(""));
+        let resolved = file
+            .imports
+            .iter()
+            .chain(&file.dynamic_imports)
+            .chain(&file.url_imports)
+            .map(|import| import.resolved_path.clone());
+        let unresolved = file
+            .unresolved_imports
+            .iter()
+            .filter(|import| import.specifier.starts_with('.'))
+            .map(|import| normalize_path(dir.join(&import.specifier)));
+        work.extend(
+            resolved
+                .chain(unresolved)
+                .filter(|path| AssetKind::of(path).is_some()),
+        );
+    }
+
+    let style_files: HashMap<&Path, _> = results
+        .style_files
+        .iter()
+        .map(|style| (style.path.as_path(), style))
+        .collect();
+    let mut referenced: HashSet<PathBuf> = HashSet::new();
+    while let Some(file) = work.pop() {
+        if !referenced.insert(file.clone()) {
+            continue;
+        }
+        let dir = file.parent().unwrap_or(Path::new("")).to_path_buf();
+        let next = match AssetKind::of(&file) {
+            Some(AssetKind::Template) => std::fs::read_to_string(&file)
+                .map(|html| html_references(&html, &dir))
+                .unwrap_or_default(),
+            Some(AssetKind::Style) => style_files
+                .get(file.as_path())
+                .map(|style| {
+                    let imports = style.imports.iter().filter_map(|i| i.resolved_path.clone());
+                    imports.chain(style.urls.iter().cloned()).collect()
+                })
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        work.extend(next);
+    }
+
+    candidates
+        .into_iter()
+        .filter(|(file, _)| {
+            !referenced.contains(file) && !covered_dirs.iter().any(|dir| file.starts_with(dir))
+        })
+        .map(|(file, (project, kind))| OrphanAsset {
+            file,
+            project,
+            kind,
+        })
+        .collect()
+}
+
+/// JSON next to the sources is data a script imports. Elsewhere it is tool
+/// configuration (`project.json`, `tsconfig.*.json`, `.eslintrc.json`);
+/// translation catalogs are loaded by path at run time.
+fn is_json_module(project: &ProjectInfo, file: &Path) -> bool {
+    file.starts_with(project.root.join("src"))
+        && !file
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with("tsconfig"))
+        && !file.ancestors().skip(1).any(|dir| {
+            dir.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| CATALOG_DIRS.contains(&name))
+        })
+}
+
+/// Non-script files under a project root, skipping hidden dirs, build
+/// output and `node_modules`.
+fn project_assets(project: &ProjectInfo) -> Vec<PathBuf> {
+    WalkDir::new(crate::nx::nx_workspace::walkable_root(&project.root))
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || !is_ignored_dir_component(entry.file_name()))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| normalize_path(entry.path()))
+        .filter(|path| AssetKind::of(path).is_some())
+        .collect()
+}
+
+/// `src`, `href` and `srcset` attribute values and inline `url(...)`.
+fn html_references(html: &str, dir: &Path) -> Vec<PathBuf> {
+    let mut urls = Vec::new();
+    for attribute in ["src=", "href=", "srcset="] {
+        let mut rest = html;
+        while let Some(pos) = rest.find(attribute) {
+            let preceded_by_space = rest[..pos].chars().last().is_some_and(char::is_whitespace);
+            rest = &rest[pos + attribute.len()..];
+            let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+                continue;
+            };
+            let Some(end) = rest[1..].find(quote) else {
+                break;
+            };
+            if preceded_by_space {
+                let value = &rest[1..end + 1];
+                if attribute == "srcset=" {
+                    urls.extend(value.split(',').filter_map(|c| c.split_whitespace().next()));
+                } else {
+                    urls.push(value);
+                }
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+    urls.extend(css_urls(html));
+    urls.into_iter()
+        .filter_map(|url| resolve_url(url, dir))
+        .collect()
+}
@@ -1,22 +1,54 @@
 use crate::analyses::project_map::ProjectCatalog;
-use crate::analyses::stats::ProjectDependencyInfo;
+use crate::analyses::stats::{DependencyKind, ProjectDependencyInfo};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
-/// NX-style tag boundary rules, read from `nx-analyzer.json` at the workspace
+/// Workspace configuration, read from `nx-analyzer.json` at the workspace
 /// root (legacy name `ng-analyzer.json` still accepted):
 ///
 /// ```json
 /// {
 ///   "boundaries": [
 ///     { "sourceTag": "type:ui", "allowedTags": ["type:ui", "type:util"] }
-///   ]
+///   ],
+///   "componentOpeners": ["openSidePanel"],
+///   "styleAliases": { "@org/theme/*": ["libs/theme/src/styles/*"] }
 /// }
 /// ```
 #[derive(Debug, Deserialize, Default)]
 pub struct AnalyzerConfig {
+    /// NX-style tag boundary rules.
     #[serde(default)]
     pub boundaries: Vec<BoundaryRule>,
+    /// Extra functions/methods that create a component passed as their first
+    /// argument, on top of [`DEFAULT_COMPONENT_OPENERS`].
+    #[serde(default, rename = "componentOpeners")]
+    pub component_openers: Vec<String>,
+    /// tsconfig-`paths`-shaped aliases for stylesheet `@use`/`@import`,
+    /// relative to the workspace root. Consulted before tsconfig `paths`.
+    #[serde(default, rename = "styleAliases")]
+    pub style_aliases: HashMap<String, Vec<String>>,
+}
+
+/// `ViewContainerRef.createComponent(X)` and the standalone
+/// `createComponent(X, ...)`, `MatDialog`/`Dialog`/`NgbModal.open(X)`,
+/// `MatSnackBar.openFromComponent(X)` and `new ComponentPortal(X)`.
+pub const DEFAULT_COMPONENT_OPENERS: &[&str] = &[
+    "createComponent",
+    "open",
+    "openFromComponent",
+    "ComponentPortal",
+];
+
+impl AnalyzerConfig {
+    pub fn component_openers(&self) -> Vec<String> {
+        DEFAULT_COMPONENT_OPENERS
+            .iter()
+            .map(|name| name.to_string())
+            .chain(self.component_openers.iter().cloned())
+            .collect()
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -31,6 +63,8 @@ pub struct BoundaryRule {
 pub struct BoundaryViolation {
     pub from: String,
     pub to: String,
+    /// `Style` when the dependency is a stylesheet import.
+    pub kind: DependencyKind,
     pub source_tag: String,
     pub allowed_tags: Vec<String>,
     pub to_tags: Vec<String>,
@@ -86,6 +120,7 @@ pub fn check_boundaries(
                 violations.push(BoundaryViolation {
                     from: from.name.clone(),
                     to: to.name.clone(),
+                    kind: dep.kind,
                     source_tag: rule.source_tag.clone(),
                     allowed_tags: rule.allowed_tags.clone(),
                     to_tags: to.tags.clone(),
@@ -94,7 +129,8 @@ pub fn check_boundaries(
         }
     }
 
-    violations
-        .sort_by(|a, b| (&a.from, &a.to, &a.source_tag).cmp(&(&b.from, &b.to, &b.source_tag)));
+    violations.sort_by(|a, b| {
+        (&a.from, &a.to, &a.source_tag, a.kind).cmp(&(&b.from, &b.to, &b.source_tag, b.kind))
+    });
     violations
 }
@@ -0,0 +1,158 @@
+use crate::analyses::project_map::{is_test_file, ProjectCatalog};
+use crate::analysis::models::file_facts::{FileFactsInfo, ModuleDirective};
+use crate::ng::models::NgAnalysisResults;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+
+/// Node built-ins Next.js has no browser fallback for. A `node:` specifier
+/// fails in a client bundle whatever the module.
+const SERVER_BUILTINS: &[&str] = &[
+    "async_hooks",
+    "child_process",
+    "cluster",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "fs",
+    "http2",
+    "inspector",
+    "module",
+    "net",
+    "perf_hooks",
+    "readline",
+    "repl",
+    "tls",
+    "trace_events",
+    "v8",
+    "worker_threads",
+];
+
+/// Server code pulled into the client bundle: a module a `'use client'` file
+/// reaches through its imports that cannot run in the browser. Each one
+/// fails `next build`.
+#[derive(Debug, Serialize)]
+pub struct ClientBoundaryViolation {
+    pub kind: ClientBoundaryKind,
+    /// `server-only`, the built-in (`fs`, `node:crypto`), or the declaration
+    /// holding the inline server action.
+    pub target: String,
+    pub file: PathBuf,
+    pub project: String,
+    /// Imports leading from a `'use client'` file to `file`, both included.
+    pub chain: Vec<PathBuf>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ClientBoundaryKind {
+    /// The module imports `server-only`.
+    ServerOnly,
+    /// The module imports a Node built-in.
+    NodeBuiltin,
+    /// A function with its own `'use server'` in client code — only a
+    /// `'use server'` file may hand server actions to the client.
+    InlineServerAction,
+}
+
+/// Walks static and dynamic imports from every `'use client'` file, stopping
+/// at `'use server'` files — the client gets references to their actions,
+/// not their code. Type-only imports are erased and not followed. Each
+/// violation shows the shortest chain from some client file.
+pub fn check_client_boundaries(
+    results: &NgAnalysisResults,
+    catalog: &ProjectCatalog,
+) -> Vec<ClientBoundaryViolation> {
+    let files: HashMap<&Path, &FileFactsInfo> = results
+        .source_files
+        .iter()
+        .filter(|file| !is_test_file(&file.path))
+        .map(|file| (file.path.as_path(), file))
+        .collect();
+    let mut entries: Vec<&Path> = files
+        .values()
+        .filter(|file| file.directive == Some(ModuleDirective::UseClient))
+        .map(|file| file.path.as_path())
+        .collect();
+    if entries.is_empty() {
+        return Vec::new();
+    }
+    entries.sort();
+
+    // Multi-source BFS: each file's parent is where it was first reached.
+    let mut parent: HashMap<&Path, Option<&Path>> =
+        entries.iter().map(|entry| (*entry, None)).collect();
+    let mut queue: VecDeque<&Path> = entries.into_iter().collect();
+    let mut violations = Vec::new();
+    while let Some(path) = queue.pop_front() {
+        let file = files[path];
+        if file.directive == Some(ModuleDirective::UseServer) {
+            continue;
+        }
+        let chain = || {
+            let mut chain = vec![path.to_path_buf()];
+            let mut current = path;
+            while let Some(Some(previous)) = parent.get(current) {
+                chain.push(previous.to_path_buf());
+                current = previous;
+            }
+            chain.reverse();
+            chain
+        };
+        let mut found = |kind, target: &str| {
+            violations.push(ClientBoundaryViolation {
+                kind,
+                target: target.to_string(),
+                file: path.to_path_buf(),
+                project: catalog
+                    .project_of(path)
+                    .map(|project| project.name.clone())
+                    .unwrap_or_default(),
+                chain: chain(),
+            })
+        };
+
+        let imports = file
+            .imports
+            .iter()
+            .chain(&file.dynamic_imports)
+            .filter(|import| !import.type_only);
+        let mut specifiers: Vec<&str> = imports
+            .clone()
+            .map(|import| import.source.as_str())
+            .chain(
+                file.unresolved_imports
+                    .iter()
+                    .filter(|unresolved| !unresolved.type_only)
+                    .map(|unresolved| unresolved.specifier.as_str()),
+            )
+            .collect();
+        specifiers.sort();
+        specifiers.dedup();
+        for specifier in specifiers {
+            if specifier == "server-only" {
+                found(ClientBoundaryKind::ServerOnly, specifier);
+            } else if is_server_builtin(specifier) {
+                found(ClientBoundaryKind::NodeBuiltin, specifier);
+            }
+        }
+        for action in &file.server_actions {
+            found(ClientBoundaryKind::InlineServerAction, action);
+        }
+
+        for import in imports {
+            let target = import.resolved_path.as_path();
+            if files.contains_key(target) && !parent.contains_key(target) {
+                parent.insert(target, Some(path));
+                queue.push_back(target);
+            }
+        }
+    }
+
+    violations.sort_by(|a, b| (&a.file, a.kind, &a.target).cmp(&(&b.file, b.kind, &b.target)));
+    violations
+}
+
+fn is_server_builtin(specifier: &str) -> bool {
+    specifier.starts_with("node:")
+        || SERVER_BUILTINS.contains(&specifier.split('/').next().unwrap_or_default())
+}
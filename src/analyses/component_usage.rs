@@ -0,0 +1,178 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::analyses::react_usage::PropUseCount;
+use crate::ng::models::NgAnalysisResults;
+use crate::ng::templates::{TemplateTargetKind, TemplateUsageInfo, TemplateUsageVia};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Usage analytics for Angular components — the counterpart of
+/// `ReactComponentUsage`: how often each component is rendered, from which
+/// projects, and which inputs/outputs the templates actually bind.
+#[derive(Debug, Serialize)]
+pub struct ComponentUsage {
+    pub component: String,
+    pub file: PathBuf,
+    pub package_name: String,
+    /// Element occurrences matching the selector across all templates.
+    pub usage_count: usize,
+    /// Occurrences per consuming project.
+    pub by_project: BTreeMap<String, usize>,
+    pub inputs: Vec<PropUseCount>,
+    pub outputs: Vec<PropUseCount>,
+    /// Declared inputs no template binds — removal candidates. Only filled
+    /// for rendered components: a never-rendered one is an `unused` finding.
+    pub unbound_inputs: Vec<String>,
+    pub unbound_outputs: Vec<String>,
+}
+
+/// Adoption of custom elements across frameworks: occurrences of the tag in
+/// Angular templates, JSX and Lit templates.
+#[derive(Debug, Serialize)]
+pub struct CustomElementUsage {
+    pub tag: String,
+    pub name: String,
+    pub file: PathBuf,
+    pub package_name: String,
+    pub usage_count: usize,
+    /// Occurrences per consuming project.
+    pub by_project: BTreeMap<String, usize>,
+    /// Occurrences per consumer: `angular`, `jsx`, `lit`.
+    pub by_framework: BTreeMap<&'static str, usize>,
+}
+
+pub fn analyze_custom_element_usage(
+    results: &NgAnalysisResults,
+    template_usages: &[TemplateUsageInfo],
+    catalog: &ProjectCatalog,
+) -> Vec<CustomElementUsage> {
+    results
+        .custom_elements
+        .iter()
+        .map(|element| {
+            let mut usage = CustomElementUsage {
+                tag: element.tag.clone(),
+                name: element.name.clone(),
+                file: element.source_path.clone(),
+                package_name: element.package_name.clone(),
+                usage_count: 0,
+                by_project: BTreeMap::new(),
+                by_framework: BTreeMap::new(),
+            };
+            let rendering = template_usages.iter().filter(|usage| {
+                usage.target_kind == TemplateTargetKind::CustomElement
+                    && usage.target_path == element.source_path
+                    && usage.target == element.name
+                    // An element rendering itself is not adoption.
+                    && !(usage.component_path == usage.target_path
+                        && usage.component == usage.target)
+            });
+            for template_usage in rendering {
+                let count = template_usage.bindings.len();
+                let project = catalog
+                    .project_of(&template_usage.component_path)
+                    .map(|project| project.name.clone())
+                    .unwrap_or_default();
+                let framework = match template_usage.via {
+                    TemplateUsageVia::Jsx => "jsx",
+                    TemplateUsageVia::LitHtml => "lit",
+                    _ => "angular",
+                };
+                usage.usage_count += count;
+                *usage.by_project.entry(project).or_insert(0) += count;
+                *usage.by_framework.entry(framework).or_insert(0) += count;
+            }
+            usage
+        })
+        .collect()
+}
+
+pub fn analyze_component_usage(
+    results: &NgAnalysisResults,
+    template_usages: &[TemplateUsageInfo],
+    catalog: &ProjectCatalog,
+) -> Vec<ComponentUsage> {
+    // (component file, name) -> every usage record targeting it.
+    let mut by_target: HashMap<(&Path, &str), Vec<&TemplateUsageInfo>> = HashMap::new();
+    for usage in template_usages {
+        if usage.target_kind != TemplateTargetKind::Component {
+            continue;
+        }
+        // A recursive component rendering itself is not adoption.
+        if usage.component_path == usage.target_path && usage.component == usage.target {
+            continue;
+        }
+        by_target
+            .entry((usage.target_path.as_path(), usage.target.as_str()))
+            .or_default()
+            .push(usage);
+    }
+
+    results
+        .components
+        .iter()
+        .map(|component| {
+            let usages = by_target
+                .get(&(
+                    component.base.source_path.as_path(),
+                    component.base.name.as_str(),
+                ))
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            let mut usage_count = 0;
+            let mut by_project: BTreeMap<String, usize> = BTreeMap::new();
+            let mut inputs: BTreeMap<&str, usize> = BTreeMap::new();
+            let mut outputs: BTreeMap<&str, usize> = BTreeMap::new();
+
+            for usage in usages {
+                let project = catalog
+                    .project_of(&usage.component_path)
+                    .map(|project| project.name.clone())
+                    .unwrap_or_default();
+                *by_project.entry(project).or_insert(0) += usage.bindings.len();
+                usage_count += usage.bindings.len();
+                for element in &usage.bindings {
+                    for input in &element.inputs {
+                        *inputs.entry(input).or_insert(0) += 1;
+                    }
+                    for output in &element.outputs {
+                        *outputs.entry(output).or_insert(0) += 1;
+                    }
+                }
+            }
+
+            let unbound = |declared: &[String], bound: &BTreeMap<&str, usize>| {
+                if usage_count == 0 {
+                    return Vec::new();
+                }
+                declared
+                    .iter()
+                    .filter(|name| !bound.contains_key(name.as_str()))
+                    .cloned()
+                    .collect()
+            };
+            let to_counts = |counts: &BTreeMap<&str, usize>| {
+                counts
+                    .iter()
+                    .map(|(name, count)| PropUseCount {
+                        name: name.to_string(),
+                        count: *count,
+                    })
+                    .collect()
+            };
+
+            ComponentUsage {
+                component: component.base.name.clone(),
+                file: component.base.source_path.clone(),
+                package_name: component.base.package_name.clone(),
+                usage_count,
+                by_project,
+                unbound_inputs: unbound(&component.inputs, &inputs),
+                unbound_outputs: unbound(&component.outputs, &outputs),
+                inputs: to_counts(&inputs),
+                outputs: to_counts(&outputs),
+            }
+        })
+        .collect()
+}
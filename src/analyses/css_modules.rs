@@ -0,0 +1,183 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::analysis::models::style::StyleFileInfo;
+use crate::analysis::resolvers::style_resolver::{css_module_classes, is_css_module};
+use crate::ng::models::NgAnalysisResults;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// CSS module classes no script reads, and reads of classes a module does
+/// not define. A module whose binding is used whole or indexed with a
+/// computed key has no unused classes — any of them may be read.
+#[derive(Debug, Serialize, Default)]
+pub struct CssModuleReport {
+    pub unused: Vec<UnusedCssClass>,
+    /// `undefined` at run time — usually a leftover after a rename.
+    pub missing: Vec<MissingCssClass>,
+}
+
+impl CssModuleReport {
+    pub fn is_empty(&self) -> bool {
+        self.unused.is_empty() && self.missing.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnusedCssClass {
+    pub class: String,
+    pub file: PathBuf,
+    pub project: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MissingCssClass {
+    pub class: String,
+    /// The CSS module read.
+    pub module: PathBuf,
+    /// The script reading it.
+    pub file: PathBuf,
+    pub project: String,
+}
+
+pub fn check_css_modules(results: &NgAnalysisResults, catalog: &ProjectCatalog) -> CssModuleReport {
+    let styles: HashMap<&Path, &StyleFileInfo> = results
+        .style_files
+        .iter()
+        .map(|style| (style.path.as_path(), style))
+        .collect();
+
+    // Module → classes read, by reading file, and whether it is read whole.
+    let mut reads: BTreeMap<&Path, (BTreeMap<&str, BTreeSet<&Path>>, bool)> = BTreeMap::new();
+    for file in &results.source_files {
+        for usage in &file.css_modules {
+            let (classes, dynamic) = reads.entry(usage.path.as_path()).or_default();
+            *dynamic |= usage.dynamic;
+            for class in &usage.classes {
+                classes
+                    .entry(class.as_str())
+                    .or_default()
+                    .insert(file.path.as_path());
+            }
+        }
+    }
+    // `composes: x from './other.module.css'` reads `x` of the other module.
+    let mut composed: HashMap<PathBuf, HashSet<&str>> = HashMap::new();
+    for style in &results.style_files {
+        for composes in &style.composes {
+            let module = composes.from.clone().unwrap_or_else(|| style.path.clone());
+            composed
+                .entry(module)
+                .or_default()
+                .insert(composes.class.as_str());
+        }
+    }
+
+    let project_of = |path: &Path| {
+        catalog
+            .project_of(path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default()
+    };
+    let mut report = CssModuleReport::default();
+    for (module, (read, dynamic)) in reads {
+        // Not parsed: not on disk, or in the indented `.sass` syntax.
+        let Some(style) = styles.get(module) else {
+            continue;
+        };
+        if module.extension().is_some_and(|ext| ext == "sass") {
+            continue;
+        }
+
+        let is_read =
+            |class: &str| read.contains_key(class) || read.contains_key(camel_case(class).as_str());
+        if !dynamic {
+            let composed = composed.get(module);
+            for class in &style.classes {
+                if !is_read(class)
+                    && !composed.is_some_and(|composed| composed.contains(class.as_str()))
+                {
+                    report.unused.push(UnusedCssClass {
+                        class: class.clone(),
+                        file: module.to_path_buf(),
+                        project: project_of(module),
+                    });
+                }
+            }
+        }
+
+        // Classes of stylesheets it `@use`s or `@import`s are its own too.
+        let mut defined: HashSet<String> = HashSet::new();
+        included_classes(style, &styles, &mut HashSet::new(), &mut defined);
+        let defined: HashSet<String> = defined
+            .iter()
+            .map(|class| camel_case(class))
+            .chain(defined.iter().cloned())
+            .collect();
+        for (class, files) in read {
+            if defined.contains(class) {
+                continue;
+            }
+            for file in files {
+                report.missing.push(MissingCssClass {
+                    class: class.to_string(),
+                    module: module.to_path_buf(),
+                    file: file.to_path_buf(),
+                    project: project_of(file),
+                });
+            }
+        }
+    }
+
+    report
+        .unused
+        .sort_by(|a, b| (&a.file, &a.class).cmp(&(&b.file, &b.class)));
+    report
+        .missing
+        .sort_by(|a, b| (&a.file, &a.module, &a.class).cmp(&(&b.file, &b.module, &b.class)));
+    report
+}
+
+/// Classes of `style` and, transitively, of the stylesheets it pulls in.
+/// Partials are not CSS modules, so their classes are read from disk.
+fn included_classes(
+    style: &StyleFileInfo,
+    styles: &HashMap<&Path, &StyleFileInfo>,
+    seen: &mut HashSet<PathBuf>,
+    classes: &mut HashSet<String>,
+) {
+    if !seen.insert(style.path.clone()) {
+        return;
+    }
+    if is_css_module(&style.path) {
+        classes.extend(style.classes.iter().cloned());
+    } else if let Ok(content) = std::fs::read_to_string(&style.path) {
+        classes.extend(css_module_classes(&content).0);
+    }
+    for import in &style.imports {
+        if let Some(imported) = import
+            .resolved_path
+            .as_deref()
+            .and_then(|path| styles.get(path))
+        {
+            included_classes(imported, styles, seen, classes);
+        }
+    }
+}
+
+/// `card-title` → `cardTitle`, css-loader's `exportLocalsConvention:
+/// 'camelCase'` name for it.
+fn camel_case(class: &str) -> String {
+    let mut name = String::with_capacity(class.len());
+    let mut upper = false;
+    for c in class.chars() {
+        match c {
+            '-' if !name.is_empty() => upper = true,
+            c if upper => {
+                name.extend(c.to_uppercase());
+                upper = false;
+            }
+            c => name.push(c),
+        }
+    }
+    name
+}
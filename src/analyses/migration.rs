@@ -0,0 +1,308 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::analyses::render_tree::lazy_module_loaders;
+use crate::ng::models::{NgAnalysisResults, NgModuleInfo};
+use crate::ng::templates::{TemplateTargetKind, TemplateUsageInfo, TemplateUsageVia};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// NgModule → standalone migration plan: how far each project already is,
+/// and what every NgModule needs before it can be deleted.
+#[derive(Debug, Serialize, Default)]
+pub struct MigrationReport {
+    pub adoption: Vec<ProjectAdoption>,
+    /// One step per NgModule, leaves first: a module is listed only after
+    /// every workspace module it imports.
+    pub plan: Vec<ModuleMigration>,
+}
+
+impl MigrationReport {
+    /// No NgModules — nothing left to migrate.
+    pub fn is_empty(&self) -> bool {
+        self.plan.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectAdoption {
+    pub project: String,
+    /// Standalone components, directives and pipes.
+    pub standalone: usize,
+    pub non_standalone: usize,
+    /// standalone / (standalone + non_standalone); 1 for projects without
+    /// declarables.
+    pub ratio: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModuleMigration {
+    pub step: usize,
+    pub module: String,
+    pub file: PathBuf,
+    pub project: String,
+    pub declarables: Vec<DeclarableMigration>,
+    /// Workspace NgModules this one imports — migrated in earlier steps.
+    pub depends_on: Vec<String>,
+    /// True when nothing but declarations keeps the module: once they are
+    /// standalone, the module can be deleted.
+    pub deletable: bool,
+    /// What has to move elsewhere first (providers, bootstrap, re-exports,
+    /// `forRoot`/`forChild` imports, `loadChildren` pointing at it).
+    pub blockers: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeclarableMigration {
+    pub name: String,
+    pub kind: String,
+    /// Declared without `standalone: true` (or the Angular 19 default).
+    pub needs_standalone: bool,
+    /// Standalone `imports` the component needs, derived from its template
+    /// usages. Built-in directives and pipes (`ngIf`, `async`, …) are not in
+    /// the selector registry and must still be added by hand.
+    pub imports: Vec<String>,
+}
+
+pub fn plan_migration(
+    results: &NgAnalysisResults,
+    template_usages: &[TemplateUsageInfo],
+    catalog: &ProjectCatalog,
+) -> MigrationReport {
+    if results.modules.is_empty() {
+        return MigrationReport::default();
+    }
+
+    let project_of = |path: &Path| {
+        catalog
+            .project_of(path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default()
+    };
+
+    // --- Adoption per project. ---
+    let mut counts: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    let declarables = results
+        .components
+        .iter()
+        .map(|c| (&c.base.source_path, c.standalone))
+        .chain(
+            results
+                .directives
+                .iter()
+                .map(|d| (&d.base.source_path, d.standalone)),
+        )
+        .chain(
+            results
+                .pipes
+                .iter()
+                .map(|p| (&p.base.source_path, p.standalone)),
+        );
+    for (path, standalone) in declarables {
+        let entry = counts.entry(project_of(path)).or_default();
+        if standalone {
+            entry.0 += 1;
+        } else {
+            entry.1 += 1;
+        }
+    }
+    let adoption = counts
+        .into_iter()
+        .map(|(project, (standalone, non_standalone))| ProjectAdoption {
+            project,
+            standalone,
+            non_standalone,
+            ratio: if standalone + non_standalone == 0 {
+                1.0
+            } else {
+                standalone as f64 / (standalone + non_standalone) as f64
+            },
+        })
+        .collect();
+
+    // --- Template-derived imports per component. ---
+    let mut template_imports: BTreeMap<(&Path, &str), BTreeSet<&str>> = BTreeMap::new();
+    for usage in template_usages {
+        // Dynamically created components and host directives (applied by
+        // the host itself) are not template imports, nor are custom
+        // elements — those need `CUSTOM_ELEMENTS_SCHEMA`.
+        let self_use = usage.component_path == usage.target_path && usage.component == usage.target;
+        let indirect = matches!(
+            usage.via,
+            TemplateUsageVia::Dynamic | TemplateUsageVia::HostDirective
+        );
+        if self_use || indirect || usage.target_kind == TemplateTargetKind::CustomElement {
+            continue;
+        }
+        template_imports
+            .entry((usage.component_path.as_path(), usage.component.as_str()))
+            .or_default()
+            .insert(usage.target.as_str());
+    }
+
+    // Declarable lookup by name; same-project declarations win on collisions.
+    let find_declarable = |name: &str, module: &NgModuleInfo| {
+        let module_project = project_of(&module.base.source_path);
+        let candidates = results
+            .components
+            .iter()
+            .map(|c| (&c.base, c.standalone, "Component"))
+            .chain(
+                results
+                    .directives
+                    .iter()
+                    .map(|d| (&d.base, d.standalone, "Directive")),
+            )
+            .chain(
+                results
+                    .pipes
+                    .iter()
+                    .map(|p| (&p.base, p.standalone, "Pipe")),
+            )
+            .filter(|(base, _, _)| base.name == name)
+            .collect::<Vec<_>>();
+        candidates
+            .iter()
+            .find(|(base, _, _)| project_of(&base.source_path) == module_project)
+            .or_else(|| candidates.first())
+            .copied()
+    };
+
+    let lazy_loaders = lazy_module_loaders(results, catalog);
+
+    let module_names: BTreeSet<&str> = results
+        .modules
+        .iter()
+        .map(|module| module.base.name.as_str())
+        .collect();
+
+    let mut steps: Vec<ModuleMigration> = results
+        .modules
+        .iter()
+        .map(|module| {
+            let declarables = module
+                .declarations
+                .iter()
+                .map(|name| match find_declarable(name, module) {
+                    Some((base, standalone, kind)) => DeclarableMigration {
+                        name: name.clone(),
+                        kind: kind.to_string(),
+                        needs_standalone: !standalone,
+                        imports: template_imports
+                            .get(&(base.source_path.as_path(), base.name.as_str()))
+                            .map(|targets| targets.iter().map(|t| t.to_string()).collect())
+                            .unwrap_or_default(),
+                    },
+                    None => DeclarableMigration {
+                        name: name.clone(),
+                        kind: "Unknown".to_string(),
+                        needs_standalone: true,
+                        imports: Vec::new(),
+                    },
+                })
+                .collect();
+
+            let depends_on: Vec<String> = module
+                .imports_idents
+                .iter()
+                .filter(|name| module_names.contains(name.as_str()) && **name != module.base.name)
+                .cloned()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+
+            let mut blockers = Vec::new();
+            if !module.providers.is_empty() {
+                blockers.push(format!(
+                    "providers: {} (move to a route or application config)",
+                    module.providers.join(", ")
+                ));
+            }
+            // Importers get these through the module today; without it, each
+            // one has to import them itself.
+            let reexports: Vec<&str> = module
+                .exports
+                .iter()
+                .filter(|name| !module.declarations.contains(name))
+                .map(String::as_str)
+                .collect();
+            if !reexports.is_empty() {
+                blockers.push(format!(
+                    "re-exports: {} (add to every importer's imports)",
+                    reexports.join(", ")
+                ));
+            }
+            if !module.configured_imports.is_empty() {
+                blockers.push(format!(
+                    "forRoot/forChild imports: {} (move to provideRouter or importProvidersFrom)",
+                    module.configured_imports.join(", ")
+                ));
+            }
+            if let Some(loaders) =
+                lazy_loaders.get(&(module.base.source_path.as_path(), module.base.name.as_str()))
+            {
+                blockers.push(format!(
+                    "loadChildren target in {} (load a routes array instead)",
+                    loaders
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+            if !module.bootstrap.is_empty() {
+                blockers.push(format!(
+                    "bootstrap: {} (switch to bootstrapApplication)",
+                    module.bootstrap.join(", ")
+                ));
+            }
+
+            ModuleMigration {
+                step: 0,
+                module: module.base.name.clone(),
+                file: module.base.source_path.clone(),
+                project: project_of(&module.base.source_path),
+                declarables,
+                depends_on,
+                deletable: blockers.is_empty(),
+                blockers,
+            }
+        })
+        .collect();
+
+    order_leaves_first(&mut steps);
+    for (index, step) in steps.iter_mut().enumerate() {
+        step.step = index + 1;
+    }
+
+    MigrationReport {
+        adoption,
+        plan: steps,
+    }
+}
+
+/// Stable topological order over `depends_on`: a module comes after every
+/// module it imports. Modules caught in an import cycle are appended in name
+/// order — the cycle has to be broken by hand anyway.
+fn order_leaves_first(steps: &mut Vec<ModuleMigration>) {
+    steps.sort_by(|a, b| (&a.module, &a.file).cmp(&(&b.module, &b.file)));
+
+    let mut ordered: Vec<ModuleMigration> = Vec::with_capacity(steps.len());
+    let mut done: BTreeSet<String> = BTreeSet::new();
+    let mut remaining = std::mem::take(steps);
+
+    loop {
+        // `remaining` stays in name order, so each layer does too.
+        let (layer, blocked): (Vec<_>, Vec<_>) = remaining
+            .into_iter()
+            .partition(|step| step.depends_on.iter().all(|dep| done.contains(dep)));
+        remaining = blocked;
+        if layer.is_empty() {
+            break;
+        }
+        done.extend(layer.iter().map(|step| step.module.clone()));
+        ordered.extend(layer);
+    }
+
+    ordered.extend(remaining);
+    *steps = ordered;
+}
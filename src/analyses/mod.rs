@@ -1,9 +1,23 @@
+pub mod assets;
 pub mod boundaries;
+pub mod client_boundary;
+pub mod component_usage;
+pub mod css_modules;
+pub mod migration;
+pub mod module_imports;
 pub mod move_candidates;
+pub mod nest;
+pub mod ngrx;
 pub mod project_map;
+pub mod providers;
+pub mod react_context;
 pub mod react_usage;
+pub mod render_tree;
 pub mod resolution;
+pub mod selectors;
 pub mod stats;
+pub mod template_members;
+pub mod translations;
 pub mod unused;
 
 use crate::analysis::resolvers::import_graph::ImportGraph;
@@ -11,7 +25,6 @@ use crate::ng::models::NgAnalysisResults;
 use crate::ng::templates::TemplateUsageInfo;
 use project_map::ProjectCatalog;
 use serde::Serialize;
-use std::path::Path;
 
 /// All derived analyses over the collected facts.
 #[derive(Serialize)]
@@ -24,6 +37,34 @@ pub struct AnalysesSection {
     pub boundary_violations: Vec<boundaries::BoundaryViolation>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub react_usage: Vec<react_usage::ReactComponentUsage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub react_hooks: Vec<react_usage::ReactHookUsage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub react_contexts: Vec<react_context::ReactContextUsage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub component_usage: Vec<component_usage::ComponentUsage>,
+    #[serde(skip_serializing_if = "migration::MigrationReport::is_empty")]
+    pub migration: migration::MigrationReport,
+    #[serde(skip_serializing_if = "selectors::SelectorReport::is_empty")]
+    pub selectors: selectors::SelectorReport,
+    #[serde(skip_serializing_if = "providers::ProviderReport::is_empty")]
+    pub providers: providers::ProviderReport,
+    #[serde(skip_serializing_if = "render_tree::RenderTreeReport::is_empty")]
+    pub render_tree: render_tree::RenderTreeReport,
+    #[serde(skip_serializing_if = "template_members::TemplateMemberReport::is_empty")]
+    pub template_members: template_members::TemplateMemberReport,
+    #[serde(skip_serializing_if = "translations::TranslationReport::is_empty")]
+    pub translations: translations::TranslationReport,
+    #[serde(skip_serializing_if = "ngrx::NgRxReport::is_empty")]
+    pub ngrx: ngrx::NgRxReport,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub client_boundaries: Vec<client_boundary::ClientBoundaryViolation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub nest_routes: Vec<nest::NestRoute>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom_element_usage: Vec<component_usage::CustomElementUsage>,
+    #[serde(skip_serializing_if = "css_modules::CssModuleReport::is_empty")]
+    pub css_modules: css_modules::CssModuleReport,
 }
 
 pub fn run_analyses(
@@ -31,15 +72,29 @@ pub fn run_analyses(
     template_usages: &[TemplateUsageInfo],
     import_graph: &ImportGraph,
     catalog: &ProjectCatalog,
-    workspace_root: &Path,
+    config: &boundaries::AnalyzerConfig,
 ) -> AnalysesSection {
     let resolution = resolution::check_resolution(results, catalog);
     let stats = stats::build_stats(results, template_usages, catalog);
     let unused = unused::find_unused(results, template_usages, import_graph, catalog);
     let move_candidates = move_candidates::find_move_candidates(results, catalog);
-    let config = boundaries::load_config(workspace_root);
-    let boundary_violations = boundaries::check_boundaries(&stats.dependencies, catalog, &config);
+    let boundary_violations = boundaries::check_boundaries(&stats.dependencies, catalog, config);
     let react_usage = react_usage::analyze_react_usage(results);
+    let react_hooks = react_usage::analyze_hook_usage(results, catalog);
+    let react_contexts = react_context::analyze_contexts(results);
+    let component_usage =
+        component_usage::analyze_component_usage(results, template_usages, catalog);
+    let migration = migration::plan_migration(results, template_usages, catalog);
+    let selectors = selectors::check_selectors(results, &stats.dependencies, catalog);
+    let providers = providers::check_providers(results, template_usages, catalog);
+    let template_members = template_members::check_template_members(results, catalog);
+    let translations = translations::check_translations(results, &stats.dependencies, catalog);
+    let ngrx = ngrx::check_ngrx(results, catalog);
+    let client_boundaries = client_boundary::check_client_boundaries(results, catalog);
+    let nest_routes = nest::route_inventory(results, catalog);
+    let custom_element_usage =
+        component_usage::analyze_custom_element_usage(results, template_usages, catalog);
+    let css_modules = css_modules::check_css_modules(results, catalog);
 
     AnalysesSection {
         resolution,
@@ -48,5 +103,21 @@ pub fn run_analyses(
         move_candidates,
         boundary_violations,
         react_usage,
+        react_hooks,
+        react_contexts,
+        component_usage,
+        migration,
+        selectors,
+        providers,
+        // Built on demand by the commands showing it (`analyze`,
+        // `render-tree`): it is the costliest section.
+        render_tree: render_tree::RenderTreeReport::default(),
+        template_members,
+        translations,
+        ngrx,
+        client_boundaries,
+        nest_routes,
+        custom_element_usage,
+        css_modules,
     }
 }
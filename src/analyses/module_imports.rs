@@ -0,0 +1,285 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::analysis::models::file_facts::FileFactsInfo;
+use crate::ng::models::{NgAnalysisResults, NgModuleInfo};
+use crate::ng::templates::TemplateUsageInfo;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Serialize)]
+pub struct UnusedModuleImport {
+    /// The importing NgModule.
+    pub module: String,
+    pub file: PathBuf,
+    pub project: String,
+    /// The workspace NgModule in its `imports: [...]` that contributes
+    /// nothing: none of its exported declarables is rendered or referenced by
+    /// the importing module's declarations, none of its providers is used,
+    /// and the importing module does not re-export it. Imports that export
+    /// third-party modules, or register providers or routes through
+    /// `X.forRoot/forChild/forFeature(...)`, are never reported.
+    pub import: String,
+    pub import_file: PathBuf,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReexportModule {
+    pub module: String,
+    pub file: PathBuf,
+    pub project: String,
+    /// Modules it exports — consumers can import these directly.
+    pub reexports: Vec<String>,
+}
+
+/// NgModule `imports` that pull in nothing the module uses, and "shared"
+/// modules that declare and provide nothing and only re-export other
+/// modules. Modules from outside the workspace (`CommonModule`,
+/// `FormsModule`, …) are not checked — their exports are unknown, and so is
+/// the use of a workspace module re-exporting them.
+pub fn check_module_imports(
+    results: &NgAnalysisResults,
+    template_usages: &[TemplateUsageInfo],
+    catalog: &ProjectCatalog,
+) -> (Vec<UnusedModuleImport>, Vec<ReexportModule>) {
+    if results.modules.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let resolver = ModuleResolver {
+        results,
+        catalog,
+        files: results
+            .source_files
+            .iter()
+            .map(|file| (file.path.as_path(), file))
+            .collect(),
+    };
+    let project_of = |path: &Path| resolver.project_of(path);
+
+    let mut unused = Vec::new();
+    let mut reexports = Vec::new();
+    for module in &results.modules {
+        let project = project_of(&module.base.source_path);
+
+        // What the module's declarations render (templates) or reference
+        // (imported names in their files), plus the module file itself.
+        let mut files: BTreeSet<&Path> = BTreeSet::new();
+        files.insert(module.base.source_path.as_path());
+        let mut declarables: HashSet<(&Path, &str)> = HashSet::new();
+        for name in module.declarations.iter().chain(&module.bootstrap) {
+            for base in resolver.declarables_named(name, &project) {
+                files.insert(base.source_path.as_path());
+                declarables.insert((base.source_path.as_path(), base.name.as_str()));
+            }
+        }
+        let mut used_here: HashSet<&str> = template_usages
+            .iter()
+            .filter(|usage| {
+                declarables.contains(&(usage.component_path.as_path(), usage.component.as_str()))
+            })
+            .map(|usage| usage.target.as_str())
+            .collect();
+        for file in &files {
+            if let Some(facts) = resolver.files.get(file) {
+                used_here.extend(facts.used_import_names.iter().map(String::as_str));
+            }
+        }
+        // Module-level providers are injectable anywhere in the project.
+        let used_in_project: HashSet<&str> = results
+            .source_files
+            .iter()
+            .filter(|facts| project_of(&facts.path) == project)
+            .flat_map(|facts| facts.used_import_names.iter().map(String::as_str))
+            .collect();
+
+        for import in &module.imports_idents {
+            if module.exports.contains(import) || module.configured_imports.contains(import) {
+                continue;
+            }
+            let Some(imported) = resolver.resolve(import, module) else {
+                continue;
+            };
+            let mut declarables = BTreeSet::new();
+            let mut external = BTreeSet::new();
+            resolver.exported_declarables(
+                imported,
+                &mut HashSet::new(),
+                &mut declarables,
+                &mut external,
+            );
+            let mut providers = BTreeSet::new();
+            let configured = resolver.providers(imported, &mut HashSet::new(), &mut providers);
+            let contributes = !external.is_empty()
+                || configured
+                || declarables.iter().any(|name| used_here.contains(name))
+                || providers.iter().any(|name| used_in_project.contains(name));
+            if !contributes {
+                unused.push(UnusedModuleImport {
+                    module: module.base.name.clone(),
+                    file: module.base.source_path.clone(),
+                    project: project.clone(),
+                    import: import.clone(),
+                    import_file: imported.base.source_path.clone(),
+                });
+            }
+        }
+
+        let exported_modules: Vec<String> = module
+            .exports
+            .iter()
+            .filter(|name| resolver.resolve(name, module).is_some())
+            .cloned()
+            .collect();
+        let only_reexports = module.declarations.is_empty()
+            && module.providers.is_empty()
+            && module.bootstrap.is_empty()
+            && !exported_modules.is_empty()
+            && exported_modules.len() == module.exports.len();
+        if only_reexports {
+            reexports.push(ReexportModule {
+                module: module.base.name.clone(),
+                file: module.base.source_path.clone(),
+                project,
+                reexports: exported_modules,
+            });
+        }
+    }
+
+    unused.sort_by(|a, b| (&a.file, &a.module, &a.import).cmp(&(&b.file, &b.module, &b.import)));
+    reexports.sort_by(|a, b| (&a.file, &a.module).cmp(&(&b.file, &b.module)));
+    (unused, reexports)
+}
+
+struct ModuleResolver<'a> {
+    results: &'a NgAnalysisResults,
+    catalog: &'a ProjectCatalog,
+    files: HashMap<&'a Path, &'a FileFactsInfo>,
+}
+
+impl<'a> ModuleResolver<'a> {
+    fn project_of(&self, path: &Path) -> String {
+        self.catalog
+            .project_of(path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Components, directives and pipes called `name`; same-project
+    /// declarations win on collisions.
+    fn declarables_named(
+        &self,
+        name: &str,
+        project: &str,
+    ) -> Vec<&'a crate::ng::models::ng_base::NgBaseInfo> {
+        let candidates: Vec<_> = self
+            .results
+            .components
+            .iter()
+            .map(|c| &c.base)
+            .chain(self.results.directives.iter().map(|d| &d.base))
+            .chain(self.results.pipes.iter().map(|p| &p.base))
+            .filter(|base| base.name == name)
+            .collect();
+        let local: Vec<_> = candidates
+            .iter()
+            .copied()
+            .filter(|base| self.project_of(&base.source_path) == project)
+            .collect();
+        if local.is_empty() {
+            candidates
+        } else {
+            local
+        }
+    }
+
+    /// The workspace NgModule `name` refers to in `from`'s file: through the
+    /// file's imports (barrels resolve to the target project), else declared
+    /// in the same file. `None` for third-party modules.
+    fn resolve(&self, name: &str, from: &NgModuleInfo) -> Option<&'a NgModuleInfo> {
+        let named = |name: &str| {
+            let name = name.to_string();
+            self.results
+                .modules
+                .iter()
+                .filter(move |m| m.base.name == name)
+        };
+        let facts = self.files.get(from.base.source_path.as_path());
+        let import = facts.and_then(|facts| {
+            facts
+                .imports
+                .iter()
+                .find(|import| import.imported_item.name == name)
+        });
+        match import {
+            Some(import) => {
+                let exported = import
+                    .imported_item
+                    .alias
+                    .as_deref()
+                    .unwrap_or(&import.imported_item.name);
+                let project = self.project_of(&import.resolved_path);
+                named(exported)
+                    .find(|m| m.base.source_path == import.resolved_path)
+                    .or_else(|| {
+                        named(exported).find(|m| self.project_of(&m.base.source_path) == project)
+                    })
+            }
+            None => named(name).find(|m| m.base.source_path == from.base.source_path),
+        }
+    }
+
+    /// Declarables a consumer of `module` can use: its exports, and
+    /// transitively whatever its exported modules export. Exports imported
+    /// from packages (`CommonModule`) go to `external`: what they export is
+    /// unknown.
+    fn exported_declarables(
+        &self,
+        module: &'a NgModuleInfo,
+        seen: &mut HashSet<(&'a Path, &'a str)>,
+        out: &mut BTreeSet<&'a str>,
+        external: &mut BTreeSet<&'a str>,
+    ) {
+        if !seen.insert((module.base.source_path.as_path(), module.base.name.as_str())) {
+            return;
+        }
+        let package_imports = self
+            .files
+            .get(module.base.source_path.as_path())
+            .map(|facts| facts.package_imports.as_slice())
+            .unwrap_or_default();
+        for name in &module.exports {
+            match self.resolve(name, module) {
+                Some(exported) => self.exported_declarables(exported, seen, out, external),
+                None if package_imports.contains(name) => {
+                    external.insert(name.as_str());
+                }
+                None => {
+                    out.insert(name.as_str());
+                }
+            }
+        }
+    }
+
+    /// Providers importing `module` registers: its own and, transitively,
+    /// those of every workspace module it imports or exports. True when one
+    /// of them imports an `X.forRoot/forChild/forFeature(...)` call, whose
+    /// providers or routes are not known by name.
+    fn providers(
+        &self,
+        module: &'a NgModuleInfo,
+        seen: &mut HashSet<(&'a Path, &'a str)>,
+        out: &mut BTreeSet<&'a str>,
+    ) -> bool {
+        if !seen.insert((module.base.source_path.as_path(), module.base.name.as_str())) {
+            return false;
+        }
+        out.extend(module.providers.iter().map(String::as_str));
+        let mut configured = !module.configured_imports.is_empty();
+        for name in module.imports_idents.iter().chain(&module.exports) {
+            if let Some(next) = self.resolve(name, module) {
+                configured |= self.providers(next, seen, out);
+            }
+        }
+        configured
+    }
+}
@@ -0,0 +1,66 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::ng::models::NgAnalysisResults;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// An HTTP endpoint a NestJS controller serves.
+#[derive(Debug, Serialize)]
+pub struct NestRoute {
+    pub method: String,
+    /// Controller path joined with the handler's, without the app's global
+    /// prefix: `/users/:id`.
+    pub path: String,
+    pub controller: String,
+    pub handler: String,
+    /// Module listing the controller in `controllers: [...]`; `None` when
+    /// no module does — Nest never serves the route.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module: Option<String>,
+    pub file: PathBuf,
+    pub project: String,
+}
+
+pub fn route_inventory(results: &NgAnalysisResults, catalog: &ProjectCatalog) -> Vec<NestRoute> {
+    let mut routes = Vec::new();
+    for controller in &results.nest_controllers {
+        let name = &controller.base.name;
+        let project = catalog
+            .project_of(&controller.base.source_path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default();
+        // Modules register classes by name; one in the same project wins.
+        let module = results
+            .nest_modules
+            .iter()
+            .filter(|module| module.controllers.contains(name))
+            .min_by_key(|module| {
+                catalog
+                    .project_of(&module.base.source_path)
+                    .is_none_or(|owner| owner.name != project)
+            })
+            .map(|module| module.base.name.clone());
+        for route in &controller.routes {
+            routes.push(NestRoute {
+                method: route.method.clone(),
+                path: join_path(&controller.path, &route.path),
+                controller: name.clone(),
+                handler: route.handler.clone(),
+                module: module.clone(),
+                file: controller.base.source_path.clone(),
+                project: project.clone(),
+            });
+        }
+    }
+    routes.sort_by(|a, b| (&a.path, &a.method, &a.file).cmp(&(&b.path, &b.method, &b.file)));
+    routes
+}
+
+/// `users` + `:id` → `/users/:id`; two empty paths → `/`.
+fn join_path(prefix: &str, path: &str) -> String {
+    let segments: Vec<&str> = [prefix, path]
+        .iter()
+        .map(|part| part.trim_matches('/'))
+        .filter(|part| !part.is_empty())
+        .collect();
+    format!("/{}", segments.join("/"))
+}
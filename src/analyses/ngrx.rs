@@ -0,0 +1,227 @@
+use crate::analyses::project_map::{is_test_file, ProjectCatalog};
+use crate::analysis::models::file_facts::FileFactsInfo;
+use crate::analysis::models::import::ImportKind;
+use crate::ng::models::ngrx::{NgRxDeclaration, NgRxHandlerKind, NgRxKind};
+use crate::ng::models::NgAnalysisResults;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// NgRx store code that is wired up but never runs: actions declared,
+/// exported and handled by reducers that nothing dispatches, and selectors
+/// nothing selects. Spec files neither dispatch nor select.
+#[derive(Debug, Serialize, Default)]
+pub struct NgRxReport {
+    /// Actions no `dispatch(...)` call and no effect emits.
+    pub undispatched_actions: Vec<NgRxUnused>,
+    /// `on(...)` / `ofType(...)` handlers reacting only to undispatched
+    /// actions — a reducer branch or an effect that never runs.
+    pub dead_handlers: Vec<NgRxDeadHandler>,
+    /// Selectors no `select` call reaches, directly or through the
+    /// selectors it feeds.
+    pub unselected_selectors: Vec<NgRxUnused>,
+}
+
+impl NgRxReport {
+    pub fn is_empty(&self) -> bool {
+        self.undispatched_actions.is_empty()
+            && self.dead_handlers.is_empty()
+            && self.unselected_selectors.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NgRxUnused {
+    pub name: String,
+    pub file: PathBuf,
+    pub project: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NgRxDeadHandler {
+    /// Reducer, feature or effect the handler sits in.
+    pub owner: String,
+    pub kind: NgRxHandlerKind,
+    pub actions: Vec<String>,
+    pub file: PathBuf,
+    pub project: String,
+}
+
+/// A declaration: declaring file and name.
+type Key<'a> = (&'a Path, &'a str);
+
+pub fn check_ngrx(results: &NgAnalysisResults, catalog: &ProjectCatalog) -> NgRxReport {
+    let mut report = NgRxReport::default();
+    let files: Vec<&FileFactsInfo> = results
+        .source_files
+        .iter()
+        .filter(|file| !is_test_file(&file.path))
+        .collect();
+    let declarations: HashMap<Key, (&FileFactsInfo, &NgRxDeclaration)> = files
+        .iter()
+        .flat_map(|file| {
+            file.ngrx.declarations.iter().map(move |declaration| {
+                (
+                    (file.path.as_path(), declaration.name.as_str()),
+                    (*file, declaration),
+                )
+            })
+        })
+        .collect();
+    if declarations.is_empty() {
+        return report;
+    }
+    let resolver = Resolver {
+        by_path: files
+            .iter()
+            .map(|file| (file.path.as_path(), *file))
+            .collect(),
+        declarations: &declarations,
+    };
+
+    let mut dispatched: HashSet<Key> = HashSet::new();
+    let mut work: Vec<Key> = Vec::new();
+    for file in &files {
+        dispatched.extend(
+            file.ngrx
+                .dispatches
+                .iter()
+                .filter_map(|action| resolver.resolve(action, file)),
+        );
+        work.extend(
+            file.ngrx
+                .selections
+                .iter()
+                .filter_map(|selector| resolver.resolve(selector, file)),
+        );
+    }
+    // A selector that is selected keeps the selectors it combines alive.
+    let mut selected: HashSet<Key> = HashSet::new();
+    while let Some(key) = work.pop() {
+        if !selected.insert(key) {
+            continue;
+        }
+        if let Some((file, declaration)) = declarations.get(&key) {
+            work.extend(
+                declaration
+                    .inputs
+                    .iter()
+                    .filter_map(|input| resolver.resolve(input, file)),
+            );
+        }
+    }
+
+    let project_of = |path: &Path| {
+        catalog
+            .project_of(path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default()
+    };
+    for file in &files {
+        for declaration in &file.ngrx.declarations {
+            let key = (file.path.as_path(), declaration.name.as_str());
+            let list = match declaration.kind {
+                NgRxKind::Action if !dispatched.contains(&key) => &mut report.undispatched_actions,
+                NgRxKind::Selector if !selected.contains(&key) => &mut report.unselected_selectors,
+                _ => continue,
+            };
+            list.push(NgRxUnused {
+                name: declaration.name.clone(),
+                file: file.path.clone(),
+                project: project_of(&file.path),
+            });
+        }
+
+        for handler in &file.ngrx.handlers {
+            // Every action must be one of ours, so an unresolved one (a
+            // router or library action) keeps the handler alive.
+            let dead = handler.actions.iter().all(|action| {
+                resolver.resolve(action, file).is_some_and(|key| {
+                    declarations[&key].1.kind == NgRxKind::Action && !dispatched.contains(&key)
+                })
+            });
+            if dead {
+                report.dead_handlers.push(NgRxDeadHandler {
+                    owner: handler.owner.clone(),
+                    kind: handler.kind,
+                    actions: handler.actions.clone(),
+                    file: file.path.clone(),
+                    project: project_of(&file.path),
+                });
+            }
+        }
+    }
+
+    report
+        .undispatched_actions
+        .sort_by(|a, b| (&a.file, &a.name).cmp(&(&b.file, &b.name)));
+    report
+        .unselected_selectors
+        .sort_by(|a, b| (&a.file, &a.name).cmp(&(&b.file, &b.name)));
+    report
+        .dead_handlers
+        .sort_by(|a, b| (&a.file, &a.owner).cmp(&(&b.file, &b.owner)));
+    report
+}
+
+struct Resolver<'a> {
+    by_path: HashMap<&'a Path, &'a FileFactsInfo>,
+    declarations: &'a HashMap<Key<'a>, (&'a FileFactsInfo, &'a NgRxDeclaration)>,
+}
+
+impl<'a> Resolver<'a> {
+    /// The declaration a reference in `file` names: through the file's
+    /// imports (a barrel resolves to the declaring project), else declared
+    /// in the same file.
+    fn resolve(&self, reference: &str, file: &FileFactsInfo) -> Option<Key<'a>> {
+        let (root, rest) = match reference.split_once('.') {
+            Some((root, rest)) => (root, Some(rest)),
+            None => (reference, None),
+        };
+        let Some(import) = file
+            .imports
+            .iter()
+            .find(|import| import.imported_item.name == root)
+        else {
+            return self.find(reference, |path| path == file.path);
+        };
+        let name = match (import.imported_item.import_kind, rest) {
+            (ImportKind::Namespace, Some(rest)) => rest.to_string(),
+            (ImportKind::Namespace, None) => return None,
+            (_, rest) => {
+                let exported = import
+                    .imported_item
+                    .alias
+                    .as_deref()
+                    .unwrap_or(&import.imported_item.name);
+                match rest {
+                    Some(rest) => format!("{exported}.{rest}"),
+                    None => exported.to_string(),
+                }
+            }
+        };
+        let target = import.resolved_path.as_path();
+        let package = self
+            .by_path
+            .get(target)
+            .map(|facts| facts.package_name.as_str());
+        self.find(&name, |path| path == target).or_else(|| {
+            self.find(&name, |path| {
+                package.is_some()
+                    && self
+                        .by_path
+                        .get(path)
+                        .map(|facts| facts.package_name.as_str())
+                        == package
+            })
+        })
+    }
+
+    fn find(&self, name: &str, in_file: impl Fn(&Path) -> bool) -> Option<Key<'a>> {
+        self.declarations
+            .keys()
+            .filter(|(path, declared)| *declared == name && in_file(path))
+            .min()
+            .copied()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::analysis::models::file_facts::{ExportInfo, ExportKind};
 use serde::Serialize;
 use std::path::{Path, PathBuf};
 
@@ -7,12 +8,40 @@ pub struct ProjectInfo {
     pub root: PathBuf,
     pub tags: Vec<String>,
     pub project_type: String,
+    /// Angular selector prefix from `project.json` (`prefix`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
     /// Directories whose every script file is a framework entry point
-    /// (expo-router `app/` dirs — file-based routing).
+    /// (expo-router `app/` dirs, Next.js `pages/` dirs — file-based
+    /// routing).
     #[serde(skip)]
-    pub entry_dirs: Vec<PathBuf>,
+    pub entry_dirs: Vec<EntryDir>,
+    /// Build inputs from `project.json` targets (`index`, `styles`,
+    /// `assets`), resolved against the workspace root. Directories count
+    /// as a reference to everything inside.
+    #[serde(skip)]
+    pub asset_refs: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntryDir {
+    pub path: PathBuf,
+    /// Exports the framework reads from the files; `None` when it may read
+    /// any of them.
+    pub exports: Option<&'static [&'static str]>,
 }
 
+/// Exports Next.js reads from a Pages Router page, `_app`, `_document` or
+/// API route.
+pub const NEXT_PAGE_EXPORTS: &[&str] = &[
+    "default",
+    "getStaticProps",
+    "getStaticPaths",
+    "getServerSideProps",
+    "config",
+    "reportWebVitals",
+];
+
 /// Maps files to the NX project that owns them (longest matching root wins —
 /// nested projects are attributed correctly).
 pub struct ProjectCatalog {
@@ -40,13 +69,27 @@ impl ProjectCatalog {
         self.projects.iter().find(|project| project.name == name)
     }
 
-    /// File lives in a file-based-routing directory (expo-router) —
-    /// the framework consumes it without imports.
+    /// File lives in a file-based-routing directory (expo-router, Next.js
+    /// `pages/`) — the framework consumes it without imports.
     pub fn is_framework_entry(&self, file: &Path) -> bool {
+        self.entry_dir_of(file).is_some()
+    }
+
+    /// The framework reads `export` of `file` itself.
+    pub fn is_framework_export(&self, file: &Path, export: &ExportInfo) -> bool {
+        let name = match export.kind {
+            ExportKind::Default => "default",
+            _ => export.name.as_str(),
+        };
+        self.entry_dir_of(file)
+            .is_some_and(|dir| dir.exports.is_none_or(|exports| exports.contains(&name)))
+    }
+
+    fn entry_dir_of(&self, file: &Path) -> Option<&EntryDir> {
         self.projects
             .iter()
             .flat_map(|project| project.entry_dirs.iter())
-            .any(|dir| file.starts_with(dir))
+            .find(|dir| file.starts_with(&dir.path))
     }
 }
 
@@ -0,0 +1,347 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::analyses::render_tree::{lazy_module_loaders, TreeBuilder};
+use crate::analysis::models::file_facts::FileFactsInfo;
+use crate::ng::models::ng_route::NgRouteInfo;
+use crate::ng::models::{NgAnalysisResults, NgServiceInfo};
+use crate::ng::templates::{TemplateTargetKind, TemplateUsageInfo};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// DI hygiene: providers nothing below their scope injects, and
+/// `providedIn: 'root'` services provided again in a narrower scope.
+#[derive(Debug, Serialize, Default)]
+pub struct ProviderReport {
+    pub unused: Vec<UnusedProvider>,
+    /// Each one creates a second instance next to the root singleton —
+    /// state written through one is invisible through the other. Providers
+    /// of an eagerly imported NgModule land in the root injector itself, so
+    /// only lazy-loaded modules count.
+    pub duplicates: Vec<DuplicateProvider>,
+}
+
+impl ProviderReport {
+    pub fn is_empty(&self) -> bool {
+        self.unused.is_empty() && self.duplicates.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderScope {
+    Component,
+    Directive,
+    Module,
+    Route,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnusedProvider {
+    pub provider: String,
+    pub scope: ProviderScope,
+    /// Component/directive/module name, or the route path.
+    pub owner: String,
+    pub file: PathBuf,
+    pub project: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DuplicateProvider {
+    pub service: String,
+    pub service_file: PathBuf,
+    pub provided_in: String,
+    pub scope: ProviderScope,
+    pub owner: String,
+    pub file: PathBuf,
+    pub project: String,
+}
+
+/// One `providers: [...]` array and where it sits.
+struct Registration<'a> {
+    scope: ProviderScope,
+    owner: String,
+    file: &'a Path,
+    providers: &'a [String],
+    /// `multi: true` contributions — collected by whoever injects the token
+    /// (often Angular itself), never by a name in this scope.
+    multi_providers: &'a [String],
+    /// Providers go to an injector of their own rather than the root one.
+    own_injector: bool,
+    /// What may inject from this scope: classes as (file, name) plus files
+    /// whose every injection counts (route tables — functional guards).
+    classes: Vec<(&'a Path, &'a str)>,
+    files: BTreeSet<&'a Path>,
+}
+
+pub fn check_providers(
+    results: &NgAnalysisResults,
+    template_usages: &[TemplateUsageInfo],
+    catalog: &ProjectCatalog,
+) -> ProviderReport {
+    let project_of = |path: &Path| {
+        catalog
+            .project_of(path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default()
+    };
+    let files: HashMap<&Path, &FileFactsInfo> = results
+        .source_files
+        .iter()
+        .map(|file| (file.path.as_path(), file))
+        .collect();
+
+    // Element-injector children: components and directives a template puts
+    // on its elements.
+    let mut rendered: HashMap<(&Path, &str), Vec<(&Path, &str)>> = HashMap::new();
+    for usage in template_usages {
+        if matches!(
+            usage.target_kind,
+            TemplateTargetKind::Component | TemplateTargetKind::Directive
+        ) {
+            rendered
+                .entry((usage.component_path.as_path(), usage.component.as_str()))
+                .or_default()
+                .push((usage.target_path.as_path(), usage.target.as_str()));
+        }
+    }
+
+    let mut injections: HashMap<(&Path, &str), Vec<&str>> = HashMap::new();
+    for file in &results.source_files {
+        for site in &file.injections {
+            if let Some(class) = &site.class {
+                injections
+                    .entry((file.path.as_path(), class.as_str()))
+                    .or_default()
+                    .push(site.token.as_str());
+            }
+        }
+    }
+    let mut services_by_name: HashMap<&str, Vec<&NgServiceInfo>> = HashMap::new();
+    for service in &results.services {
+        services_by_name
+            .entry(service.base.name.as_str())
+            .or_default()
+            .push(service);
+    }
+
+    let index = InjectionIndex {
+        files: &files,
+        rendered,
+        injections,
+        services_by_name,
+    };
+
+    let mut registrations: Vec<Registration> = Vec::new();
+    for component in &results.components {
+        registrations.push(Registration {
+            scope: ProviderScope::Component,
+            own_injector: true,
+            owner: component.base.name.clone(),
+            file: &component.base.source_path,
+            providers: &component.providers,
+            multi_providers: &component.multi_providers,
+            classes: vec![(&component.base.source_path, &component.base.name)],
+            files: BTreeSet::new(),
+        });
+    }
+    for directive in &results.directives {
+        registrations.push(Registration {
+            scope: ProviderScope::Directive,
+            own_injector: true,
+            owner: directive.base.name.clone(),
+            file: &directive.base.source_path,
+            providers: &directive.providers,
+            multi_providers: &directive.multi_providers,
+            classes: vec![(&directive.base.source_path, &directive.base.name)],
+            files: BTreeSet::new(),
+        });
+    }
+    // Module providers land in the root (or lazy) environment injector:
+    // anything in the workspace may inject them.
+    let lazy_modules = lazy_module_loaders(results, catalog);
+    let all_files: BTreeSet<&Path> = files.keys().copied().collect();
+    for module in &results.modules {
+        registrations.push(Registration {
+            scope: ProviderScope::Module,
+            own_injector: lazy_modules
+                .contains_key(&(module.base.source_path.as_path(), module.base.name.as_str())),
+            owner: module.base.name.clone(),
+            file: &module.base.source_path,
+            providers: &module.providers,
+            multi_providers: &module.multi_providers,
+            classes: Vec::new(),
+            files: all_files.clone(),
+        });
+    }
+    let tree = TreeBuilder::new(results, template_usages, catalog);
+    fn routes_with_providers<'r>(routes: &'r [NgRouteInfo], out: &mut Vec<&'r NgRouteInfo>) {
+        for route in routes {
+            if !route.providers.is_empty() {
+                out.push(route);
+            }
+            routes_with_providers(&route.children, out);
+        }
+    }
+    for file in &results.source_files {
+        let mut routes = Vec::new();
+        routes_with_providers(&file.routes, &mut routes);
+        for route in routes {
+            let mut components = Vec::new();
+            let mut scope_files = BTreeSet::new();
+            tree.route_scope(route, &file.path, &mut components, &mut scope_files);
+            registrations.push(Registration {
+                scope: ProviderScope::Route,
+                own_injector: true,
+                owner: route.path.clone().unwrap_or_default(),
+                file: &file.path,
+                providers: &route.providers,
+                multi_providers: &route.multi_providers,
+                classes: components
+                    .iter()
+                    .map(|c| (c.base.source_path.as_path(), c.base.name.as_str()))
+                    .collect(),
+                files: scope_files,
+            });
+        }
+    }
+
+    let mut report = ProviderReport::default();
+    for registration in registrations
+        .iter()
+        .filter(|registration| !registration.providers.is_empty())
+    {
+        let injected = index.injected_below(&registration.classes, &registration.files);
+        let project = project_of(registration.file);
+        // Tokens a package declares (`HTTP_INTERCEPTORS`, `ErrorHandler`)
+        // are read by the package: no injection in the workspace is needed.
+        let package_imports = files
+            .get(registration.file)
+            .map(|facts| facts.package_imports.as_slice())
+            .unwrap_or_default();
+        for provider in registration.providers {
+            if !injected.contains(provider.as_str())
+                && !registration.multi_providers.contains(provider)
+                && !package_imports.contains(provider)
+            {
+                report.unused.push(UnusedProvider {
+                    provider: provider.clone(),
+                    scope: registration.scope,
+                    owner: registration.owner.clone(),
+                    file: registration.file.to_path_buf(),
+                    project: project.clone(),
+                });
+            }
+
+            let root_service = resolve_service(provider, registration.file, &files, results)
+                .filter(|_| registration.own_injector)
+                .filter(|service| {
+                    matches!(service.provided_in.as_deref(), Some("root" | "platform"))
+                });
+            if let Some(service) = root_service {
+                report.duplicates.push(DuplicateProvider {
+                    service: service.base.name.clone(),
+                    service_file: service.base.source_path.clone(),
+                    provided_in: service.provided_in.clone().unwrap_or_default(),
+                    scope: registration.scope,
+                    owner: registration.owner.clone(),
+                    file: registration.file.to_path_buf(),
+                    project: project.clone(),
+                });
+            }
+        }
+    }
+
+    report
+        .unused
+        .sort_by(|a, b| (&a.file, &a.owner, &a.provider).cmp(&(&b.file, &b.owner, &b.provider)));
+    report
+        .duplicates
+        .sort_by(|a, b| (&a.file, &a.owner, &a.service).cmp(&(&b.file, &b.owner, &b.service)));
+    report
+}
+
+struct InjectionIndex<'a, 'f> {
+    files: &'f HashMap<&'a Path, &'a FileFactsInfo>,
+    rendered: HashMap<(&'a Path, &'a str), Vec<(&'a Path, &'a str)>>,
+    injections: HashMap<(&'a Path, &'a str), Vec<&'a str>>,
+    services_by_name: HashMap<&'a str, Vec<&'a NgServiceInfo>>,
+}
+
+impl<'a> InjectionIndex<'a, '_> {
+    /// Tokens injected from `classes` (and everything they render), or from
+    /// any code in `scope_files`, following injected services' own
+    /// dependencies — a service nobody reaches does not keep its deps alive.
+    fn injected_below(
+        &self,
+        classes: &[(&'a Path, &'a str)],
+        scope_files: &BTreeSet<&'a Path>,
+    ) -> HashSet<&'a str> {
+        let mut tokens: HashSet<&'a str> = HashSet::new();
+        let mut work: Vec<(&'a Path, &'a str)> = classes.to_vec();
+        let mut inject = |token: &'a str, work: &mut Vec<(&'a Path, &'a str)>| {
+            if tokens.insert(token) {
+                for service in self.services_by_name.get(token).into_iter().flatten() {
+                    work.push((
+                        service.base.source_path.as_path(),
+                        service.base.name.as_str(),
+                    ));
+                }
+            }
+        };
+        for file in scope_files {
+            if let Some(facts) = self.files.get(file) {
+                for site in &facts.injections {
+                    inject(&site.token, &mut work);
+                }
+            }
+        }
+        let mut seen: HashSet<(&Path, &str)> = HashSet::new();
+        while let Some(class) = work.pop() {
+            if !seen.insert(class) {
+                continue;
+            }
+            for token in self.injections.get(&class).into_iter().flatten() {
+                inject(token, &mut work);
+            }
+            work.extend(self.rendered.get(&class).into_iter().flatten().copied());
+        }
+        tokens
+    }
+}
+
+/// The service a provider token names in `file`: through the file's imports
+/// (a barrel resolves to the service's project), else declared in the same
+/// file.
+fn resolve_service<'a>(
+    token: &str,
+    file: &Path,
+    files: &HashMap<&Path, &FileFactsInfo>,
+    results: &'a NgAnalysisResults,
+) -> Option<&'a NgServiceInfo> {
+    let import = files.get(file).and_then(|facts| {
+        facts
+            .imports
+            .iter()
+            .find(|import| import.imported_item.name == token)
+    });
+    match import {
+        Some(import) => {
+            let exported = import
+                .imported_item
+                .alias
+                .as_deref()
+                .unwrap_or(&import.imported_item.name);
+            let package = files
+                .get(import.resolved_path.as_path())
+                .map(|facts| facts.package_name.as_str());
+            results.services.iter().find(|service| {
+                service.base.name == exported
+                    && (service.base.source_path == import.resolved_path
+                        || package == Some(service.base.package_name.as_str()))
+            })
+        }
+        None => results
+            .services
+            .iter()
+            .find(|service| service.base.name == token && service.base.source_path == file),
+    }
+}
@@ -0,0 +1,241 @@
+use crate::analyses::project_map::is_test_file;
+use crate::analyses::react_usage::JsxResolver;
+use crate::analysis::models::file_facts::FileFactsInfo;
+use crate::analysis::models::react::JsxUsageInfo;
+use crate::ng::models::NgAnalysisResults;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Who provides and who reads each React context. Spec files neither
+/// provide nor consume.
+#[derive(Debug, Serialize)]
+pub struct ReactContextUsage {
+    pub context: String,
+    pub file: PathBuf,
+    pub package_name: String,
+    /// Where `<X.Provider>` (or React 19's `<X>`) is rendered.
+    pub providers: Vec<ContextSite>,
+    /// Components reading it: with `useContext` / `use`, through a custom
+    /// hook that does, or with `<X.Consumer>`.
+    pub consumers: Vec<ContextSite>,
+    /// Rendered consumers no render path places below a provider — they
+    /// only ever see the default value.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unprovided_consumers: Vec<ContextSite>,
+    /// Provided, but nothing reads it.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub never_consumed: bool,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ContextSite {
+    /// Top-level declaration; `None` for top-level code
+    /// (`root.render(<ThemeContext.Provider …>)`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    pub file: PathBuf,
+}
+
+/// A top-level declaration: file and name, `""` for top-level code.
+type Node<'a> = (&'a Path, &'a str);
+
+struct Element<'a> {
+    file: &'a FileFactsInfo,
+    usage: &'a JsxUsageInfo,
+    caller: Node<'a>,
+    /// The component it renders.
+    target: Option<Node<'a>>,
+    /// The context it provides.
+    provides: Option<Node<'a>>,
+    /// Per enclosing element: the component it renders, the context it
+    /// provides.
+    within: Vec<(Option<Node<'a>>, Option<Node<'a>>)>,
+}
+
+pub fn analyze_contexts<'a>(results: &'a NgAnalysisResults) -> Vec<ReactContextUsage> {
+    if results.react_contexts.is_empty() {
+        return Vec::new();
+    }
+    let resolver = JsxResolver::new(results);
+    let contexts: HashSet<Node> = results
+        .react_contexts
+        .iter()
+        .map(|context| (context.source_path.as_path(), context.name.as_str()))
+        .collect();
+    let files: Vec<&FileFactsInfo> = results
+        .source_files
+        .iter()
+        .filter(|file| !is_test_file(&file.path))
+        .collect();
+    let context_of = |reference: &str, file: &'a FileFactsInfo| {
+        resolver
+            .reference(reference, file)
+            .filter(|found| contexts.contains(found))
+    };
+    // `<X.Provider>`, or `<X>` naming a context rather than a component.
+    let provided_by = |tag: &str, file: &'a FileFactsInfo| match tag.strip_suffix(".Provider") {
+        Some(context) => context_of(context, file),
+        None if resolver.resolve(tag, file).is_none() => context_of(tag, file),
+        None => None,
+    };
+
+    let elements: Vec<Element> = files
+        .iter()
+        .flat_map(|file| {
+            file.jsx_usages.iter().map(|usage| Element {
+                file,
+                usage,
+                caller: (
+                    file.path.as_path(),
+                    usage.caller.as_deref().unwrap_or_default(),
+                ),
+                target: resolver.resolve(&usage.component, file),
+                provides: provided_by(&usage.component, file),
+                within: usage
+                    .within
+                    .iter()
+                    .map(|tag| (resolver.resolve(tag, file), provided_by(tag, file)))
+                    .collect(),
+            })
+        })
+        .collect();
+    let rendered: HashSet<Node> = elements.iter().filter_map(|e| e.target).collect();
+
+    // Hook -> the declarations calling it.
+    let mut hook_callers: HashMap<Node, Vec<Node>> = HashMap::new();
+    for file in &files {
+        for call in &file.hook_calls {
+            let hook = resolver
+                .reference(&call.hook, file)
+                .unwrap_or((file.path.as_path(), call.hook.as_str()));
+            hook_callers.entry(hook).or_default().push((
+                file.path.as_path(),
+                call.caller.as_deref().unwrap_or_default(),
+            ));
+        }
+    }
+
+    let mut usages = Vec::new();
+    for context in &results.react_contexts {
+        let key = (context.source_path.as_path(), context.name.as_str());
+
+        let providers: Vec<&Element> = elements
+            .iter()
+            .filter(|e| e.provides == Some(key))
+            .collect();
+
+        // Direct reads, then up through the hooks doing the reading.
+        let mut work: Vec<Node> = Vec::new();
+        for file in &files {
+            work.extend(
+                file.context_reads
+                    .iter()
+                    .filter(|read| context_of(&read.context, file) == Some(key))
+                    .map(|read| {
+                        (
+                            file.path.as_path(),
+                            read.caller.as_deref().unwrap_or_default(),
+                        )
+                    }),
+            );
+        }
+        let consumer_elements: Vec<&Element> = elements
+            .iter()
+            .filter(|e| {
+                e.usage
+                    .component
+                    .strip_suffix(".Consumer")
+                    .and_then(|context| context_of(context, e.file))
+                    == Some(key)
+            })
+            .collect();
+        work.extend(consumer_elements.iter().map(|e| e.caller));
+        let mut reading: HashSet<Node> = HashSet::new();
+        while let Some(reader) = work.pop() {
+            if reading.insert(reader) {
+                work.extend(hook_callers.get(&reader).into_iter().flatten().copied());
+            }
+        }
+        let consumers: BTreeSet<Node> = reading
+            .into_iter()
+            .filter(|(_, name)| !is_hook_name(name))
+            .collect();
+
+        // Declarations that render a provider, directly or through the
+        // components they render — `ThemeProvider` wrapping its children.
+        // Assumed to wrap everything passed to them.
+        let mut wraps: HashSet<Node> = providers.iter().map(|e| e.caller).collect();
+        loop {
+            let before = wraps.len();
+            for element in &elements {
+                if element.target.is_some_and(|target| wraps.contains(&target)) {
+                    wraps.insert(element.caller);
+                }
+            }
+            if wraps.len() == before {
+                break;
+            }
+        }
+        let within_provider = |element: &Element| {
+            element.within.iter().any(|(target, provides)| {
+                *provides == Some(key) || target.is_some_and(|target| wraps.contains(&target))
+            })
+        };
+        let below_provider: Vec<bool> = elements.iter().map(within_provider).collect();
+
+        // Components rendered below a provider on some render path.
+        let mut provided: HashSet<Node> = HashSet::new();
+        loop {
+            let before = provided.len();
+            for (element, below) in elements.iter().zip(&below_provider) {
+                if let Some(target) = element.target {
+                    if *below || provided.contains(&element.caller) {
+                        provided.insert(target);
+                    }
+                }
+            }
+            if provided.len() == before {
+                break;
+            }
+        }
+        // `<X.Consumer>` inside the component's own `<X.Provider>`.
+        let self_provided: HashSet<Node> = consumer_elements
+            .iter()
+            .filter(|e| within_provider(e))
+            .map(|e| e.caller)
+            .collect();
+
+        let site = |(path, name): Node| ContextSite {
+            component: (!name.is_empty()).then(|| name.to_string()),
+            file: path.to_path_buf(),
+        };
+        let providers: BTreeSet<ContextSite> = providers.iter().map(|e| site(e.caller)).collect();
+        let unprovided_consumers = consumers
+            .iter()
+            .filter(|consumer| {
+                rendered.contains(*consumer)
+                    && !provided.contains(*consumer)
+                    && !self_provided.contains(*consumer)
+            })
+            .map(|consumer| site(*consumer))
+            .collect();
+        usages.push(ReactContextUsage {
+            context: context.name.clone(),
+            file: context.source_path.clone(),
+            package_name: context.package_name.clone(),
+            never_consumed: !providers.is_empty() && consumers.is_empty(),
+            providers: providers.into_iter().collect(),
+            consumers: consumers.into_iter().map(site).collect(),
+            unprovided_consumers,
+        });
+    }
+    usages
+}
+
+/// React's rule: `use` followed by a capital letter or digit.
+fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
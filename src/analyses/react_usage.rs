@@ -1,7 +1,11 @@
+use crate::analyses::project_map::{is_test_file, ProjectCatalog};
+use crate::analysis::models::file_facts::FileFactsInfo;
+use crate::analysis::models::import::ImportKind;
+use crate::analysis::models::react::{CompoundComponent, JsxUsageInfo, ReactProp};
 use crate::ng::models::NgAnalysisResults;
 use serde::Serialize;
-use std::collections::BTreeMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Usage analytics for React components — how often each component is
 /// rendered and which props are actually used (design-system adoption data,
@@ -13,6 +17,36 @@ pub struct ReactComponentUsage {
     pub package_name: String,
     pub usage_count: usize,
     pub props: Vec<PropUseCount>,
+    /// Declared props no usage passes. Only for rendered components whose
+    /// props are known, and not when a usage spreads props into it.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unpassed_props: Vec<String>,
+    /// Required props a usage leaves out, per using file. Usages with a
+    /// spread are skipped.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing_required_props: Vec<MissingProp>,
+}
+
+/// Adoption of custom hooks — the `ReactComponentUsage` counterpart for
+/// `useX` functions. Calls from spec files are not adoption.
+#[derive(Debug, Serialize)]
+pub struct ReactHookUsage {
+    pub hook: String,
+    pub file: PathBuf,
+    pub package_name: String,
+    pub call_count: usize,
+    /// Calls per calling project.
+    pub by_project: BTreeMap<String, usize>,
+    /// Called only from hooks that are themselves unused, directly or down
+    /// a chain of them — dead along with them.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub only_from_unused_hooks: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MissingProp {
+    pub prop: String,
+    pub file: PathBuf,
 }
 
 #[derive(Debug, Serialize)]
@@ -28,31 +62,22 @@ pub fn analyze_react_usage(results: &NgAnalysisResults) -> Vec<ReactComponentUsa
 
     // (component file, name) -> (usage count, prop -> count)
     let mut stats: BTreeMap<(PathBuf, String), (usize, BTreeMap<String, usize>)> = BTreeMap::new();
+    // (component file, name) -> (using file, usage)
+    let mut usages: HashMap<(PathBuf, String), Vec<(&Path, &JsxUsageInfo)>> = HashMap::new();
 
+    let resolver = JsxResolver::new(results);
     for file in &results.source_files {
         for usage in &file.jsx_usages {
-            // Resolve the JSX tag: imported name → declaring file, otherwise
-            // a component defined in the same file.
-            let target: Option<PathBuf> = file
-                .imports
-                .iter()
-                .find(|import| import.imported_item.name == usage.component)
-                .map(|import| import.resolved_path.clone())
-                .or_else(|| {
-                    results
-                        .react_components
-                        .iter()
-                        .find(|component| {
-                            component.name == usage.component && component.source_path == file.path
-                        })
-                        .map(|component| component.source_path.clone())
-                });
-
-            let Some(target) = target else { continue };
+            let Some((path, name)) = resolver.resolve(&usage.component, file) else {
+                continue;
+            };
 
-            let entry = stats
-                .entry((target, usage.component.clone()))
-                .or_insert_with(|| (0, BTreeMap::new()));
+            let key = (path.to_path_buf(), name.to_string());
+            usages
+                .entry(key.clone())
+                .or_default()
+                .push((file.path.as_path(), usage));
+            let entry = stats.entry(key).or_insert_with(|| (0, BTreeMap::new()));
             entry.0 += 1;
             for prop in &usage.props {
                 *entry.1.entry(prop.clone()).or_insert(0) += 1;
@@ -66,6 +91,11 @@ pub fn analyze_react_usage(results: &NgAnalysisResults) -> Vec<ReactComponentUsa
         .map(|component| {
             let key = (component.source_path.clone(), component.name.clone());
             let (usage_count, props) = stats.get(&key).cloned().unwrap_or_default();
+            let (unpassed_props, missing_required_props) =
+                match (&component.props, usages.get(&key)) {
+                    (Some(declared), Some(usages)) => check_props(declared, usages),
+                    _ => Default::default(),
+                };
             ReactComponentUsage {
                 component: component.name.clone(),
                 file: component.source_path.clone(),
@@ -75,6 +105,288 @@ pub fn analyze_react_usage(results: &NgAnalysisResults) -> Vec<ReactComponentUsa
                     .into_iter()
                     .map(|(name, count)| PropUseCount { name, count })
                     .collect(),
+                unpassed_props,
+                missing_required_props,
+            }
+        })
+        .collect()
+}
+
+/// Resolves JSX tags to the component they render, as (declaring file,
+/// name): `<Button>` and `<Btn>` through the file's imports (a barrel
+/// resolves to the declaring project), `<UI.Button>` through a namespace
+/// import, `<Form.Field>` through the compound component `Form` to the
+/// component its `Field` property holds. Hooks and contexts resolve the
+/// same way with `reference`.
+pub struct JsxResolver<'a> {
+    by_path: HashMap<&'a Path, &'a FileFactsInfo>,
+    components: HashSet<(&'a Path, &'a str)>,
+    /// Components, compounds, hooks and contexts.
+    declarations: Vec<(&'a Path, &'a str)>,
+}
+
+impl<'a> JsxResolver<'a> {
+    pub fn new(results: &'a NgAnalysisResults) -> Self {
+        let components: HashSet<(&Path, &str)> = results
+            .react_components
+            .iter()
+            .map(|component| (component.source_path.as_path(), component.name.as_str()))
+            .collect();
+        let compounds = results.source_files.iter().flat_map(|file| {
+            file.compound_components
+                .iter()
+                .map(|compound| (file.path.as_path(), compound.name.as_str()))
+        });
+        let hooks = results
+            .react_hooks
+            .iter()
+            .map(|hook| (hook.source_path.as_path(), hook.name.as_str()));
+        let contexts = results
+            .react_contexts
+            .iter()
+            .map(|context| (context.source_path.as_path(), context.name.as_str()));
+        let mut declarations: Vec<(&Path, &str)> = components
+            .iter()
+            .copied()
+            .chain(compounds)
+            .chain(hooks)
+            .chain(contexts)
+            .collect();
+        declarations.sort();
+        Self {
+            by_path: results
+                .source_files
+                .iter()
+                .map(|file| (file.path.as_path(), file))
+                .collect(),
+            components,
+            declarations,
+        }
+    }
+
+    pub fn resolve(&self, tag: &str, file: &'a FileFactsInfo) -> Option<(&'a Path, &'a str)> {
+        let mut segments = tag.split('.');
+        let mut current = self.name_in(segments.next()?, file, &mut segments)?;
+        for member in segments {
+            let compound = self.compound(current)?;
+            let held = compound.members.get(member)?;
+            current = self.name_in(held, self.by_path.get(current.0)?, &mut std::iter::empty())?;
+        }
+        // `Object.assign(FormRoot, { … })` renders as `FormRoot`.
+        for _ in 0..8 {
+            if self.components.contains(&current) {
+                return Some(current);
+            }
+            let root = self.compound(current)?.root.as_deref()?;
+            current = self.name_in(root, self.by_path.get(current.0)?, &mut std::iter::empty())?;
+        }
+        None
+    }
+
+    /// The declaration a reference in `file` names: `ThemeContext`,
+    /// `contexts.ThemeContext` off a namespace import.
+    pub fn reference(
+        &self,
+        reference: &str,
+        file: &'a FileFactsInfo,
+    ) -> Option<(&'a Path, &'a str)> {
+        let mut segments = reference.split('.');
+        let found = self.name_in(segments.next()?, file, &mut segments)?;
+        segments.next().is_none().then_some(found)
+    }
+
+    /// The declaration a name in `file` refers to. A namespace
+    /// import takes the export name from `rest`.
+    fn name_in<'s>(
+        &self,
+        name: &str,
+        file: &'a FileFactsInfo,
+        rest: &mut impl Iterator<Item = &'s str>,
+    ) -> Option<(&'a Path, &'a str)> {
+        let Some(import) = file
+            .imports
+            .iter()
+            .find(|import| import.imported_item.name == name)
+        else {
+            return self.declared(name, |path| path == file.path);
+        };
+        let exported = match import.imported_item.import_kind {
+            ImportKind::Namespace => rest.next()?,
+            _ => import
+                .imported_item
+                .alias
+                .as_deref()
+                .unwrap_or(&import.imported_item.name),
+        };
+        let target = import.resolved_path.as_path();
+        let package = self
+            .by_path
+            .get(target)
+            .map(|facts| facts.package_name.as_str());
+        self.declared(exported, |path| path == target).or_else(|| {
+            self.declared(exported, |path| {
+                package.is_some()
+                    && self
+                        .by_path
+                        .get(path)
+                        .map(|facts| facts.package_name.as_str())
+                        == package
+            })
+        })
+    }
+
+    fn declared(&self, name: &str, in_file: impl Fn(&Path) -> bool) -> Option<(&'a Path, &'a str)> {
+        self.declarations
+            .iter()
+            .copied()
+            .find(|(path, declared)| *declared == name && in_file(path))
+    }
+
+    fn compound(&self, (path, name): (&Path, &str)) -> Option<&'a CompoundComponent> {
+        self.by_path
+            .get(path)?
+            .compound_components
+            .iter()
+            .find(|compound| compound.name == name)
+    }
+}
+
+/// Declared props no usage passes, and required props each usage lacks.
+/// Content between the tags passes `children`.
+fn check_props(
+    declared: &[ReactProp],
+    usages: &[(&Path, &JsxUsageInfo)],
+) -> (Vec<String>, Vec<MissingProp>) {
+    let passes = |usage: &JsxUsageInfo, prop: &str| {
+        usage.props.iter().any(|passed| passed == prop) || (prop == "children" && usage.children)
+    };
+    let unpassed = if usages.iter().any(|(_, usage)| usage.spread) {
+        Vec::new()
+    } else {
+        declared
+            .iter()
+            .filter(|prop| !usages.iter().any(|(_, usage)| passes(usage, &prop.name)))
+            .map(|prop| prop.name.clone())
+            .collect()
+    };
+    let missing: BTreeSet<MissingProp> = usages
+        .iter()
+        .filter(|(_, usage)| !usage.spread)
+        .flat_map(|(file, usage)| {
+            declared
+                .iter()
+                .filter(|prop| !prop.optional && !passes(usage, &prop.name))
+                .map(|prop| MissingProp {
+                    prop: prop.name.clone(),
+                    file: file.to_path_buf(),
+                })
+        })
+        .collect();
+    (unpassed, missing.into_iter().collect())
+}
+
+pub fn analyze_hook_usage(
+    results: &NgAnalysisResults,
+    catalog: &ProjectCatalog,
+) -> Vec<ReactHookUsage> {
+    if results.react_hooks.is_empty() {
+        return Vec::new();
+    }
+    let hooks: HashSet<(&Path, &str)> = results
+        .react_hooks
+        .iter()
+        .map(|hook| (hook.source_path.as_path(), hook.name.as_str()))
+        .collect();
+    let by_path: HashMap<&Path, &FileFactsInfo> = results
+        .source_files
+        .iter()
+        .map(|file| (file.path.as_path(), file))
+        .collect();
+
+    // hook -> calling projects; hook -> hooks calling it.
+    let mut calls: HashMap<(&Path, &str), BTreeMap<String, usize>> = HashMap::new();
+    let mut called_by_hooks: HashMap<(&Path, &str), Vec<(&Path, &str)>> = HashMap::new();
+    let mut live: Vec<(&Path, &str)> = Vec::new();
+    for file in results
+        .source_files
+        .iter()
+        .filter(|f| !is_test_file(&f.path))
+    {
+        let project = catalog
+            .project_of(&file.path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default();
+        for call in &file.hook_calls {
+            // Imported hook → its declaring file (a barrel resolves to the
+            // declaring project); otherwise a hook declared here.
+            let target = match file
+                .imports
+                .iter()
+                .find(|import| import.imported_item.name == call.hook)
+            {
+                Some(import) => {
+                    let exported = import
+                        .imported_item
+                        .alias
+                        .as_deref()
+                        .unwrap_or(&import.imported_item.name);
+                    let package = by_path
+                        .get(import.resolved_path.as_path())
+                        .map(|facts| facts.package_name.as_str());
+                    let mut named = results
+                        .react_hooks
+                        .iter()
+                        .filter(|hook| hook.name == exported);
+                    named
+                        .clone()
+                        .find(|hook| hook.source_path == import.resolved_path)
+                        .or_else(|| named.find(|hook| Some(hook.package_name.as_str()) == package))
+                        .map(|hook| (hook.source_path.as_path(), hook.name.as_str()))
+                }
+                None => hooks
+                    .get(&(file.path.as_path(), call.hook.as_str()))
+                    .copied(),
+            };
+            let Some(target) = target else { continue };
+
+            *calls
+                .entry(target)
+                .or_default()
+                .entry(project.clone())
+                .or_insert(0) += 1;
+            match call
+                .caller
+                .as_deref()
+                .and_then(|caller| hooks.get(&(file.path.as_path(), caller)))
+            {
+                Some(&caller) => called_by_hooks.entry(caller).or_default().push(target),
+                None => live.push(target),
+            }
+        }
+    }
+
+    // Live hooks: called from anything but a hook, or from a live hook.
+    let mut reached: HashSet<(&Path, &str)> = HashSet::new();
+    while let Some(hook) = live.pop() {
+        if reached.insert(hook) {
+            live.extend(called_by_hooks.get(&hook).into_iter().flatten().copied());
+        }
+    }
+
+    results
+        .react_hooks
+        .iter()
+        .map(|hook| {
+            let key = (hook.source_path.as_path(), hook.name.as_str());
+            let by_project = calls.get(&key).cloned().unwrap_or_default();
+            let call_count = by_project.values().sum();
+            ReactHookUsage {
+                hook: hook.name.clone(),
+                file: hook.source_path.clone(),
+                package_name: hook.package_name.clone(),
+                call_count,
+                by_project,
+                only_from_unused_hooks: call_count > 0 && !reached.contains(&key),
             }
         })
         .collect()
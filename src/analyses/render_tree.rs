@@ -0,0 +1,603 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::analysis::models::file_facts::{ExportKind, FileFactsInfo};
+use crate::ng::models::ng_route::{LazyTarget, NgRouteInfo};
+use crate::ng::models::{NgAnalysisResults, NgComponentInfo};
+use crate::ng::templates::{TemplateTargetKind, TemplateUsageInfo};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// The component hierarchy each app actually renders, from its bootstrap
+/// components down through templates and routes.
+#[derive(Debug, Serialize, Default)]
+pub struct RenderTreeReport {
+    pub roots: Vec<RenderRoot>,
+    /// Components some template renders, but only templates no app ever
+    /// reaches — the whole branch above them is dead.
+    pub unreachable: Vec<UnreachableComponent>,
+}
+
+impl RenderTreeReport {
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty() && self.unreachable.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RenderRoot {
+    /// Project whose bootstrap call or route table starts this tree — the
+    /// root component itself may live in a library.
+    pub app: String,
+    #[serde(flatten)]
+    pub node: RenderNode,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RenderNode {
+    pub component: String,
+    pub file: PathBuf,
+    pub project: String,
+    pub via: RenderVia,
+    /// Route path for `Route`/`LazyRoute` nodes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route_path: Option<String>,
+    /// The component already appears above this node — its subtree is not
+    /// expanded again.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub recursive: bool,
+    /// The component's subtree is already expanded earlier in this tree —
+    /// shared components are expanded once, later occurrences refer back.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub repeated: bool,
+    pub children: Vec<RenderNode>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum RenderVia {
+    /// NgModule `bootstrap` or `bootstrapApplication(...)`.
+    Bootstrap,
+    Template,
+    Route,
+    /// `loadComponent`, or a route of a `loadChildren` table.
+    LazyRoute,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct UnreachableComponent {
+    pub name: String,
+    pub file: PathBuf,
+    pub project: String,
+    /// Components whose templates render it — none of them is reachable.
+    pub rendered_by: Vec<String>,
+}
+
+type ComponentKey<'a> = (&'a Path, &'a str);
+
+pub(crate) struct TreeBuilder<'a> {
+    results: &'a NgAnalysisResults,
+    catalog: &'a ProjectCatalog,
+    files: HashMap<&'a Path, &'a FileFactsInfo>,
+    /// Rendering component → components its template renders.
+    template_children: BTreeMap<ComponentKey<'a>, BTreeSet<ComponentKey<'a>>>,
+    components: HashMap<ComponentKey<'a>, &'a NgComponentInfo>,
+    reachable: BTreeSet<ComponentKey<'a>>,
+    /// Components whose subtree the current root already expanded.
+    expanded: HashSet<ComponentKey<'a>>,
+}
+
+pub fn build_render_tree(
+    results: &NgAnalysisResults,
+    template_usages: &[TemplateUsageInfo],
+    catalog: &ProjectCatalog,
+) -> RenderTreeReport {
+    let mut builder = TreeBuilder::new(results, template_usages, catalog);
+
+    // Bootstrap components: NgModule `bootstrap` arrays and
+    // `bootstrapApplication(...)` calls, resolved from the declaring file.
+    let mut root_components: Vec<&NgComponentInfo> = Vec::new();
+    for module in &results.modules {
+        for name in &module.bootstrap {
+            root_components.extend(builder.resolve_component(name, &module.base.source_path));
+        }
+    }
+    for file in &results.source_files {
+        for name in &file.bootstrap_calls {
+            root_components.extend(builder.resolve_component(name, &file.path));
+        }
+    }
+    root_components.sort_by(|a, b| {
+        (&a.base.source_path, &a.base.name).cmp(&(&b.base.source_path, &b.base.name))
+    });
+    root_components
+        .dedup_by(|a, b| a.base.source_path == b.base.source_path && a.base.name == b.base.name);
+
+    // Root route tables: every table of a project with a bootstrap component,
+    // minus the ones some `loadChildren` pulls in (they nest under it).
+    let lazy_tables: BTreeSet<&Path> = results
+        .source_files
+        .iter()
+        .flat_map(|file| {
+            collect_load_children(&file.routes)
+                .into_iter()
+                .filter_map(|target| builder.lazy_route_file(target, &file.path))
+        })
+        .collect();
+
+    let mut root_routes: BTreeMap<String, Vec<(&Path, &NgRouteInfo)>> = BTreeMap::new();
+    for file in &results.source_files {
+        if file.routes.is_empty() || lazy_tables.contains(file.path.as_path()) {
+            continue;
+        }
+        root_routes
+            .entry(builder.project_of(&file.path))
+            .or_default()
+            .extend(file.routes.iter().map(|route| (file.path.as_path(), route)));
+    }
+
+    let mut roots = Vec::new();
+    let mut bootstrapped: BTreeSet<String> = BTreeSet::new();
+    for root in root_components {
+        let project = builder.project_of(&root.base.source_path);
+        let mut stack = Vec::new();
+        builder.expanded.clear();
+        let mut node = builder.node(root, RenderVia::Bootstrap, None, &mut stack);
+        stack.push((root.base.source_path.as_path(), root.base.name.as_str()));
+        for (file, route) in root_routes.get(&project).into_iter().flatten() {
+            node.children.extend(builder.route_nodes(
+                route,
+                file,
+                false,
+                &mut stack,
+                &mut BTreeSet::new(),
+            ));
+        }
+        roots.push(RenderRoot {
+            app: project.clone(),
+            node,
+        });
+        bootstrapped.insert(project);
+    }
+    // Without a bootstrap component in sight (bootstrapped elsewhere, or a
+    // route table exported for a host app), route components are roots.
+    for (project, routes) in &root_routes {
+        if bootstrapped.contains(project) {
+            continue;
+        }
+        for (file, route) in routes {
+            builder.expanded.clear();
+            roots.extend(
+                builder
+                    .route_nodes(route, file, false, &mut Vec::new(), &mut BTreeSet::new())
+                    .into_iter()
+                    .map(|node| RenderRoot {
+                        app: project.clone(),
+                        node,
+                    }),
+            );
+        }
+    }
+
+    // Rendered somewhere, but never from a reachable component.
+    let mut parents: BTreeMap<ComponentKey, BTreeSet<&str>> = BTreeMap::new();
+    for (parent, children) in &builder.template_children {
+        for child in children {
+            if child != parent {
+                parents.entry(*child).or_default().insert(parent.1);
+            }
+        }
+    }
+    let unreachable = if roots.is_empty() {
+        Vec::new()
+    } else {
+        parents
+            .into_iter()
+            .filter(|(child, _)| !builder.reachable.contains(child))
+            .map(|((path, name), rendered_by)| UnreachableComponent {
+                name: name.to_string(),
+                file: path.to_path_buf(),
+                project: builder.project_of(path),
+                rendered_by: rendered_by.into_iter().map(str::to_string).collect(),
+            })
+            .collect()
+    };
+
+    RenderTreeReport { roots, unreachable }
+}
+
+impl<'a> TreeBuilder<'a> {
+    pub(crate) fn new(
+        results: &'a NgAnalysisResults,
+        template_usages: &'a [TemplateUsageInfo],
+        catalog: &'a ProjectCatalog,
+    ) -> Self {
+        let components: HashMap<ComponentKey, &NgComponentInfo> = results
+            .components
+            .iter()
+            .map(|c| ((c.base.source_path.as_path(), c.base.name.as_str()), c))
+            .collect();
+        let mut template_children: BTreeMap<ComponentKey, BTreeSet<ComponentKey>> = BTreeMap::new();
+        for usage in template_usages {
+            let parent = (usage.component_path.as_path(), usage.component.as_str());
+            // Dynamic creation from a service or plain function has no place
+            // in the tree.
+            if usage.target_kind != TemplateTargetKind::Component
+                || !components.contains_key(&parent)
+            {
+                continue;
+            }
+            template_children
+                .entry(parent)
+                .or_default()
+                .insert((usage.target_path.as_path(), usage.target.as_str()));
+        }
+
+        TreeBuilder {
+            results,
+            catalog,
+            files: results
+                .source_files
+                .iter()
+                .map(|file| (file.path.as_path(), file))
+                .collect(),
+            template_children,
+            components,
+            reachable: BTreeSet::new(),
+            expanded: HashSet::new(),
+        }
+    }
+
+    /// Components a route activates — its own (eager or lazy), nested
+    /// `children` and the routes of `loadChildren` tables — plus the files
+    /// holding those tables.
+    pub(crate) fn route_scope(
+        &self,
+        route: &'a NgRouteInfo,
+        file: &'a Path,
+        components: &mut Vec<&'a NgComponentInfo>,
+        files: &mut BTreeSet<&'a Path>,
+    ) {
+        files.insert(file);
+        components.extend(
+            route
+                .component
+                .as_deref()
+                .and_then(|name| self.resolve_component(name, file)),
+        );
+        components.extend(
+            route
+                .load_component
+                .as_ref()
+                .and_then(|target| self.resolve_lazy_component(target, file)),
+        );
+        for child in &route.children {
+            self.route_scope(child, file, components, files);
+        }
+        if let Some(table_file) = route
+            .load_children
+            .as_ref()
+            .and_then(|target| self.lazy_route_file(target, file))
+        {
+            // A table loaded twice (or loading itself) is walked once.
+            if files.insert(table_file) {
+                if let Some(facts) = self.files.get(table_file).copied() {
+                    for child in &facts.routes {
+                        self.route_scope(child, table_file, components, files);
+                    }
+                }
+            }
+        }
+    }
+
+    fn project_of(&self, path: &Path) -> String {
+        self.catalog
+            .project_of(path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Builds the node for `component` and its template subtree. `stack` holds
+    /// the components on the path from the root, to stop recursion; a
+    /// component expanded before in this tree is emitted without children.
+    fn node(
+        &mut self,
+        component: &'a NgComponentInfo,
+        via: RenderVia,
+        route_path: Option<String>,
+        stack: &mut Vec<ComponentKey<'a>>,
+    ) -> RenderNode {
+        let key = (
+            component.base.source_path.as_path(),
+            component.base.name.as_str(),
+        );
+        self.reachable.insert(key);
+        let mut node = RenderNode {
+            component: component.base.name.clone(),
+            file: component.base.source_path.clone(),
+            project: self.project_of(&component.base.source_path),
+            via,
+            route_path,
+            recursive: stack.contains(&key),
+            repeated: false,
+            children: Vec::new(),
+        };
+        if node.recursive {
+            return node;
+        }
+        if !self.expanded.insert(key) {
+            node.repeated = true;
+            return node;
+        }
+
+        stack.push(key);
+        let children: Vec<ComponentKey> = self
+            .template_children
+            .get(&key)
+            .map(|children| children.iter().copied().collect())
+            .unwrap_or_default();
+        for child in children {
+            if let Some(child) = self.components.get(&child).copied() {
+                let child_node = self.node(child, RenderVia::Template, None, stack);
+                node.children.push(child_node);
+            }
+        }
+        stack.pop();
+        node
+    }
+
+    /// Nodes for one route: its component (eager or lazy) with nested
+    /// children, or — for a componentless route — the children directly.
+    /// `tables` holds the `loadChildren` tables on the path from the root.
+    fn route_nodes(
+        &mut self,
+        route: &'a NgRouteInfo,
+        file: &'a Path,
+        lazy: bool,
+        stack: &mut Vec<ComponentKey<'a>>,
+        tables: &mut BTreeSet<&'a Path>,
+    ) -> Vec<RenderNode> {
+        let eager = route
+            .component
+            .as_deref()
+            .and_then(|name| self.resolve_component(name, file))
+            .map(|component| (component, lazy));
+        let loaded = route
+            .load_component
+            .as_ref()
+            .and_then(|target| self.resolve_lazy_component(target, file))
+            .map(|component| (component, true));
+
+        let mut children = Vec::new();
+        for child in &route.children {
+            children.extend(self.route_nodes(child, file, lazy, stack, tables));
+        }
+        if let Some(target) = &route.load_children {
+            if let Some(table_file) = self.lazy_route_file(target, file) {
+                // A table loading one of the tables it is loaded from
+                // closes a cycle: its routes are already on the path.
+                if tables.insert(table_file) {
+                    if let Some(facts) = self.files.get(table_file).copied() {
+                        for child in &facts.routes {
+                            children
+                                .extend(self.route_nodes(child, table_file, true, stack, tables));
+                        }
+                    }
+                    tables.remove(table_file);
+                }
+            }
+        }
+
+        match eager.or(loaded) {
+            Some((component, lazy)) => {
+                let via = if lazy {
+                    RenderVia::LazyRoute
+                } else {
+                    RenderVia::Route
+                };
+                let mut node = self.node(component, via, route.path.clone(), stack);
+                node.children.extend(children);
+                vec![node]
+            }
+            None => children,
+        }
+    }
+
+    /// `name` as seen from `file`: imported binding → declaring file, then a
+    /// component declared in `file` itself.
+    fn resolve_component(&self, name: &str, file: &Path) -> Option<&'a NgComponentInfo> {
+        let facts = self.files.get(file)?;
+        let imported = facts
+            .imports
+            .iter()
+            .find(|import| import.imported_item.name == name)
+            .and_then(|import| {
+                let exported = import
+                    .imported_item
+                    .alias
+                    .as_deref()
+                    .unwrap_or(&import.imported_item.name);
+                self.components
+                    .get(&(import.resolved_path.as_path(), exported))
+                    .copied()
+            });
+        imported.or_else(|| self.components.get(&(file, name)).copied())
+    }
+
+    /// Dynamic imports resolve to the module file (often a barrel), so the
+    /// component is looked up by export name within the target's project.
+    fn resolve_lazy_component(
+        &self,
+        target: &LazyTarget,
+        file: &Path,
+    ) -> Option<&'a NgComponentInfo> {
+        let resolved = self.lazy_resolved_path(target, file)?;
+        let project = self.project_of(resolved);
+        let export = target.export.as_deref();
+        self.results.components.iter().find(|component| {
+            let name_matches = match export {
+                Some(name) => component.base.name == name,
+                None => component.base.source_path == resolved,
+            };
+            name_matches && self.project_of(&component.base.source_path) == project
+        })
+    }
+
+    /// The file holding the route table a `loadChildren` points at. With a
+    /// `.then(m => m.X)` name: the resolved file when it declares `X`, else
+    /// the file its re-exports of `X` lead to; a project-wide search for a
+    /// table exporting `X` is the last resort (an unresolved re-export).
+    /// Without one: the resolved file itself.
+    fn lazy_route_file(&self, target: &LazyTarget, file: &Path) -> Option<&'a Path> {
+        let resolved = self.lazy_resolved_path(target, file)?;
+        let Some(name) = target.export.as_deref() else {
+            return self
+                .files
+                .get(resolved)
+                .filter(|facts| !facts.routes.is_empty())
+                .map(|facts| facts.path.as_path());
+        };
+        if let Some(table) = self.route_table_exporting(resolved, name, &mut HashSet::new()) {
+            return Some(table);
+        }
+        let project = self.project_of(resolved);
+        self.results
+            .source_files
+            .iter()
+            .filter(|facts| !facts.routes.is_empty() && self.project_of(&facts.path) == project)
+            .find(|facts| {
+                facts
+                    .exports
+                    .iter()
+                    .any(|export| export.name == name && export.from_module.is_none())
+            })
+            .map(|facts| facts.path.as_path())
+    }
+
+    /// The route table `name` exported from `path` comes from, following
+    /// `export { name } from` and `export *` chains.
+    fn route_table_exporting(
+        &self,
+        path: &Path,
+        name: &str,
+        seen: &mut HashSet<&'a Path>,
+    ) -> Option<&'a Path> {
+        let facts = self.files.get(path).copied()?;
+        if !seen.insert(facts.path.as_path()) {
+            return None;
+        }
+        let with_routes =
+            |facts: &'a FileFactsInfo| (!facts.routes.is_empty()).then_some(facts.path.as_path());
+        let exports = || facts.exports.iter();
+        if exports().any(|export| export.name == name && export.from_module.is_none()) {
+            return with_routes(facts);
+        }
+        // A named re-export resolves to the declaring file already.
+        if let Some(declaring) = exports()
+            .find(|export| export.name == name && export.from_module.is_some())
+            .and_then(|export| export.resolved_path.as_deref())
+        {
+            return self.files.get(declaring).copied().and_then(with_routes);
+        }
+        exports()
+            .filter(|export| export.kind == ExportKind::ReExportAll)
+            .filter_map(|export| export.resolved_path.as_deref())
+            .find_map(|module| self.route_table_exporting(module, name, seen))
+    }
+
+    fn lazy_resolved_path(&self, target: &LazyTarget, file: &Path) -> Option<&'a Path> {
+        self.files
+            .get(file)?
+            .dynamic_imports
+            .iter()
+            .find(|import| import.source == target.specifier)
+            .map(|import| import.resolved_path.as_path())
+    }
+}
+
+fn collect_load_children(routes: &[NgRouteInfo]) -> Vec<&LazyTarget> {
+    routes
+        .iter()
+        .flat_map(|route| {
+            route
+                .load_children
+                .iter()
+                .chain(collect_load_children(&route.children))
+        })
+        .collect()
+}
+
+/// NgModules some `loadChildren` loads, with the files holding those routes.
+/// A barrel resolves to the module's project, like lazy components.
+pub(crate) fn lazy_module_loaders<'a>(
+    results: &'a NgAnalysisResults,
+    catalog: &ProjectCatalog,
+) -> BTreeMap<(&'a Path, &'a str), BTreeSet<&'a Path>> {
+    let project_of = |path: &Path| catalog.project_of(path).map(|project| &project.name);
+    let mut loaders: BTreeMap<(&Path, &str), BTreeSet<&Path>> = BTreeMap::new();
+    for file in &results.source_files {
+        for target in collect_load_children(&file.routes) {
+            let Some(resolved) = file
+                .dynamic_imports
+                .iter()
+                .find(|import| import.source == target.specifier)
+                .map(|import| import.resolved_path.as_path())
+            else {
+                continue;
+            };
+            let module = results.modules.iter().find(|module| match &target.export {
+                Some(export) => {
+                    module.base.name == *export
+                        && (module.base.source_path == resolved
+                            || project_of(&module.base.source_path) == project_of(resolved))
+                }
+                None => module.base.source_path == resolved,
+            });
+            if let Some(module) = module {
+                loaders
+                    .entry((module.base.source_path.as_path(), module.base.name.as_str()))
+                    .or_default()
+                    .insert(file.path.as_path());
+            }
+        }
+    }
+    loaders
+}
+
+/// The part of the tree bootstrapped in `project` (everything when `None`),
+/// cut off below `depth` levels — the view shared by the `render-tree`
+/// output formats.
+pub fn select(
+    report: &RenderTreeReport,
+    project: Option<&str>,
+    depth: Option<usize>,
+) -> RenderTreeReport {
+    fn truncate(node: &mut RenderNode, depth: usize) {
+        if depth == 0 {
+            node.children.clear();
+        }
+        for child in &mut node.children {
+            truncate(child, depth.saturating_sub(1));
+        }
+    }
+
+    let in_project = |name: &str| project.is_none_or(|project| project == name);
+    RenderTreeReport {
+        roots: report
+            .roots
+            .iter()
+            .filter(|root| in_project(&root.app))
+            .cloned()
+            .map(|mut root| {
+                if let Some(depth) = depth {
+                    truncate(&mut root.node, depth);
+                }
+                root
+            })
+            .collect(),
+        unreachable: report
+            .unreachable
+            .iter()
+            .filter(|component| in_project(&component.project))
+            .cloned()
+            .collect(),
+    }
+}
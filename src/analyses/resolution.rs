@@ -54,7 +54,8 @@ pub fn check_resolution(results: &NgAnalysisResults, catalog: &ProjectCatalog) -
     let mut resolved_imports = 0usize;
 
     for file in &results.source_files {
-        resolved_imports += file.imports.len() + file.dynamic_imports.len();
+        resolved_imports +=
+            file.imports.len() + file.dynamic_imports.len() + file.url_imports.len();
 
         for unresolved in &file.unresolved_imports {
             match unresolved.scope {
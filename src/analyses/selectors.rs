@@ -0,0 +1,227 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::analyses::stats::{DependencyKind, ProjectDependencyInfo};
+use crate::ng::models::NgAnalysisResults;
+use crate::ng::templates::selector::{self, SimpleSelector};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Selector hygiene: prefix conventions and selectors that more than one
+/// entity can match.
+#[derive(Debug, Serialize, Default)]
+pub struct SelectorReport {
+    pub prefix_violations: Vec<PrefixViolation>,
+    /// Pairs that can meet in one template — same project, or both reachable
+    /// from one project — and claim the same element: two components (or
+    /// custom elements) whose selectors overlap, or two directives with an
+    /// identical selector. A directive refining a component's selector
+    /// (`ui-card[elevated]` on `ui-card`) is the intended pattern.
+    pub collisions: Vec<SelectorCollision>,
+}
+
+impl SelectorReport {
+    pub fn is_empty(&self) -> bool {
+        self.prefix_violations.is_empty() && self.collisions.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PrefixViolation {
+    pub name: String,
+    pub kind: String,
+    pub selector: String,
+    pub file: PathBuf,
+    pub project: String,
+    pub expected_prefix: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SelectorCollision {
+    pub first: SelectorOwner,
+    pub second: SelectorOwner,
+    /// The two selectors share an identical alternative; otherwise one
+    /// alternative is strictly more general than the other.
+    pub exact: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SelectorOwner {
+    pub name: String,
+    pub kind: String,
+    pub selector: String,
+    pub file: PathBuf,
+    pub project: String,
+}
+
+struct Entry<'a> {
+    owner: SelectorOwner,
+    alternatives: Vec<SimpleSelector>,
+    path: &'a Path,
+}
+
+pub fn check_selectors(
+    results: &NgAnalysisResults,
+    dependencies: &[ProjectDependencyInfo],
+    catalog: &ProjectCatalog,
+) -> SelectorReport {
+    let project_of = |path: &Path| {
+        catalog
+            .project_of(path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default()
+    };
+
+    let mut entries: Vec<Entry> = results
+        .components
+        .iter()
+        .map(|c| (&c.base, &c.selector, "Component"))
+        .chain(
+            results
+                .directives
+                .iter()
+                .map(|d| (&d.base, &d.selector, "Directive")),
+        )
+        .filter(|(_, selector, _)| !selector.is_empty())
+        .map(|(base, selector, kind)| Entry {
+            owner: SelectorOwner {
+                name: base.name.clone(),
+                kind: kind.to_string(),
+                selector: selector.clone(),
+                file: base.source_path.clone(),
+                project: project_of(&base.source_path),
+            },
+            alternatives: selector::parse_selector(selector),
+            path: base.source_path.as_path(),
+        })
+        .collect();
+    // A custom element sharing a component's tag is a collision too: both
+    // claim the element.
+    entries.extend(results.custom_elements.iter().map(|element| Entry {
+        owner: SelectorOwner {
+            name: element.name.clone(),
+            kind: "CustomElement".to_string(),
+            selector: element.tag.clone(),
+            file: element.source_path.clone(),
+            project: project_of(&element.source_path),
+        },
+        alternatives: selector::parse_selector(&element.tag),
+        path: element.source_path.as_path(),
+    }));
+    entries.sort_by(|a, b| (&a.owner.file, &a.owner.name).cmp(&(&b.owner.file, &b.owner.name)));
+
+    let mut prefix_violations = Vec::new();
+    for entry in &entries {
+        let Some(prefix) = catalog
+            .project_of(entry.path)
+            .and_then(|project| project.prefix.as_deref())
+            .filter(|prefix| !prefix.is_empty())
+        else {
+            continue;
+        };
+        let compliant = entry
+            .alternatives
+            .iter()
+            .all(|alternative| has_prefix(alternative, prefix));
+        if !compliant {
+            prefix_violations.push(PrefixViolation {
+                name: entry.owner.name.clone(),
+                kind: entry.owner.kind.clone(),
+                selector: entry.owner.selector.clone(),
+                file: entry.owner.file.clone(),
+                project: entry.owner.project.clone(),
+                expected_prefix: prefix.to_string(),
+            });
+        }
+    }
+
+    let reach = reachable_projects(dependencies);
+    let share_scope = |a: &str, b: &str| {
+        a == b
+            || reach
+                .values()
+                .any(|projects| projects.contains(a) && projects.contains(b))
+    };
+    let claims_element = |owner: &SelectorOwner| owner.kind != "Directive";
+
+    let mut collisions = Vec::new();
+    for (i, first) in entries.iter().enumerate() {
+        for second in &entries[i + 1..] {
+            if !share_scope(&first.owner.project, &second.owner.project) {
+                continue;
+            }
+            let mut overlap = false;
+            let mut exact = false;
+            for a in &first.alternatives {
+                for b in &second.alternatives {
+                    let (a_general, b_general) = (a.subsumes(b), b.subsumes(a));
+                    overlap |= a_general || b_general;
+                    exact |= a_general && b_general;
+                }
+            }
+            let both_claim = claims_element(&first.owner) && claims_element(&second.owner);
+            let both_directives =
+                first.owner.kind == "Directive" && second.owner.kind == "Directive";
+            if (both_claim && overlap) || (both_directives && exact) {
+                collisions.push(SelectorCollision {
+                    first: first.owner.clone(),
+                    second: second.owner.clone(),
+                    exact,
+                });
+            }
+        }
+    }
+
+    SelectorReport {
+        prefix_violations,
+        collisions,
+    }
+}
+
+/// Each project with every project its code reaches, itself included.
+fn reachable_projects(dependencies: &[ProjectDependencyInfo]) -> HashMap<&str, HashSet<&str>> {
+    let mut depends_on: HashMap<&str, Vec<&str>> = HashMap::new();
+    for dep in dependencies
+        .iter()
+        .filter(|d| d.kind == DependencyKind::Code)
+    {
+        depends_on.entry(&dep.from).or_default().push(&dep.to);
+    }
+    depends_on
+        .keys()
+        .map(|&project| {
+            let mut reached: HashSet<&str> = HashSet::from([project]);
+            let mut work = vec![project];
+            while let Some(next) = work.pop() {
+                for dep in depends_on.get(next).into_iter().flatten() {
+                    if reached.insert(dep) {
+                        work.push(dep);
+                    }
+                }
+            }
+            (project, reached)
+        })
+        .collect()
+}
+
+/// Angular style guide: custom elements are `prefix-name`, attribute
+/// selectors are `prefixName`. A compound selector on a native element
+/// (`button[fixBtn]`) is judged by its attributes; any other element
+/// selector (`mycard`) must be `prefix-name`.
+fn has_prefix(alternative: &SimpleSelector, prefix: &str) -> bool {
+    if let Some(tag) = alternative.element.as_deref() {
+        if tag.contains('-') || alternative.attributes.is_empty() {
+            return tag.starts_with(&format!("{}-", prefix));
+        }
+    }
+    if alternative.attributes.is_empty() {
+        // Class-only — no convention to check.
+        return true;
+    }
+    alternative.attributes.iter().any(|attr| {
+        attr.strip_prefix(prefix).is_some_and(|rest| {
+            rest.is_empty()
+                || rest.starts_with('-')
+                || rest.starts_with(|c: char| c.is_ascii_uppercase())
+        })
+    })
+}
@@ -29,11 +29,23 @@ pub struct ProjectStatsInfo {
     pub instability: f64,
 }
 
+/// What a project dependency goes through. A pair of projects connected both
+/// ways has one entry per kind.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DependencyKind {
+    /// Script imports, template usages and lazy loads.
+    Code,
+    /// Stylesheet `@use`/`@forward`/`@import`; symbols are the specifiers.
+    Style,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ProjectDependencyInfo {
     pub from: String,
     pub to: String,
-    /// Total references (imports + template usages + lazy loads).
+    pub kind: DependencyKind,
+    /// Total references (imports + template usages + lazy loads, or style
+    /// imports for `Style` edges).
     pub count: usize,
     /// True when any of the references is a dynamic `import()`.
     pub lazy: bool,
@@ -51,12 +63,13 @@ pub fn build_stats(
     template_usages: &[TemplateUsageInfo],
     catalog: &ProjectCatalog,
 ) -> StatsReport {
-    // (from_project, to_project) -> (symbol -> count, lazy)
-    let mut edges: BTreeMap<(String, String), (BTreeMap<String, usize>, bool)> = BTreeMap::new();
+    // (from_project, to_project, kind) -> (symbol -> count, lazy)
+    type Edges = BTreeMap<(String, String, DependencyKind), (BTreeMap<String, usize>, bool)>;
+    let mut edges: Edges = BTreeMap::new();
 
-    let mut record = |from: &str, to: &str, symbol: String, lazy: bool| {
+    let mut record = |from: &str, to: &str, kind: DependencyKind, symbol: String, lazy: bool| {
         let entry = edges
-            .entry((from.to_string(), to.to_string()))
+            .entry((from.to_string(), to.to_string(), kind))
             .or_insert_with(|| (BTreeMap::new(), false));
         *entry.0.entry(symbol).or_insert(0) += 1;
         entry.1 |= lazy;
@@ -80,14 +93,20 @@ pub fn build_stats(
                             .clone()
                             .unwrap_or_else(|| import.imported_item.name.clone())
                     };
-                    record(&from.name, &to.name, exported, false);
+                    record(&from.name, &to.name, DependencyKind::Code, exported, false);
                 }
             }
         }
-        for import in &file.dynamic_imports {
+        for import in file.dynamic_imports.iter().chain(&file.url_imports) {
             if let Some(to) = catalog.project_of(&import.resolved_path) {
                 if to.name != from.name {
-                    record(&from.name, &to.name, "*".to_string(), true);
+                    record(
+                        &from.name,
+                        &to.name,
+                        DependencyKind::Code,
+                        "*".to_string(),
+                        true,
+                    );
                 }
             }
         }
@@ -101,25 +120,58 @@ pub fn build_stats(
             continue;
         };
         if from.name != to.name {
-            record(&from.name, &to.name, usage.target.clone(), false);
+            record(
+                &from.name,
+                &to.name,
+                DependencyKind::Code,
+                usage.target.clone(),
+                false,
+            );
+        }
+    }
+
+    for style in &results.style_files {
+        let Some(from) = catalog.project_of(&style.path) else {
+            continue;
+        };
+        for import in &style.imports {
+            let Some(to) = import
+                .resolved_path
+                .as_ref()
+                .and_then(|path| catalog.project_of(path))
+            else {
+                continue;
+            };
+            if to.name != from.name {
+                record(
+                    &from.name,
+                    &to.name,
+                    DependencyKind::Style,
+                    import.specifier.clone(),
+                    false,
+                );
+            }
         }
     }
 
     let dependencies: Vec<ProjectDependencyInfo> = edges
         .iter()
-        .map(|((from, to), (symbols, lazy))| ProjectDependencyInfo {
-            from: from.clone(),
-            to: to.clone(),
-            count: symbols.values().sum(),
-            lazy: *lazy,
-            symbols: symbols
-                .iter()
-                .map(|(name, count)| SymbolUseCount {
-                    name: name.clone(),
-                    count: *count,
-                })
-                .collect(),
-        })
+        .map(
+            |((from, to, kind), (symbols, lazy))| ProjectDependencyInfo {
+                from: from.clone(),
+                to: to.clone(),
+                kind: *kind,
+                count: symbols.values().sum(),
+                lazy: *lazy,
+                symbols: symbols
+                    .iter()
+                    .map(|(name, count)| SymbolUseCount {
+                        name: name.clone(),
+                        count: *count,
+                    })
+                    .collect(),
+            },
+        )
         .collect();
 
     // Per-project aggregates.
@@ -0,0 +1,135 @@
+use crate::analyses::project_map::ProjectCatalog;
+use crate::analyses::unused::Confidence;
+use crate::ng::models::{MemberKind, MemberVisibility, NgAnalysisResults};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// Lifecycle hooks and callbacks Angular (forms, router) invokes by name.
+const FRAMEWORK_CALLBACKS: &[&str] = &[
+    "ngOnChanges",
+    "ngOnInit",
+    "ngDoCheck",
+    "ngAfterContentInit",
+    "ngAfterContentChecked",
+    "ngAfterViewInit",
+    "ngAfterViewChecked",
+    "ngOnDestroy",
+    "writeValue",
+    "registerOnChange",
+    "registerOnTouched",
+    "setDisabledState",
+    "validate",
+    "registerOnValidatorChange",
+    "canDeactivate",
+];
+
+/// Component members a template should be using but no longer does, and
+/// template reads of members the class no longer has. Components extending
+/// another class are skipped — their members are not all visible here.
+#[derive(Debug, Serialize, Default)]
+pub struct TemplateMemberReport {
+    pub unused: Vec<UnusedMember>,
+    /// A compile error under AOT — usually a leftover after a rename.
+    pub missing: Vec<MissingMember>,
+}
+
+impl TemplateMemberReport {
+    pub fn is_empty(&self) -> bool {
+        self.unused.is_empty() && self.missing.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnusedMember {
+    pub component: String,
+    pub member: String,
+    pub kind: MemberKind,
+    pub visibility: MemberVisibility,
+    pub file: PathBuf,
+    pub project: String,
+    /// High for `protected` members — only the template could read them.
+    /// Medium for public ones, which a parent may reach through a query.
+    pub confidence: Confidence,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MissingMember {
+    pub component: String,
+    pub member: String,
+    pub file: PathBuf,
+    pub project: String,
+}
+
+pub fn check_template_members(
+    results: &NgAnalysisResults,
+    catalog: &ProjectCatalog,
+) -> TemplateMemberReport {
+    let mut report = TemplateMemberReport::default();
+    for component in &results.components {
+        let Some(template_members) = &component.template_members else {
+            continue;
+        };
+        if component.extends.is_some() {
+            continue;
+        }
+        let project = catalog
+            .project_of(&component.base.source_path)
+            .map(|project| project.name.clone())
+            .unwrap_or_default();
+        let read: HashSet<&str> = template_members
+            .iter()
+            .chain(&component.member_refs)
+            .map(String::as_str)
+            .collect();
+
+        for member in &component.members {
+            let wired = member.decorated
+                || FRAMEWORK_CALLBACKS.contains(&member.name.as_str())
+                || component.inputs.contains(&member.name)
+                || component.outputs.contains(&member.name);
+            // Unused private members are the compiler's job.
+            if wired || member.visibility == MemberVisibility::Private {
+                continue;
+            }
+            if !read.contains(member.name.as_str()) {
+                report.unused.push(UnusedMember {
+                    component: component.base.name.clone(),
+                    member: member.name.clone(),
+                    kind: member.kind,
+                    visibility: member.visibility,
+                    file: component.base.source_path.clone(),
+                    project: project.clone(),
+                    confidence: match member.visibility {
+                        MemberVisibility::Protected => Confidence::High,
+                        _ => Confidence::Medium,
+                    },
+                });
+            }
+        }
+
+        let declared: HashSet<&str> = component
+            .members
+            .iter()
+            .map(|member| member.name.as_str())
+            .collect();
+        for name in template_members {
+            if !declared.contains(name.as_str()) {
+                report.missing.push(MissingMember {
+                    component: component.base.name.clone(),
+                    member: name.clone(),
+                    file: component.base.source_path.clone(),
+                    project: project.clone(),
+                });
+            }
+        }
+    }
+
+    report.unused.sort_by(|a, b| {
+        (&a.file, &a.component, &a.member).cmp(&(&b.file, &b.component, &b.member))
+    });
+    report.missing.sort_by(|a, b| {
+        (&a.file, &a.component, &a.member).cmp(&(&b.file, &b.component, &b.member))
+    });
+    report
+}
@@ -0,0 +1,389 @@
+use crate::analyses::project_map::{is_test_file, ProjectCatalog};
+use crate::analyses::stats::{DependencyKind, ProjectDependencyInfo};
+use crate::analysis::models::file_facts::TranslationKey;
+use crate::analysis::utils::path_utils::{is_ignored_dir_component, normalize_path};
+use crate::ng::models::NgAnalysisResults;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Directories translation JSON files live in (`src/assets/i18n`,
+/// `public/i18n`, `src/locale`). A subdirectory is a Transloco scope.
+pub(crate) const CATALOG_DIRS: &[&str] = &["i18n", "locale", "locales"];
+
+/// Translation keys compared with the JSON files that define them. Each
+/// directory of language files is checked against the code that can load it:
+/// its own project, projects whose `assets` include it, and everything those
+/// depend on.
+#[derive(Debug, Serialize, Default)]
+pub struct TranslationReport {
+    /// Keys nothing looks up.
+    pub unused: Vec<UnusedTranslation>,
+    /// Keys looked up but absent from a language.
+    pub missing: Vec<MissingTranslation>,
+    /// Keys matched only by a key built at runtime (`'errors.' + code`) —
+    /// probably used, but nothing names them. Lower confidence than `unused`.
+    pub dynamic_only: Vec<DynamicTranslation>,
+}
+
+impl TranslationReport {
+    pub fn is_empty(&self) -> bool {
+        self.unused.is_empty() && self.missing.is_empty() && self.dynamic_only.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnusedTranslation {
+    pub key: String,
+    pub languages: Vec<String>,
+    /// File of the first language defining the key.
+    pub file: PathBuf,
+    pub project: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MissingTranslation {
+    pub key: String,
+    pub language: String,
+    /// The language file the key is missing from.
+    pub file: PathBuf,
+    /// First file looking it up.
+    pub used_in: PathBuf,
+    pub project: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DynamicTranslation {
+    pub key: String,
+    pub prefix: String,
+    pub file: PathBuf,
+    pub used_in: PathBuf,
+    pub project: String,
+}
+
+/// Language files under one catalog directory.
+struct Catalog {
+    root: PathBuf,
+    project: String,
+    /// language → key → defining file.
+    languages: BTreeMap<String, BTreeMap<String, PathBuf>>,
+    /// language → file of the unscoped translations.
+    main_files: BTreeMap<String, PathBuf>,
+}
+
+pub fn check_translations<'a>(
+    results: &'a NgAnalysisResults,
+    dependencies: &'a [ProjectDependencyInfo],
+    catalog: &'a ProjectCatalog,
+) -> TranslationReport {
+    let mut report = TranslationReport::default();
+    let catalogs = find_catalogs(catalog);
+    if catalogs.is_empty() {
+        return report;
+    }
+
+    let mut depends_on: HashMap<&str, Vec<&str>> = HashMap::new();
+    for dep in dependencies
+        .iter()
+        .filter(|d| d.kind == DependencyKind::Code)
+    {
+        depends_on.entry(&dep.from).or_default().push(&dep.to);
+    }
+
+    // (key or prefix, dynamic, file) per project.
+    let mut lookups: HashMap<&str, Vec<(&str, bool, &Path)>> = HashMap::new();
+    let mut record = |file: &'a Path, keys: &'a [TranslationKey]| {
+        if is_test_file(file) {
+            return;
+        }
+        let Some(project) = catalog.project_of(file) else {
+            return;
+        };
+        let entry = lookups.entry(project.name.as_str()).or_default();
+        for key in keys {
+            entry.push((key.key.as_str(), key.dynamic, file));
+        }
+    };
+    for file in &results.source_files {
+        record(&file.path, &file.translation_keys);
+    }
+    for component in &results.components {
+        record(
+            &component.base.source_path,
+            &component.template_translation_keys,
+        );
+    }
+
+    // Projects able to load each catalog, then their dependencies.
+    let scopes: Vec<HashSet<&str>> = catalogs
+        .iter()
+        .map(|translations| {
+            let mut scope: HashSet<&str> = catalog
+                .projects()
+                .filter(|project| {
+                    project.name == translations.project
+                        || project.asset_refs.iter().any(|asset| {
+                            translations.root.starts_with(asset)
+                                || asset.starts_with(&translations.root)
+                        })
+                })
+                .map(|project| project.name.as_str())
+                .collect();
+            let mut work: Vec<&str> = scope.iter().copied().collect();
+            while let Some(project) = work.pop() {
+                for dep in depends_on.get(project).into_iter().flatten() {
+                    if scope.insert(dep) {
+                        work.push(dep);
+                    }
+                }
+            }
+            scope
+        })
+        .collect();
+
+    for (translations, scope) in catalogs.iter().zip(&scopes) {
+        // key → first file looking it up, and the projects doing so.
+        let mut used: BTreeMap<&str, (&Path, BTreeSet<&str>)> = BTreeMap::new();
+        let mut prefixes: Vec<(&str, &Path)> = Vec::new();
+        for project in scope {
+            for &(key, dynamic, file) in lookups.get(project).into_iter().flatten() {
+                if dynamic {
+                    prefixes.push((key, file));
+                } else {
+                    let (first, projects) = used.entry(key).or_insert((file, BTreeSet::new()));
+                    *first = (*first).min(file);
+                    projects.insert(project);
+                }
+            }
+        }
+        prefixes.sort();
+
+        let mut defined: BTreeMap<&str, Vec<(&str, &Path)>> = BTreeMap::new();
+        for (language, keys) in &translations.languages {
+            for (key, file) in keys {
+                defined
+                    .entry(key.as_str())
+                    .or_default()
+                    .push((language.as_str(), file.as_path()));
+            }
+        }
+
+        for (key, languages) in &defined {
+            if used.contains_key(key) {
+                continue;
+            }
+            // Computed `$localize` ids are hashes nothing spells out.
+            if key
+                .strip_prefix("@@")
+                .is_some_and(|id| id.bytes().all(|b| b.is_ascii_digit()))
+            {
+                continue;
+            }
+            let (_, file) = languages[0];
+            match prefixes.iter().find(|(prefix, _)| key.starts_with(prefix)) {
+                Some((prefix, used_in)) => report.dynamic_only.push(DynamicTranslation {
+                    key: key.to_string(),
+                    prefix: prefix.to_string(),
+                    file: file.to_path_buf(),
+                    used_in: used_in.to_path_buf(),
+                    project: translations.project.clone(),
+                }),
+                None => report.unused.push(UnusedTranslation {
+                    key: key.to_string(),
+                    languages: languages.iter().map(|(l, _)| l.to_string()).collect(),
+                    file: file.to_path_buf(),
+                    project: translations.project.clone(),
+                }),
+            }
+        }
+
+        let localize = defined.keys().any(|key| key.starts_with("@@"));
+        for (key, (used_in, projects)) in &used {
+            // A `$localize` catalog only answers `@@id` lookups and vice versa.
+            if key.starts_with("@@") != localize {
+                continue;
+            }
+            for (language, keys) in &translations.languages {
+                // Another catalog loaded by the same code (a lib's next to
+                // the app's) may define it.
+                let defined_elsewhere = catalogs.iter().zip(&scopes).any(|(other, scope)| {
+                    projects.iter().any(|project| scope.contains(project))
+                        && other
+                            .languages
+                            .get(language)
+                            .is_some_and(|keys| keys.contains_key(*key))
+                });
+                if !keys.contains_key(*key) && !defined_elsewhere {
+                    report.missing.push(MissingTranslation {
+                        key: key.to_string(),
+                        language: language.clone(),
+                        file: translations.main_files[language].clone(),
+                        used_in: used_in.to_path_buf(),
+                        project: translations.project.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    // A key no catalog in scope defines is missing from each of them:
+    // report it once, against the catalog of the project looking it up when
+    // it has one.
+    let own_catalog = |missing: &MissingTranslation| {
+        catalog
+            .project_of(&missing.used_in)
+            .is_none_or(|project| project.name != missing.project)
+    };
+    report.missing.sort_by(|a, b| {
+        (&a.key, &a.language, &a.used_in, own_catalog(a), &a.file).cmp(&(
+            &b.key,
+            &b.language,
+            &b.used_in,
+            own_catalog(b),
+            &b.file,
+        ))
+    });
+    report
+        .missing
+        .dedup_by(|a, b| (&a.key, &a.language, &a.used_in) == (&b.key, &b.language, &b.used_in));
+
+    report
+        .unused
+        .sort_by(|a, b| (&a.file, &a.key).cmp(&(&b.file, &b.key)));
+    report
+        .missing
+        .sort_by(|a, b| (&a.file, &a.key).cmp(&(&b.file, &b.key)));
+    report
+        .dynamic_only
+        .sort_by(|a, b| (&a.file, &a.key).cmp(&(&b.file, &b.key)));
+    report
+}
+
+/// Language files of every project, grouped by catalog directory. A file
+/// is a language file when its name is a locale (`en.json`, `pt-BR.json`,
+/// `messages.fr.json`) or it has Angular's `{ "locale", "translations" }`
+/// shape.
+fn find_catalogs(catalog: &ProjectCatalog) -> Vec<Catalog> {
+    let mut catalogs: BTreeMap<PathBuf, Catalog> = BTreeMap::new();
+    for project in catalog.projects() {
+        let files = WalkDir::new(crate::nx::nx_workspace::walkable_root(&project.root))
+            .into_iter()
+            .filter_entry(|entry| {
+                entry.depth() == 0 || !is_ignored_dir_component(entry.file_name())
+            })
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry.file_type().is_file()
+                    && entry.path().extension().is_some_and(|ext| ext == "json")
+            })
+            .map(|entry| normalize_path(entry.path()));
+
+        for file in files {
+            if catalog.project_of(&file).map(|p| &p.name) != Some(&project.name) {
+                continue;
+            }
+            let Some(root) = file.ancestors().skip(1).find(|dir| {
+                dir.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| CATALOG_DIRS.contains(&name))
+            }) else {
+                continue;
+            };
+            let Some(json) = std::fs::read_to_string(&file)
+                .ok()
+                .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            else {
+                continue;
+            };
+
+            let mut keys = BTreeMap::new();
+            let language = match (json.get("locale"), json.get("translations")) {
+                (Some(Value::String(locale)), Some(Value::Object(translations))) => {
+                    for id in translations.keys() {
+                        keys.insert(format!("@@{id}"), file.clone());
+                    }
+                    locale.clone()
+                }
+                _ => {
+                    let Some(language) = language_of(&file) else {
+                        continue;
+                    };
+                    let scope = scope_prefix(root, &file);
+                    flatten(&json, &scope, &file, &mut keys);
+                    language
+                }
+            };
+
+            let entry = catalogs
+                .entry(root.to_path_buf())
+                .or_insert_with(|| Catalog {
+                    root: root.to_path_buf(),
+                    project: project.name.clone(),
+                    languages: BTreeMap::new(),
+                    main_files: BTreeMap::new(),
+                });
+            if file.parent() == Some(root) || !entry.main_files.contains_key(&language) {
+                entry.main_files.insert(language.clone(), file.clone());
+            }
+            entry.languages.entry(language).or_default().extend(keys);
+        }
+    }
+    catalogs.into_values().collect()
+}
+
+/// `en`, `pt-BR`, `zh_Hant` from `en.json`, `messages.pt-BR.json`.
+fn language_of(file: &Path) -> Option<String> {
+    let stem = file.file_stem()?.to_str()?;
+    let language = stem.rsplit('.').next()?;
+    let mut parts = language.split(['-', '_']);
+    let primary = parts.next()?;
+    let valid = (2..=3).contains(&primary.len())
+        && primary.bytes().all(|b| b.is_ascii_lowercase())
+        && parts.all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric()));
+    valid.then(|| language.to_string())
+}
+
+/// Transloco scope of a file in a subdirectory: `i18n/admin-page/en.json`
+/// → `adminPage.` (scopes are camelCased by default).
+fn scope_prefix(root: &Path, file: &Path) -> String {
+    let Some(dir) = file.parent().and_then(|dir| dir.strip_prefix(root).ok()) else {
+        return String::new();
+    };
+    dir.components()
+        .map(|component| {
+            let mut scope = String::new();
+            let mut upper = false;
+            for c in component.as_os_str().to_string_lossy().chars() {
+                match c {
+                    '-' | '_' => upper = true,
+                    c if upper => {
+                        scope.extend(c.to_uppercase());
+                        upper = false;
+                    }
+                    c => scope.push(c),
+                }
+            }
+            scope + "."
+        })
+        .collect()
+}
+
+/// Nested objects become dotted keys (`{ "home": { "title": … } }` →
+/// `home.title`), as both ngx-translate and Transloco read them.
+fn flatten(value: &Value, prefix: &str, file: &Path, keys: &mut BTreeMap<String, PathBuf>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                flatten(value, &format!("{prefix}{key}."), file, keys);
+            }
+        }
+        _ => {
+            let key = prefix.trim_end_matches('.');
+            if !key.is_empty() {
+                keys.insert(key.to_string(), file.to_path_buf());
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::analyses::assets;
+use crate::analyses::module_imports;
 use crate::analyses::project_map::{is_entry_file, is_test_file, ProjectCatalog};
 use crate::analysis::models::file_facts::{ExportInfo, ExportKind, LocalReference};
 use crate::analysis::models::import::ImportKind;
@@ -27,6 +29,15 @@ pub struct UnusedReport {
     pub unused_imports: Vec<UnusedImport>,
     /// Files with no incoming edges at all (and not entry/test files).
     pub orphan_files: Vec<PathBuf>,
+    /// NgModule `imports` entries that contribute nothing to the module.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unused_module_imports: Vec<module_imports::UnusedModuleImport>,
+    /// NgModules that only re-export other modules.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reexport_modules: Vec<module_imports::ReexportModule>,
+    /// Templates, stylesheets and assets nothing references.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub orphan_assets: Vec<assets::OrphanAsset>,
 }
 
 #[derive(Debug, Serialize)]
@@ -170,21 +181,34 @@ pub fn find_unused(
         );
     }
 
-    // Bootstrap components are always live.
-    let bootstrap_names: HashSet<&str> = results
+    // Bootstrap components, and the controllers and providers NestJS
+    // modules register, are created by the framework: always live.
+    let nest_registered = results.nest_modules.iter().flat_map(|module| {
+        module.controllers.iter().map(String::as_str).chain(
+            module
+                .providers
+                .iter()
+                .flat_map(|provider| provider.class.as_ref().into_iter().chain([&provider.token]))
+                .map(String::as_str),
+        )
+    });
+    let framework_names: HashSet<&str> = results
         .modules
         .iter()
         .flat_map(|module| module.bootstrap.iter().map(String::as_str))
+        .chain(nest_registered)
         .collect();
 
     // --- 2. Metadata-only usage detection (declarations/imports arrays). ---
     // name -> set of files where the name appears in decorator metadata.
     let mut metadata_refs: HashMap<&str, HashSet<&Path>> = HashMap::new();
     for component in &results.components {
+        let host_directives = component.host_directives.iter().map(|hd| &hd.directive);
         for name in component
             .standalone_imports
             .iter()
             .chain(component.providers.iter())
+            .chain(host_directives)
         {
             metadata_refs
                 .entry(name)
@@ -192,6 +216,14 @@ pub fn find_unused(
                 .insert(component.base.source_path.as_path());
         }
     }
+    for directive in &results.directives {
+        for hd in &directive.host_directives {
+            metadata_refs
+                .entry(&hd.directive)
+                .or_default()
+                .insert(directive.base.source_path.as_path());
+        }
+    }
     for module in &results.modules {
         for name in module
             .declarations
@@ -248,12 +280,45 @@ pub fn find_unused(
             "Module",
         );
     }
+    for module in &results.nest_modules {
+        entity_kinds.insert(
+            (module.base.source_path.as_path(), &module.base.name),
+            "NestModule",
+        );
+    }
+    for controller in &results.nest_controllers {
+        entity_kinds.insert(
+            (controller.base.source_path.as_path(), &controller.base.name),
+            "Controller",
+        );
+    }
     for component in &results.react_components {
         entity_kinds.insert(
             (component.source_path.as_path(), &component.name),
             "ReactComponent",
         );
     }
+    for hook in &results.react_hooks {
+        entity_kinds.insert((hook.source_path.as_path(), &hook.name), "ReactHook");
+    }
+    for context in &results.react_contexts {
+        entity_kinds.insert(
+            (context.source_path.as_path(), &context.name),
+            "ReactContext",
+        );
+    }
+    for component in &results.vue_components {
+        entity_kinds.insert(
+            (component.source_path.as_path(), &component.name),
+            "VueComponent",
+        );
+    }
+    for element in &results.custom_elements {
+        entity_kinds.insert(
+            (element.source_path.as_path(), &element.name),
+            "CustomElement",
+        );
+    }
 
     // --- 3. Unused exports. ---
     let mut unused_exports = Vec::new();
@@ -263,11 +328,7 @@ pub fn find_unused(
     let is_ambient = |path: &Path| path.to_string_lossy().ends_with(".d.ts");
 
     for file in &results.source_files {
-        if is_test_file(&file.path)
-            || is_entry_file(&file.path)
-            || catalog.is_framework_entry(&file.path)
-            || is_ambient(&file.path)
-        {
+        if is_test_file(&file.path) || is_entry_file(&file.path) || is_ambient(&file.path) {
             continue;
         }
         let project = catalog
@@ -290,14 +351,19 @@ pub fn find_unused(
                     alive_test.insert(export.name.as_str());
                 }
             }
-            if bootstrap_names.contains(export.name.as_str()) {
+            if framework_names.contains(export.name.as_str())
+                || catalog.is_framework_export(&file.path, export)
+            {
                 alive_prod.insert(export.name.as_str());
             }
         }
         let has_dependents = import_graph
             .get_dependents(&file.path)
             .is_some_and(|dependents| !dependents.is_empty());
-        if has_dependents || lazy_reachable.contains(&file.path) {
+        if has_dependents
+            || lazy_reachable.contains(&file.path)
+            || catalog.is_framework_entry(&file.path)
+        {
             alive_prod.insert(""); // top-level statements execute on load
         }
         propagate_liveness(&mut alive_prod, &file.local_references);
@@ -309,12 +375,17 @@ pub fn find_unused(
             if matches!(export.kind, ExportKind::ReExport | ExportKind::ReExportAll) {
                 continue;
             }
-            if bootstrap_names.contains(export.name.as_str()) {
+            if framework_names.contains(export.name.as_str()) {
                 continue;
             }
             if lazy_reachable.contains(&file.path) {
                 continue;
             }
+            // A page's default export and data loaders are read by the
+            // framework; a helper exported next to them is not.
+            if catalog.is_framework_export(&file.path, export) {
+                continue;
+            }
 
             let kind = entity_kinds
                 .get(&(file.path.as_path(), export.name.as_str()))
@@ -363,7 +434,7 @@ pub fn find_unused(
         if template_used.contains(&(path, name)) {
             return;
         }
-        if bootstrap_names.contains(name) {
+        if framework_names.contains(name) {
             return;
         }
         if lazy_reachable.contains(path) {
@@ -457,6 +528,10 @@ pub fn find_unused(
     unused_imports
         .sort_by(|a, b| (&a.file, &a.name, &a.specifier).cmp(&(&b.file, &b.name, &b.specifier)));
 
+    let (unused_module_imports, reexport_modules) =
+        module_imports::check_module_imports(results, template_usages, catalog);
+    let orphan_assets = assets::find_orphan_assets(results, catalog);
+
     UnusedReport {
         unused_exports,
         test_only_exports,
@@ -464,6 +539,9 @@ pub fn find_unused(
         declared_not_rendered,
         unused_imports,
         orphan_files,
+        unused_module_imports,
+        reexport_modules,
+        orphan_assets,
     }
 }
 
@@ -14,6 +14,11 @@ pub struct FileFactsInfo {
     pub imports: Vec<ResolvedImport>,
     /// `import('...')` expressions — lazy edges (Angular routes, React.lazy).
     pub dynamic_imports: Vec<ResolvedImport>,
+    /// `new URL('./x', import.meta.url)` references, as `ImportKind::Worker`
+    /// (inside `new Worker(...)` and friends) or `ImportKind::Url` — lazy
+    /// edges to files loaded at run time, never imported from.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub url_imports: Vec<ResolvedImport>,
     /// Local names of imports actually referenced in the file body
     /// (identifier or type usage — covers DI constructor types). An import
     /// whose local name is absent here is a leftover statement: it must NOT
@@ -23,14 +28,126 @@ pub struct FileFactsInfo {
     /// missing from the graph. Deduplicated per file.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub unresolved_imports: Vec<UnresolvedImport>,
+    /// Local names imported from npm packages (`NG_VALUE_ACCESSOR` from
+    /// `@angular/forms`) — the package, not the workspace, declares them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub package_imports: Vec<String>,
     /// JSX component usages (React .tsx files).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub jsx_usages: Vec<crate::analysis::models::react::JsxUsageInfo>,
+    /// Compound components declared here, for resolving `<Form.Field>`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compound_components: Vec<crate::analysis::models::react::CompoundComponent>,
+    /// Calls of custom hooks imported from the workspace or declared here.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hook_calls: Vec<crate::analysis::models::react::HookCall>,
+    /// Contexts read with `useContext(X)` / `use(X)`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub context_reads: Vec<crate::analysis::models::react::ContextRead>,
     /// Same-file references between top-level declarations — a union member
     /// referenced by an exported union type is alive when the union is, even
     /// though nobody imports the member directly.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub local_references: Vec<LocalReference>,
+    /// Components passed to `bootstrapApplication(...)` — roots of a
+    /// standalone Angular app.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bootstrap_calls: Vec<String>,
+    /// Angular route tables (`Routes` array literals) declared in this file;
+    /// nested `children` stay inside their parent route.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes: Vec<crate::ng::models::ng_route::NgRouteInfo>,
+    /// DI lookups: constructor parameter types, `@Inject(TOKEN)` parameters
+    /// and `inject(X)` calls.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub injections: Vec<InjectionSite>,
+    /// Components created in code rather than in a template:
+    /// `createComponent(X)`, `dialog.open(X)`, `new ComponentPortal(X)`, …
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dynamic_renders: Vec<DynamicRender>,
+    /// Translation lookups in code: `translate.instant('x')`, `t('x')`,
+    /// `$localize`:@@x:…``.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translation_keys: Vec<TranslationKey>,
+    /// NgRx actions, selectors, effects and reducers, and where actions are
+    /// dispatched, handled and selectors selected.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::ng::models::ngrx::NgRxFacts::is_empty"
+    )]
+    pub ngrx: crate::ng::models::ngrx::NgRxFacts,
+    /// `'use client'` / `'use server'` opening the file (React Server
+    /// Components, Next.js App Router).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directive: Option<ModuleDirective>,
+    /// Top-level declarations holding inline server actions: functions
+    /// whose body opens with `'use server'`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub server_actions: Vec<String>,
+    /// Custom element tags in JSX and Lit `html` templates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub element_tags: Vec<crate::analysis::models::web_component::ElementTagUsage>,
+    /// Class names read off imported CSS modules (`styles.card`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub css_modules: Vec<crate::analysis::models::style::CssModuleUsage>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleDirective {
+    /// `'use client'` — the file and everything it imports is client code.
+    #[serde(rename = "use client")]
+    UseClient,
+    /// `'use server'` — every export is a server action.
+    #[serde(rename = "use server")]
+    UseServer,
+}
+
+/// A translation lookup — `'home.title' | translate`, `translate.instant('x')`,
+/// `t('x')`, `$localize`:@@x:…`` or `i18n="@@x"`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TranslationKey {
+    /// The key; for a key built at runtime (`'errors.' + code`), the literal
+    /// prefix it starts with.
+    pub key: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dynamic: bool,
+}
+
+impl TranslationKey {
+    pub fn literal(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            dynamic: false,
+        }
+    }
+
+    pub fn prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            key: prefix.into(),
+            dynamic: true,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct DynamicRender {
+    /// Class making the call; `None` outside any class.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Identifier passed as the first argument — resolved to a component
+    /// during template analysis.
+    pub component: String,
+    /// Function, method or constructor that creates it.
+    pub opener: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct InjectionSite {
+    /// Enclosing class; `None` for functional guards, interceptors and
+    /// factories outside any class.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    pub token: String,
 }
 
 /// `from` declares a top-level name (`""` = top-level statements, which run
@@ -54,6 +171,10 @@ pub struct ExportInfo {
     /// pass-through that can never receive an inbound edge.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub from_module: Option<String>,
+    /// Where a re-export leads: the file declaring `X` for
+    /// `export { X } from './y'`, the module itself for `export *`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_path: Option<PathBuf>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -7,6 +7,10 @@ pub struct ResolvedImport {
     pub resolved_path: PathBuf, // Absolute resolved path
     pub import_type: ImportType,
     pub imported_item: ImportedItem,
+    /// `import type { X }` / `import { type X }` — erased at compile time,
+    /// so nothing of the target ends up in the bundle.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub type_only: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -17,6 +21,12 @@ pub enum ImportKind {
     /// `import './polyfills'` — no local binding; runs the module for its
     /// top-level effects and keeps it (but none of its exports) alive.
     SideEffect,
+    /// `new Worker(new URL('./x.worker', import.meta.url))` — the target
+    /// runs in a thread of its own: loaded, but nothing is imported from it.
+    Worker,
+    /// `new URL('./logo.svg', import.meta.url)` — the target is emitted by
+    /// the bundler and fetched at run time.
+    Url,
 }
 
 impl fmt::Display for ResolvedImport {
@@ -38,6 +48,8 @@ impl fmt::Display for ImportKind {
             ImportKind::Default => write!(f, "default"),
             ImportKind::Namespace => write!(f, "namespace"),
             ImportKind::SideEffect => write!(f, "side-effect"),
+            ImportKind::Worker => write!(f, "worker"),
+            ImportKind::Url => write!(f, "url"),
         }
     }
 }
@@ -50,6 +62,10 @@ impl fmt::Display for ImportKind {
 pub struct UnresolvedImport {
     pub specifier: String,
     pub scope: UnresolvedScope,
+    /// Only ever imported with `import type` — still a missing edge, but
+    /// nothing of it is loaded at runtime.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub type_only: bool,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
@@ -1,4 +1,7 @@
 pub mod file_facts;
 pub mod import;
 pub mod react;
+pub mod style;
 pub mod ts_config;
+pub mod vue;
+pub mod web_component;
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 /// A React function component found in a .tsx file: a capitalized top-level
@@ -11,11 +12,94 @@ pub struct ReactComponentInfo {
     pub package_name: String,
     /// True when wrapped in memo()/forwardRef().
     pub wrapped: bool,
+    /// Declared props, from the props parameter's type and destructuring.
+    /// `None` when the type is declared in another file or not at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub props: Option<Vec<ReactProp>>,
 }
 
-/// One `<Component prop1 prop2={...}>` occurrence in JSX.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReactProp {
+    pub name: String,
+    /// `name?:` in the type, or a default value in the destructuring.
+    pub optional: bool,
+}
+
+/// An exported custom hook: a top-level function named `use` + capital
+/// (`useCart`, `useMediaQuery`), in any script file.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReactHookInfo {
+    pub name: String,
+    pub source_path: PathBuf,
+    pub package_name: String,
+}
+
+/// A React context: a top-level `const ThemeContext = createContext(…)`, in
+/// any script file.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReactContextInfo {
+    pub name: String,
+    pub source_path: PathBuf,
+    pub package_name: String,
+}
+
+/// `useContext(ThemeContext)` or `use(ThemeContext)` on a workspace name.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ContextRead {
+    /// As written: `ThemeContext`, `contexts.ThemeContext`.
+    pub context: String,
+    /// Top-level declaration the read sits in — a component, or a hook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller: Option<String>,
+}
+
+/// A call of a workspace hook, `useCart()`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HookCall {
+    pub hook: String,
+    /// Top-level declaration the call sits in — a component, or another
+    /// hook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller: Option<String>,
+}
+
+/// A top-level name whose properties are components — the target of
+/// `<Form.Field>` tags:
+///
+/// ```tsx
+/// export const Form = Object.assign(FormRoot, { Field: FormField });
+/// export const Form = { Field: FormField, Label };
+/// FormRoot.Field = FormField;
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CompoundComponent {
+    pub name: String,
+    /// The component `<Form>` itself renders: the first argument of
+    /// `Object.assign`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<String>,
+    /// Property → name of the component it holds, both as written here.
+    pub members: BTreeMap<String, String>,
+}
+
+/// One `<Component prop1 prop2={...}>` occurrence in JSX. `component` is the
+/// tag as written — `Form.Field` and `UI.Button` for member tags.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct JsxUsageInfo {
     pub component: String,
     pub props: Vec<String>,
+    /// Has content between its tags — passes `children`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub children: bool,
+    /// Has a `{...props}` spread — may pass any prop.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub spread: bool,
+    /// Top-level declaration the element sits in — the component rendering
+    /// it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller: Option<String>,
+    /// Tags of the component elements enclosing this one, outermost first:
+    /// `["ThemeContext.Provider", "Layout"]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub within: Vec<String>,
 }
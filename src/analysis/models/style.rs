@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Facts about one stylesheet (`.css`, `.scss`, `.sass`, `.less`) — the
+/// style counterpart of [`FileFactsInfo`](super::file_facts::FileFactsInfo).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StyleFileInfo {
+    pub path: PathBuf,
+    /// `@use`, `@forward` and `@import` targets, in source order. Built-in
+    /// Sass modules (`sass:math`) are not recorded.
+    pub imports: Vec<StyleImport>,
+    /// Relative `url(...)` references, resolved against the file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<PathBuf>,
+    /// Class selectors a CSS module (`*.module.scss`) defines, nesting
+    /// resolved; `:global(...)` classes are not the module's.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub classes: Vec<String>,
+    /// Classes its rules pull in with `composes:`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub composes: Vec<ComposedClass>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ComposedClass {
+    pub class: String,
+    /// The module of `composes: x from './other.module.css'`; `None` for a
+    /// class of the same module.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<PathBuf>,
+}
+
+/// How a script reads one CSS module it imports: `styles.card`,
+/// `styles['card-title']`, or `import { card } from './x.module.css'`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CssModuleUsage {
+    pub path: PathBuf,
+    pub classes: Vec<String>,
+    /// The binding is also used whole — spread, passed on, or indexed with
+    /// a computed key — so any class may be read.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dynamic: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct StyleImport {
+    pub specifier: String,
+    /// `None` for packages and anything that does not exist on disk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_path: Option<PathBuf>,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// A Vue single-file component — every `.vue` file. Named after the file,
+/// the way it is imported and registered.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VueComponentInfo {
+    pub name: String,
+    pub source_path: PathBuf,
+    pub package_name: String,
+    /// Written with `<script setup>`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub setup: bool,
+    /// Components its template renders, in PascalCase as written
+    /// (`<user-card>` is `UserCard`) — resolved to files by
+    /// `analyze_templates`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub template_components: Vec<String>,
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// A custom element: a class registered with
+/// `customElements.define('ui-button', UiButton)` or decorated with Lit's
+/// `@customElement('ui-button')`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CustomElementInfo {
+    /// The class; the tag for an anonymous `class extends HTMLElement`.
+    pub name: String,
+    pub tag: String,
+    pub source_path: PathBuf,
+    pub package_name: String,
+}
+
+/// A custom element tag written outside Angular templates, one per
+/// occurrence. Tags of elements nobody registers are kept too — a library
+/// outside the workspace may define them.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ElementTagUsage {
+    pub tag: String,
+    pub via: ElementTagVia,
+    /// Top-level declaration the tag sits in — a component, a Lit element.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ElementTagVia {
+    /// A JSX intrinsic element, `<ui-button>`.
+    Jsx,
+    /// An element in a Lit `` html`…` `` template.
+    LitHtml,
+}
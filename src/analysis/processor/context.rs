@@ -14,4 +14,7 @@ pub struct AnalysisContext {
     /// Angular >= 19: components without an explicit `standalone:` flag are
     /// standalone by default.
     pub default_standalone: bool,
+    /// Functions/methods whose first argument is a component they create
+    /// (`createComponent`, `open`, … plus `componentOpeners` from config).
+    pub component_openers: Arc<Vec<String>>,
 }
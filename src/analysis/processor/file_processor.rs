@@ -60,6 +60,7 @@ impl ProjectProcessor {
             source_map,
             file_reader: shared.file_reader.clone(),
             default_standalone,
+            component_openers: Arc::default(),
         };
 
         ProjectProcessor {
@@ -77,6 +78,12 @@ impl ProjectProcessor {
         self
     }
 
+    /// Functions/methods whose first argument is a component they create.
+    pub fn with_component_openers(mut self, openers: Arc<Vec<String>>) -> Self {
+        self.context.component_openers = openers;
+        self
+    }
+
     /// Prunes roots of OTHER projects nested inside this one — their files
     /// belong to the nested project and must not be processed twice.
     pub fn exclude_nested_roots(mut self, roots: Vec<PathBuf>) -> Self {
@@ -84,26 +91,20 @@ impl ProjectProcessor {
         self
     }
 
-    /// Restricts analysis to TypeScript files (.ts/.tsx).
+    /// Restricts analysis to TypeScript files (.ts/.tsx), and Vue SFCs —
+    /// their scripts are TypeScript in a TypeScript workspace.
     pub fn filter_ts_files(self) -> Self {
         self.filter(|entry| {
             entry
                 .path()
                 .extension()
-                .is_some_and(|ext| ext == "ts" || ext == "tsx")
+                .is_some_and(|ext| ext == "ts" || ext == "tsx" || ext == "vue")
         })
     }
 
     /// Restricts analysis to any parseable script files.
     pub fn filter_script_files(self) -> Self {
-        self.filter(|entry| {
-            entry.path().extension().is_some_and(|ext| {
-                matches!(
-                    ext.to_string_lossy().as_ref(),
-                    "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" | "mts" | "cts"
-                )
-            })
-        })
+        self.filter(|entry| crate::analysis::utils::path_utils::is_script_file(entry.path()))
     }
 
     pub fn filter<P>(mut self, predicate: P) -> Self
@@ -1,3 +1,4 @@
+use crate::analysis::utils::path_utils::is_script_file;
 use dashmap::DashMap;
 use petgraph::algo::tarjan_scc;
 use petgraph::graph::{DiGraph, NodeIndex};
@@ -9,6 +10,9 @@ use std::sync::Arc;
 pub struct ImportGraph {
     dependencies: Arc<DashMap<PathBuf, HashSet<PathBuf>>>,
     reverse_dependencies: Arc<DashMap<PathBuf, HashSet<PathBuf>>>,
+    /// The subset of `dependencies` loaded by URL — workers and assets
+    /// behind `new URL('./x', import.meta.url)`.
+    url_dependencies: Arc<DashMap<PathBuf, HashSet<PathBuf>>>,
 }
 
 /// Serializable snapshot of the graph, sorted for deterministic output.
@@ -16,12 +20,20 @@ pub struct ImportGraph {
 pub struct ImportGraphSnapshot {
     pub edges: Vec<ImportGraphEdge>,
     pub circular_dependencies: Vec<Vec<PathBuf>>,
+    /// Imported files that are not scripts — JSON, images, stylesheets.
+    /// Leaves of the graph: nothing is parsed out of them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub non_script_nodes: Vec<PathBuf>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct ImportGraphEdge {
     pub from: PathBuf,
     pub to: Vec<PathBuf>,
+    /// Targets of `to` loaded by URL rather than imported: lazy edges to
+    /// workers and assets.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub url: Vec<PathBuf>,
 }
 
 impl ImportGraph {
@@ -29,6 +41,7 @@ impl ImportGraph {
         Self {
             dependencies: Arc::new(DashMap::new()),
             reverse_dependencies: Arc::new(DashMap::new()),
+            url_dependencies: Arc::new(DashMap::new()),
         }
     }
 
@@ -44,6 +57,16 @@ impl ImportGraph {
             .insert(source);
     }
 
+    /// Marks `source → target` as loaded by URL; the edge itself counts like
+    /// any other.
+    pub fn add_url_dependency(&self, source: PathBuf, target: PathBuf) {
+        self.add_dependency(source.clone(), target.clone());
+        self.url_dependencies
+            .entry(source)
+            .or_default()
+            .insert(target);
+    }
+
     #[allow(dead_code)] // query API for upcoming analyses (unused/stats)
     pub fn get_dependencies(&self, file: &Path) -> Option<HashSet<PathBuf>> {
         self.dependencies.get(file).map(|deps| deps.clone())
@@ -72,6 +95,20 @@ impl ImportGraph {
         all_deps
     }
 
+    /// Import targets that are not script modules (`./config.json`,
+    /// `./logo.svg?react`, `./x.module.css`), sorted.
+    pub fn non_script_nodes(&self) -> Vec<PathBuf> {
+        let mut nodes: Vec<PathBuf> = self
+            .reverse_dependencies
+            .iter()
+            .map(|entry| entry.key().clone())
+            // Opaque packages resolve to their directory.
+            .filter(|path| !is_script_file(path) && path.is_file())
+            .collect();
+        nodes.sort();
+        nodes
+    }
+
     /// Finds circular dependencies as strongly connected components
     /// (Tarjan, iterative — no recursion, no missed cycles).
     /// Every returned group has at least 2 files, or is a self-loop.
@@ -124,9 +161,16 @@ impl ImportGraph {
             .map(|entry| {
                 let mut targets: Vec<PathBuf> = entry.value().iter().cloned().collect();
                 targets.sort();
+                let mut url: Vec<PathBuf> = self
+                    .url_dependencies
+                    .get(entry.key())
+                    .map(|url| url.iter().cloned().collect())
+                    .unwrap_or_default();
+                url.sort();
                 ImportGraphEdge {
                     from: entry.key().clone(),
                     to: targets,
+                    url,
                 }
             })
             .collect();
@@ -135,6 +179,7 @@ impl ImportGraph {
         ImportGraphSnapshot {
             edges,
             circular_dependencies: self.analyze_circular_dependencies(),
+            non_script_nodes: self.non_script_nodes(),
         }
     }
 }
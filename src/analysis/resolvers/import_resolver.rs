@@ -31,6 +31,11 @@ impl ImportResolver {
         }
     }
 
+    /// The graph every resolved import is added to.
+    pub fn import_graph(&self) -> &ImportGraph {
+        &self.import_graph
+    }
+
     pub fn resolve_import(
         &mut self,
         import_path: &str,
@@ -106,6 +111,7 @@ impl ImportResolver {
                 alias: None,
                 import_kind: ImportKind::Named,
             },
+            type_only: false,
         }
     }
 }
@@ -3,3 +3,4 @@ pub mod import_graph;
 pub mod import_resolver;
 pub mod parsers;
 pub mod resolver;
+pub mod style_resolver;
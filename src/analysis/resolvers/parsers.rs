@@ -1,3 +1,4 @@
+use crate::ng::templates::sfc::{self, Sfc};
 use dashmap::DashMap;
 use std::collections::{HashMap, HashSet};
 use std::fs;
@@ -51,7 +52,13 @@ impl ModuleCache {
         }
 
         let table = Self::parse(path)
-            .map(|module| Arc::new(Self::extract(&module)))
+            .map(|module| {
+                let mut table = Self::extract(&module);
+                // `<script setup>` has no `export default`, yet the SFC is
+                // the default export of its file.
+                table.has_default |= sfc::is_vue(path);
+                Arc::new(table)
+            })
             .or(None);
         self.tables.insert(path.to_path_buf(), table.clone());
         table
@@ -110,16 +117,17 @@ impl ModuleCache {
     }
 
     fn parse(path: &Path) -> Option<Module> {
-        let source = fs::read_to_string(path).ok()?;
+        let mut source = fs::read_to_string(path).ok()?;
+        let mut syntax = crate::ng::visitors::syntax_for(path);
+        if sfc::is_vue(path) {
+            let parsed = Sfc::parse(&source);
+            syntax = crate::ng::visitors::syntax(parsed.jsx);
+            source = parsed.script;
+        }
         let cm = SourceMap::default();
         let fm = cm.new_source_file(Arc::from(FileName::Real(path.to_path_buf())), source);
 
-        let lexer = Lexer::new(
-            crate::ng::visitors::syntax_for(path),
-            Default::default(),
-            StringInput::from(&*fm),
-            None,
-        );
+        let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
 
         Parser::new_from(lexer).parse_module().ok()
     }
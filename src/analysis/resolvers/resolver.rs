@@ -73,6 +73,12 @@ impl ImportPathResolver {
         ts_paths: &HashMap<String, Vec<String>>,
         base_url: Option<&str>,
     ) -> (Option<PathBuf>, ImportType) {
+        // Bundler query suffixes — `./logo.svg?react`, `./shader.glsl?raw`,
+        // `./worker?worker` — select a loader, not a different file.
+        let import_path = import_path
+            .split_once('?')
+            .map_or(import_path, |(path, _)| path);
+
         if import_path.starts_with("./") || import_path.starts_with("../") {
             return (
                 self.resolve_relative_import(import_path, current_file),
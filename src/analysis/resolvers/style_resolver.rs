@@ -0,0 +1,504 @@
+use crate::analysis::models::style::{ComposedClass, StyleFileInfo, StyleImport};
+use crate::analysis::models::ts_config::TSConfig;
+use crate::analysis::utils::path_utils::{is_ignored_dir_component, normalize_path};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+const STYLE_EXTENSIONS: &[&str] = &["scss", "sass", "css", "less"];
+
+pub fn is_style_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| STYLE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+/// `card.module.scss` — class names are scoped to the file and read off
+/// its import binding.
+pub fn is_css_module(path: &Path) -> bool {
+    is_style_file(path)
+        && path.file_stem().is_some_and(|stem| {
+            Path::new(stem)
+                .extension()
+                .is_some_and(|ext| ext == "module")
+        })
+}
+
+/// Resolves stylesheet module specifiers the way Sass does: relative to the
+/// importing file, then through `stylePreprocessorOptions.includePaths`, then
+/// through aliases — `styleAliases` from `nx-analyzer.json` first, tsconfig
+/// `paths` after.
+pub struct StyleResolver {
+    include_paths: Vec<PathBuf>,
+    /// (alias, targets, directory the targets are relative to).
+    aliases: Vec<(String, Vec<String>, PathBuf)>,
+}
+
+impl StyleResolver {
+    pub fn new(
+        workspace_root: &Path,
+        include_paths: Vec<PathBuf>,
+        style_aliases: &HashMap<String, Vec<String>>,
+        ts_config: &TSConfig,
+    ) -> Self {
+        let mut aliases: Vec<(String, Vec<String>, PathBuf)> = style_aliases
+            .iter()
+            .map(|(alias, targets)| (alias.clone(), targets.clone(), workspace_root.into()))
+            .collect();
+        if let Some(options) = &ts_config.compiler_options {
+            // `baseUrl` is already anchored (see ImportPathResolver).
+            let base = options
+                .base_url
+                .as_ref()
+                .map_or_else(|| workspace_root.to_path_buf(), PathBuf::from);
+            for (alias, targets) in options.paths.iter().flatten() {
+                aliases.push((alias.clone(), targets.clone(), base.clone()));
+            }
+        }
+        Self {
+            include_paths,
+            aliases,
+        }
+    }
+
+    pub fn resolve(&self, specifier: &str, from_file: &Path) -> Option<PathBuf> {
+        // Webpack-era `~` prefix: "look up the module path, not relative".
+        let specifier = specifier.strip_prefix('~').unwrap_or(specifier);
+        if specifier.contains("://") || specifier.starts_with('/') {
+            return None;
+        }
+        let dir = from_file.parent().unwrap_or(Path::new(""));
+        if let Some(resolved) = resolve_style_module(dir.join(specifier)) {
+            return Some(resolved);
+        }
+        for include in &self.include_paths {
+            if let Some(resolved) = resolve_style_module(include.join(specifier)) {
+                return Some(resolved);
+            }
+        }
+        self.resolve_alias(specifier)
+    }
+
+    /// Exact aliases first, then the longest wildcard prefix (TS semantics).
+    fn resolve_alias(&self, specifier: &str) -> Option<PathBuf> {
+        for (alias, targets, base) in &self.aliases {
+            if alias == specifier {
+                let found = targets
+                    .iter()
+                    .find_map(|target| resolve_style_module(base.join(target)));
+                if found.is_some() {
+                    return found;
+                }
+            }
+        }
+        let mut wildcards: Vec<_> = self
+            .aliases
+            .iter()
+            .filter(|(alias, _, _)| alias.contains('*'))
+            .collect();
+        wildcards.sort_by_key(|(alias, _, _)| std::cmp::Reverse(alias.len()));
+        for (alias, targets, base) in wildcards {
+            let (prefix, suffix) = alias.split_once('*').unwrap_or((alias, ""));
+            let Some(matched) = specifier
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+            else {
+                continue;
+            };
+            let found = targets
+                .iter()
+                .find_map(|target| resolve_style_module(base.join(target.replace('*', matched))));
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+}
+
+/// A style module path to the stylesheet on disk: as written, with a style
+/// extension, as a `_partial`, or as a directory's `_index`/`index`. Only
+/// stylesheets count — an alias shared with TypeScript (`@fix/ui` →
+/// `index.ts`) is no style dependency.
+fn resolve_style_module(base: PathBuf) -> Option<PathBuf> {
+    let name = base.file_name()?.to_string_lossy().to_string();
+    let parent = base.parent()?.to_path_buf();
+    if is_style_file(&base) {
+        return [base.clone(), parent.join(format!("_{name}"))]
+            .into_iter()
+            .find(|candidate| candidate.is_file())
+            .map(normalize_path);
+    }
+    STYLE_EXTENSIONS
+        .iter()
+        .flat_map(|ext| {
+            [
+                parent.join(format!("{name}.{ext}")),
+                parent.join(format!("_{name}.{ext}")),
+                base.join(format!("_index.{ext}")),
+                base.join(format!("index.{ext}")),
+            ]
+        })
+        .find(|candidate| candidate.is_file())
+        .map(normalize_path)
+}
+
+/// Parses every stylesheet under `project_root`, leaving out `nested_roots`
+/// (other projects own those files).
+pub fn collect_style_files(
+    project_root: &Path,
+    nested_roots: &[PathBuf],
+    resolver: &StyleResolver,
+) -> Vec<StyleFileInfo> {
+    WalkDir::new(crate::nx::nx_workspace::walkable_root(project_root))
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !(is_ignored_dir_component(entry.file_name())
+                    || nested_roots.iter().any(|nested| entry.path() == nested))
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file() && is_style_file(entry.path()))
+        .filter_map(|entry| {
+            let path = normalize_path(entry.path());
+            let content = std::fs::read_to_string(&path).ok()?;
+            Some(parse_style_file(path, &content, resolver))
+        })
+        .collect()
+}
+
+pub fn parse_style_file(path: PathBuf, content: &str, resolver: &StyleResolver) -> StyleFileInfo {
+    let imports = style_specifiers(content)
+        .into_iter()
+        .filter(|specifier| !specifier.starts_with("sass:"))
+        .map(|specifier| StyleImport {
+            resolved_path: resolver.resolve(specifier, &path),
+            specifier: specifier.to_string(),
+        })
+        .collect();
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let urls = css_urls(content)
+        .filter_map(|url| resolve_url(url, dir))
+        .collect();
+    // The indented `.sass` syntax has no braces to follow.
+    let (classes, composes) =
+        if is_css_module(&path) && path.extension().is_none_or(|ext| ext != "sass") {
+            let (classes, composes) = css_module_classes(content);
+            let composes = composes
+                .into_iter()
+                .map(|(class, from)| ComposedClass {
+                    class,
+                    from: from.map(|from| normalize_path(dir.join(from))),
+                })
+                .collect();
+            (classes, composes)
+        } else {
+            (Vec::new(), Vec::new())
+        };
+    StyleFileInfo {
+        path,
+        imports,
+        urls,
+        classes,
+        composes,
+    }
+}
+
+/// Class selectors of a CSS module, sorted, and the classes its rules
+/// `composes:` — each with the relative module it comes from, if any.
+/// SCSS/Less nesting is followed: `.card { &__title {} }` defines
+/// `card__title`. Classes built by interpolation (`.icon-#{$name}`) are
+/// left out, as is everything under `:global`.
+pub fn css_module_classes(css: &str) -> (Vec<String>, Vec<(String, Option<String>)>) {
+    let code = blank_non_code(css);
+    let mut classes = std::collections::BTreeSet::new();
+    let mut composes = Vec::new();
+    // Selectors of the enclosing rules, `&` already substituted.
+    let mut parents: Vec<Vec<String>> = vec![Vec::new()];
+    let mut start = 0;
+    for (i, c) in code.char_indices() {
+        match c {
+            '{' => {
+                let prelude = code[start..i].trim();
+                let outer = parents.last().cloned().unwrap_or_default();
+                let selectors = if prelude.starts_with('@') {
+                    // `@media`, `@supports`, `@include x { … }`: same rule.
+                    outer
+                } else {
+                    let selectors = nest_selectors(prelude, &outer);
+                    for selector in &selectors {
+                        classes.extend(selector_classes(selector));
+                    }
+                    selectors
+                };
+                parents.push(selectors);
+                start = i + 1;
+            }
+            ';' | '}' => {
+                let declaration = code[start..i].trim_end();
+                let end = start + declaration.len();
+                let value = declaration
+                    .trim_start()
+                    .strip_prefix("composes")
+                    .and_then(|rest| rest.trim_start().strip_prefix(':'));
+                if let Some(value) = value {
+                    // Strings are blanked in `code`; read the value from the
+                    // source.
+                    let value = &css[end - value.len()..end];
+                    let (names, from) = match value.split_once(" from ") {
+                        Some((names, from)) => (names, Some(from.trim())),
+                        None => (value, None),
+                    };
+                    if from != Some("global") {
+                        let from = from.map(|from| from.trim_matches(['"', '\'']).to_string());
+                        composes.extend(
+                            names
+                                .split_whitespace()
+                                .map(|name| (name.to_string(), from.clone())),
+                        );
+                    }
+                }
+                if c == '}' && parents.len() > 1 {
+                    parents.pop();
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    (classes.into_iter().collect(), composes)
+}
+
+/// `css` with comments and string contents blanked and interpolations
+/// (`#{$x}`, `@{x}`) collapsed to a `$` — byte offsets are kept, and no
+/// brace left is anything but a block.
+fn blank_non_code(css: &str) -> String {
+    let bytes = css.as_bytes();
+    let mut code = bytes.to_vec();
+    let mut i = 0;
+    let blank = |code: &mut Vec<u8>, range: std::ops::Range<usize>| {
+        for byte in &mut code[range] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+    };
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        let end = if rest.starts_with(b"/*") {
+            let end = css[i + 2..]
+                .find("*/")
+                .map_or(bytes.len(), |end| i + 2 + end + 2);
+            blank(&mut code, i..end);
+            end
+        } else if rest.starts_with(b"//") && (i == 0 || bytes[i - 1].is_ascii_whitespace()) {
+            let end = css[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            blank(&mut code, i..end);
+            end
+        } else if rest.starts_with(b"#{") || rest.starts_with(b"@{") {
+            let end = css[i..].find('}').map_or(bytes.len(), |end| i + end + 1);
+            blank(&mut code, i..end);
+            code[i] = b'$';
+            end
+        } else if rest[0] == b'"' || rest[0] == b'\'' {
+            let quote = rest[0] as char;
+            let end = css[i + 1..]
+                .find(quote)
+                .map_or(bytes.len(), |end| i + 1 + end);
+            blank(&mut code, i + 1..end);
+            end + 1
+        } else {
+            i + 1
+        };
+        i = end;
+    }
+    // Whole UTF-8 sequences are blanked or kept, never split.
+    String::from_utf8(code).unwrap_or_default()
+}
+
+/// Selectors of a nested rule: `&` stands for each enclosing selector, and
+/// a selector without one is a descendant of them.
+fn nest_selectors(prelude: &str, outer: &[String]) -> Vec<String> {
+    let mut selectors = Vec::new();
+    for selector in prelude.split(',').map(str::trim) {
+        if outer.is_empty() {
+            selectors.push(selector.to_string());
+        } else if selector.contains('&') {
+            selectors.extend(outer.iter().map(|parent| selector.replace('&', parent)));
+        } else {
+            selectors.extend(outer.iter().map(|parent| format!("{parent} {selector}")));
+        }
+    }
+    selectors
+}
+
+/// `.card`, `.card--active` in a selector, skipping `:global(...)` and
+/// everything after a bare `:global`.
+fn selector_classes(selector: &str) -> Vec<String> {
+    let mut local = String::new();
+    let mut rest = selector;
+    while let Some(at) = rest.find(":global") {
+        local.push_str(&rest[..at]);
+        let after = &rest[at + ":global".len()..];
+        rest = match after.strip_prefix('(') {
+            Some(inner) => inner.find(')').map_or("", |end| &inner[end + 1..]),
+            None => "",
+        };
+    }
+    local.push_str(rest);
+
+    let is_name = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-' || !c.is_ascii();
+    let mut classes = Vec::new();
+    for (dot, _) in local.match_indices('.') {
+        // `1.5` inside `:nth-child()` is a number, not a class.
+        if local[..dot].ends_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
+        let name = &local[dot + 1..];
+        let len = name.find(|c: char| !is_name(c)).unwrap_or(name.len());
+        let starts_name = name.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '-');
+        // An interpolated name (`.icon-#{$x}`) is not known statically.
+        if len > 0 && starts_name && !name[len..].starts_with('$') {
+            classes.push(name[..len].to_string());
+        }
+    }
+    classes
+}
+
+/// `@use` / `@forward` take one module, `@import` a comma-separated list.
+/// `// commented-out` rules still count — a missed edge is worse than an
+/// extra one.
+pub fn style_specifiers(css: &str) -> Vec<&str> {
+    let mut found: Vec<(usize, &str)> = Vec::new();
+    for at_rule in ["@use", "@forward", "@import"] {
+        for (pos, _) in css.match_indices(at_rule) {
+            let rest = &css[pos + at_rule.len()..];
+            if !rest.starts_with(|c: char| c.is_whitespace() || c == '\'' || c == '"') {
+                continue;
+            }
+            let statement = &rest[..statement_end(rest)];
+            let mut specifiers = quoted_strings(statement);
+            if at_rule != "@import" {
+                specifiers.truncate(1);
+            }
+            found.extend(specifiers.into_iter().map(|specifier| (pos, specifier)));
+        }
+    }
+    found.sort_by_key(|(pos, _)| *pos);
+    found.into_iter().map(|(_, specifier)| specifier).collect()
+}
+
+/// Contents of every `url(...)`, unquoted.
+pub fn css_urls(text: &str) -> impl Iterator<Item = &str> {
+    text.match_indices("url(").filter_map(move |(pos, _)| {
+        let rest = &text[pos + 4..];
+        let end = rest.find(')')?;
+        Some(rest[..end].trim().trim_matches(|c| c == '"' || c == '\''))
+    })
+}
+
+/// A relative URL to a path next to `dir`. External, absolute, data and
+/// interpolated URLs are not files of this project.
+pub fn resolve_url(url: &str, dir: &Path) -> Option<PathBuf> {
+    let url = url.split(['?', '#']).next()?.trim();
+    let external = url.is_empty()
+        || url.contains("://")
+        || url.starts_with("//")
+        || url.starts_with('/')
+        || url.starts_with("data:")
+        || url.starts_with("mailto:")
+        || url.contains("{{")
+        || url.contains("#{")
+        || url.starts_with('$')
+        || url.starts_with('~');
+    if external {
+        return None;
+    }
+    Some(normalize_path(dir.join(url)))
+}
+
+/// End of an at-rule: `;`, a block, or — in indented `.sass` syntax — a
+/// line break not continuing a comma-separated list.
+fn statement_end(rest: &str) -> usize {
+    let mut last_significant = ' ';
+    for (index, c) in rest.char_indices() {
+        match c {
+            ';' | '{' => return index,
+            '\n' if last_significant != ',' => return index,
+            c if !c.is_whitespace() => last_significant = c,
+            _ => {}
+        }
+    }
+    rest.len()
+}
+
+fn quoted_strings(statement: &str) -> Vec<&str> {
+    let mut strings = Vec::new();
+    let mut rest = statement;
+    while let Some(start) = rest.find(['"', '\'']) {
+        let quote = &rest[start..start + 1];
+        let after = &rest[start + 1..];
+        let Some(end) = after.find(quote) else {
+            break;
+        };
+        strings.push(&after[..end]);
+        rest = &after[end + 1..];
+    }
+    strings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specifiers_of_every_at_rule_in_source_order() {
+        let css = "@use 'sass:math';\n@import 'a', \"b\";\n@forward 'c' show x;\n\
+                   @use 'd' with ($gap: 'e');\n.x { @include mixins.useless; }";
+        assert_eq!(style_specifiers(css), vec!["sass:math", "a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn indented_syntax_ends_at_line_break() {
+        let sass = "@import 'a',\n  'b'\n.x\n  content: 'not an import'";
+        assert_eq!(style_specifiers(sass), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn css_module_classes_follow_nesting() {
+        let scss = "// .commented { }\n.card { padding: 0.5rem; &__title { content: '.x{'; }\n\
+                    &:hover, &--active { } .icon-#{$name} { } }\n\
+                    @media (min-width: 1px) { .wide:not(.card) { } }\n\
+                    :global(.legacy) .scoped { } :global .outside { }";
+        let (classes, composes) = css_module_classes(scss);
+        assert_eq!(
+            classes,
+            vec!["card", "card--active", "card__title", "scoped", "wide"]
+        );
+        assert!(composes.is_empty());
+    }
+
+    #[test]
+    fn css_module_composes() {
+        let css = ".base { } .primary { composes: base; color: red }\n\
+                   .button { composes: reset focus from './shared.module.css'; }\n\
+                   .global { composes: legacy from global; }";
+        let (_, composes) = css_module_classes(css);
+        let shared = Some("./shared.module.css".to_string());
+        assert_eq!(
+            composes,
+            vec![
+                ("base".to_string(), None),
+                ("reset".to_string(), shared.clone()),
+                ("focus".to_string(), shared),
+            ]
+        );
+    }
+
+    #[test]
+    fn css_module_file_names() {
+        assert!(is_css_module(Path::new("src/card.module.scss")));
+        assert!(!is_css_module(Path::new("src/card.scss")));
+        assert!(!is_css_module(Path::new("src/card.module.ts")));
+    }
+}
@@ -23,6 +23,17 @@ pub fn is_ignored_dir_component(name: &std::ffi::OsStr) -> bool {
 
 use std::path::{Path, PathBuf};
 
+/// TypeScript, JavaScript and Vue sources — everything the parser reads.
+/// Any other import target (JSON, images, styles) is a non-script module.
+pub fn is_script_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        matches!(
+            ext.to_string_lossy().as_ref(),
+            "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" | "mts" | "cts" | "vue"
+        )
+    })
+}
+
 /// Normalizes a file path by:
 /// - Resolving '..' (parent directory) references
 /// - Removing '.' (current directory) references
@@ -8,8 +8,9 @@ mod ng;
 mod nx;
 mod report;
 
-use crate::analyses::project_map::{ProjectCatalog, ProjectInfo};
+use crate::analyses::project_map::{EntryDir, ProjectCatalog, ProjectInfo};
 use crate::analysis::processor::file_processor::{ProjectProcessor, SharedAnalysisState};
+use crate::analysis::resolvers::style_resolver::{collect_style_files, StyleResolver};
 use crate::ng::ng_reporter::NgReporter;
 use crate::nx::nx_project::NxProject;
 use crate::nx::NxWorkspace;
@@ -57,7 +58,8 @@ struct Args {
     strict: bool,
 
     /// Fail (exit 2) when any new finding exists. Repeat or comma-separate:
-    /// unused, cycles, boundaries, all
+    /// unused, cycles, boundaries, selectors, providers, members, translations, ngrx,
+    /// client-boundaries, css-modules, all
     #[arg(long, global = true, value_delimiter = ',')]
     fail_on: Vec<FailCategory>,
 
@@ -79,7 +81,7 @@ enum Command {
         #[arg(long)]
         project: Option<String>,
     },
-    /// Unused exports, never-rendered entities, orphan files
+    /// Unused exports, never-rendered entities, orphan files and assets
     Unused {
         /// Show only findings in this project
         #[arg(long)]
@@ -110,6 +112,59 @@ enum Command {
     },
     /// NX tag boundary rule violations (rules in nx-analyzer.json)
     Boundaries,
+    /// Selector prefix violations and selectors matched by several entities
+    Selectors,
+    /// Providers nothing injects, and root services provided again
+    Providers,
+    /// Component members no template uses, and template reads of missing members
+    Members,
+    /// Translation keys nothing looks up, and lookups a language lacks
+    Translations {
+        /// Show only catalogs of this project
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// NgRx actions nothing dispatches, their handlers, and unselected selectors
+    Ngrx {
+        /// Show only findings in this project
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// Server-only modules and Node built-ins reached from 'use client' files
+    ClientBoundaries {
+        /// Show only violations in this project
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// HTTP routes of NestJS controllers
+    NestRoutes {
+        /// Show only routes of this project
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// CSS module classes nothing reads, and reads of classes a module lacks
+    CssModules {
+        /// Show only findings in this project
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// NgModule → standalone migration plan, leaves first
+    Migration {
+        /// Show only adoption and steps of this project
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// Component hierarchy rendered from bootstrap components and routes
+    RenderTree {
+        #[arg(long, value_enum, default_value_t = TreeFormat::Text)]
+        format: TreeFormat,
+        /// Show only this many levels below each root
+        #[arg(long)]
+        depth: Option<usize>,
+        /// Show only roots bootstrapped in this project
+        #[arg(long)]
+        project: Option<String>,
+    },
     /// Export the dependency graph
     Graph {
         #[arg(long, value_enum, default_value_t = GraphFormat::Mermaid)]
@@ -139,6 +194,13 @@ enum FailCategory {
     Unused,
     Cycles,
     Boundaries,
+    Selectors,
+    Providers,
+    Members,
+    Translations,
+    Ngrx,
+    ClientBoundaries,
+    CssModules,
     All,
 }
 
@@ -149,6 +211,13 @@ enum GraphFormat {
     Json,
 }
 
+#[derive(ValueEnum, Clone, Debug)]
+enum TreeFormat {
+    Text,
+    Json,
+    Mermaid,
+}
+
 #[derive(ValueEnum, Clone, Debug)]
 enum GraphLevel {
     Project,
@@ -185,23 +254,35 @@ fn detect_default_standalone(workspace_root: &Path) -> bool {
     major.is_some_and(|major| major >= 19)
 }
 
-/// expo-router uses file-based routing: EVERY script file under the app
-/// directory is a route consumed by the framework. Detected via the
-/// project-level package.json.
-fn detect_file_routing_dirs(project_root: &Path) -> Vec<PathBuf> {
-    let package_json = project_root.join("package.json");
-    let Ok(content) = std::fs::read_to_string(&package_json) else {
-        return Vec::new();
+/// File-based routing: EVERY script file under the routing directory is a
+/// route consumed by the framework. expo-router is detected via the
+/// project-level package.json; Next.js also via a `next.config.*` file,
+/// since NX Next apps usually have no package.json of their own.
+fn detect_file_routing_dirs(project_root: &Path) -> Vec<EntryDir> {
+    let package_json =
+        std::fs::read_to_string(project_root.join("package.json")).unwrap_or_default();
+    let dirs = |dirs: &[&str], exports: Option<&'static [&'static str]>| {
+        dirs.iter()
+            .map(|dir| project_root.join(dir))
+            .filter(|dir| dir.is_dir())
+            .map(|path| EntryDir { path, exports })
+            .collect::<Vec<_>>()
     };
-    if !content.contains("\"expo-router\"") {
-        return Vec::new();
-    }
 
-    ["src/app", "app"]
+    let mut entry_dirs = Vec::new();
+    if package_json.contains("\"expo-router\"") {
+        entry_dirs.extend(dirs(&["src/app", "app"], None));
+    }
+    let has_next_config = ["js", "mjs", "cjs", "ts", "mts"]
         .iter()
-        .map(|dir| project_root.join(dir))
-        .filter(|dir| dir.is_dir())
-        .collect()
+        .any(|ext| project_root.join(format!("next.config.{ext}")).is_file());
+    if has_next_config || package_json.contains("\"next\"") {
+        entry_dirs.extend(dirs(
+            &["src/pages", "pages"],
+            Some(analyses::project_map::NEXT_PAGE_EXPORTS),
+        ));
+    }
+    entry_dirs
 }
 
 /// Runs the whole pipeline: workspace discovery → per-project extraction →
@@ -223,6 +304,8 @@ fn run_pipeline(args: &Args) -> Result<(FullReport, ProjectCatalog), Box<dyn std
     let shared = SharedAnalysisState::new();
     let workspace_root = nx_workspace.workspace_root().to_path_buf();
     let default_standalone = detect_default_standalone(&workspace_root);
+    let config = analyses::boundaries::load_config(&workspace_root);
+    let component_openers = Arc::new(config.component_openers());
 
     let projects: Vec<(PathBuf, NxProject)> = if let Some(project_filter) = &args.projects {
         let project_names: Vec<&str> = project_filter.split(',').collect();
@@ -262,7 +345,8 @@ fn run_pipeline(args: &Args) -> Result<(FullReport, ProjectCatalog), Box<dyn std
             Arc::clone(&source_map),
             default_standalone,
         )
-        .exclude_nested_roots(nested_roots);
+        .with_component_openers(Arc::clone(&component_openers))
+        .exclude_nested_roots(nested_roots.clone());
 
         if args.exclude_node_modules {
             processor = processor.filter_node_modules();
@@ -275,6 +359,23 @@ fn run_pipeline(args: &Args) -> Result<(FullReport, ProjectCatalog), Box<dyn std
         };
 
         processor.process_files(&mut results);
+
+        let style_resolver = StyleResolver::new(
+            &workspace_root,
+            project
+                .config
+                .style_include_paths()
+                .iter()
+                .map(|path| analysis::utils::path_utils::normalize_path(workspace_root.join(path)))
+                .collect(),
+            &config.style_aliases,
+            &project.ts_config,
+        );
+        results.style_files.extend(collect_style_files(
+            project_path,
+            &nested_roots,
+            &style_resolver,
+        ));
         metrics
             .file_analysis_times
             .push((project.name.clone(), project_start.elapsed()));
@@ -290,7 +391,7 @@ fn run_pipeline(args: &Args) -> Result<(FullReport, ProjectCatalog), Box<dyn std
 
     // Template usages add edges to the import graph — the snapshot must be
     // taken afterwards.
-    let template_usages = ng::templates::analyze_templates(&results, &shared.import_graph);
+    let template_usages = ng::templates::analyze_templates(&mut results, &shared.import_graph);
 
     let catalog = ProjectCatalog::new(
         projects
@@ -304,18 +405,34 @@ fn run_pipeline(args: &Args) -> Result<(FullReport, ProjectCatalog), Box<dyn std
                     .project_type
                     .clone()
                     .unwrap_or_else(|| "library".to_string()),
+                prefix: project.config.prefix.clone(),
                 entry_dirs: detect_file_routing_dirs(root),
+                asset_refs: project
+                    .config
+                    .asset_references()
+                    .iter()
+                    .map(|path| {
+                        analysis::utils::path_utils::normalize_path(workspace_root.join(path))
+                    })
+                    .collect(),
             })
             .collect(),
     );
 
-    let analysis = analyses::run_analyses(
+    let mut analysis = analyses::run_analyses(
         &results,
         &template_usages,
         &shared.import_graph,
         &catalog,
-        &workspace_root,
+        &config,
     );
+    if matches!(
+        args.command,
+        None | Some(Command::Analyze { .. }) | Some(Command::RenderTree { .. })
+    ) {
+        analysis.render_tree =
+            analyses::render_tree::build_render_tree(&results, &template_usages, &catalog);
+    }
 
     if args.verbose {
         metrics.print_summary();
@@ -374,11 +491,21 @@ fn enforce_policy(
             FailCategory::Unused => {
                 key.starts_with("unused:")
                     || key.starts_with("unused-import:")
+                    || key.starts_with("unused-module-import:")
+                    || key.starts_with("reexport-module:")
                     || key.starts_with("not-rendered:")
                     || key.starts_with("orphan:")
+                    || key.starts_with("orphan-asset:")
             }
             FailCategory::Cycles => key.starts_with("cycle:") || key.starts_with("project-cycle:"),
             FailCategory::Boundaries => key.starts_with("boundary:"),
+            FailCategory::Selectors => key.starts_with("selector-"),
+            FailCategory::Providers => key.starts_with("provider-"),
+            FailCategory::Members => key.starts_with("member-"),
+            FailCategory::Translations => key.starts_with("translation-"),
+            FailCategory::Ngrx => key.starts_with("ngrx-"),
+            FailCategory::ClientBoundaries => key.starts_with("client-boundary:"),
+            FailCategory::CssModules => key.starts_with("css-class-"),
         })
     };
 
@@ -428,6 +555,45 @@ fn run(args: &Args) -> Result<i32, Box<dyn std::error::Error>> {
             report::terminal::print_move_candidates(&full_report, project.as_deref())
         }
         Some(Command::Boundaries) => report::terminal::print_boundaries(&full_report),
+        Some(Command::Selectors) => report::terminal::print_selectors(&full_report),
+        Some(Command::Providers) => report::terminal::print_providers(&full_report),
+        Some(Command::Members) => report::terminal::print_template_members(&full_report),
+        Some(Command::Translations { project }) => {
+            report::terminal::print_translations(&full_report, project.as_deref())
+        }
+        Some(Command::Ngrx { project }) => {
+            report::terminal::print_ngrx(&full_report, project.as_deref())
+        }
+        Some(Command::ClientBoundaries { project }) => {
+            report::terminal::print_client_boundaries(&full_report, project.as_deref())
+        }
+        Some(Command::NestRoutes { project }) => {
+            report::terminal::print_nest_routes(&full_report, project.as_deref())
+        }
+        Some(Command::CssModules { project }) => {
+            report::terminal::print_css_modules(&full_report, project.as_deref())
+        }
+        Some(Command::Migration { project }) => {
+            report::terminal::print_migration(&full_report, project.as_deref())
+        }
+        Some(Command::RenderTree {
+            format,
+            depth,
+            project,
+        }) => {
+            let tree = analyses::render_tree::select(
+                &full_report.analysis.render_tree,
+                project.as_deref(),
+                *depth,
+            );
+            match format {
+                TreeFormat::Text => report::terminal::print_render_tree(&tree),
+                TreeFormat::Json => println!("{}", serde_json::to_string_pretty(&tree)?),
+                TreeFormat::Mermaid => {
+                    println!("{}", report::graph_export::render_tree_mermaid(&tree.roots))
+                }
+            }
+        }
         Some(Command::Usages { symbol, from, json }) => {
             let usage_report =
                 report::usages::symbol_usages(&full_report, &catalog, symbol, from.as_deref());
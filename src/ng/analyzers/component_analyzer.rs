@@ -2,7 +2,10 @@ use crate::analysis::models::import::ResolvedImport;
 use crate::ng::analyzers::decorator_analyzer::{DecoratorAnalysis, DecoratorAnalyzer};
 use crate::ng::models::ng_base::NgBaseInfo;
 use crate::ng::models::NgComponentInfo;
+use crate::ng::templates::expression;
+use std::collections::HashSet;
 use std::path::Path;
+use swc_ecma_ast::{Expr, Lit, Prop, PropName, PropOrSpread};
 
 pub struct NgComponentAnalyzer;
 
@@ -29,6 +32,28 @@ impl NgComponentAnalyzer {
             style_paths.push(style_url);
         }
 
+        // `host: { '(click)': 'toggle()', '[class.open]': 'isOpen' }` reads
+        // members like a template does.
+        let mut host_reads = HashSet::new();
+        if let Some(Expr::Object(host)) = DecoratorAnalyzer::find_prop(props, "host") {
+            for prop in &host.props {
+                let PropOrSpread::Prop(prop) = prop else {
+                    continue;
+                };
+                let Prop::KeyValue(kv) = &**prop else {
+                    continue;
+                };
+                let (PropName::Str(key), Expr::Lit(Lit::Str(value))) = (&kv.key, &*kv.value) else {
+                    continue;
+                };
+                if key.value.starts_with('[') || key.value.starts_with('(') {
+                    expression::collect_reads(&value.value, &mut host_reads);
+                }
+            }
+        }
+        let mut member_refs: Vec<String> = host_reads.into_iter().collect();
+        member_refs.sort();
+
         Some(NgComponentInfo {
             base: NgBaseInfo::new(
                 class_name.to_string(),
@@ -45,9 +70,16 @@ impl NgComponentAnalyzer {
             standalone: DecoratorAnalyzer::get_bool_prop(props, "standalone")
                 .unwrap_or(default_standalone),
             standalone_imports: DecoratorAnalyzer::get_ident_array_prop(props, "imports"),
-            providers: DecoratorAnalyzer::get_ident_array_prop(props, "providers"),
+            providers: DecoratorAnalyzer::get_provider_tokens(props, "providers"),
+            multi_providers: DecoratorAnalyzer::get_multi_provider_tokens(props, "providers"),
             inputs,
             outputs,
+            host_directives: DecoratorAnalyzer::get_host_directives(props),
+            extends: None,
+            members: Vec::new(),
+            member_refs,
+            template_members: None,
+            template_translation_keys: Vec::new(),
         })
     }
 }
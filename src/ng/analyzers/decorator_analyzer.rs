@@ -1,3 +1,4 @@
+use crate::ng::models::ng_directive::NgHostDirective;
 use swc_ecma_ast::{Decorator, Expr, Lit, PropName};
 
 #[derive(Clone)]
@@ -36,7 +37,7 @@ impl DecoratorAnalyzer {
         })
     }
 
-    fn find_prop<'a>(
+    pub fn find_prop<'a>(
         obj: &'a swc_ecma_ast::ObjectLit,
         prop_name: &str,
     ) -> Option<&'a swc_ecma_ast::Expr> {
@@ -54,9 +55,15 @@ impl DecoratorAnalyzer {
         })
     }
 
+    /// String literal, or a template literal without substitutions — the
+    /// usual spelling of a multi-line inline `template:`.
     pub fn get_string_prop(obj: &swc_ecma_ast::ObjectLit, prop_name: &str) -> Option<String> {
         match Self::find_prop(obj, prop_name)? {
             Expr::Lit(Lit::Str(str_lit)) => Some(str_lit.value.to_string()),
+            Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+                .quasis
+                .first()
+                .map(|quasi| quasi.cooked.as_ref().unwrap_or(&quasi.raw).to_string()),
             _ => None,
         }
     }
@@ -69,18 +76,120 @@ impl DecoratorAnalyzer {
     }
 
     /// Array of identifiers, e.g. `imports: [CommonModule, UiButtonComponent]`.
+    /// A `RouterModule.forChild(routes)` entry counts as `RouterModule`.
     pub fn get_ident_array_prop(obj: &swc_ecma_ast::ObjectLit, prop_name: &str) -> Vec<String> {
         match Self::find_prop(obj, prop_name) {
             Some(Expr::Array(arr)) => arr
                 .elems
                 .iter()
                 .flatten()
-                .filter_map(|elem| {
-                    if let Expr::Ident(ident) = &*elem.expr {
-                        Some(ident.sym.to_string())
-                    } else {
-                        None
+                .filter_map(|elem| match &*elem.expr {
+                    Expr::Ident(ident) => Some(ident.sym.to_string()),
+                    expr => Self::module_with_providers(expr),
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The entries of an array written as `X.forRoot(...)`, `X.forChild(...)`
+    /// or `X.forFeature(...)` — modules imported with the providers or routes
+    /// the call registers.
+    pub fn get_configured_modules(obj: &swc_ecma_ast::ObjectLit, prop_name: &str) -> Vec<String> {
+        match Self::find_prop(obj, prop_name) {
+            Some(Expr::Array(arr)) => arr
+                .elems
+                .iter()
+                .flatten()
+                .filter_map(|elem| Self::module_with_providers(&elem.expr))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// `X` of a `X.forRoot(...)`/`forChild(...)`/`forFeature(...)` call.
+    fn module_with_providers(expr: &Expr) -> Option<String> {
+        let Expr::Call(call) = expr else {
+            return None;
+        };
+        let member = call.callee.as_expr()?.as_member()?;
+        let method = member.prop.as_ident()?;
+        if !matches!(method.sym.as_ref(), "forRoot" | "forChild" | "forFeature") {
+            return None;
+        }
+        Some(member.obj.as_ident()?.sym.to_string())
+    }
+
+    /// DI tokens of a `providers: [...]` array: bare classes (`ApiService`)
+    /// and the `provide:` token of provider objects
+    /// (`{ provide: Logger, useClass: FileLogger }`).
+    pub fn get_provider_tokens(obj: &swc_ecma_ast::ObjectLit, prop_name: &str) -> Vec<String> {
+        match Self::find_prop(obj, prop_name) {
+            Some(Expr::Array(arr)) => arr
+                .elems
+                .iter()
+                .flatten()
+                .filter_map(|elem| match &*elem.expr {
+                    Expr::Ident(ident) => Some(ident.sym.to_string()),
+                    Expr::Object(provider) => match Self::find_prop(provider, "provide") {
+                        Some(Expr::Ident(token)) => Some(token.sym.to_string()),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The `provide:` tokens of `{ provide: X, ..., multi: true }` entries.
+    pub fn get_multi_provider_tokens(
+        obj: &swc_ecma_ast::ObjectLit,
+        prop_name: &str,
+    ) -> Vec<String> {
+        match Self::find_prop(obj, prop_name) {
+            Some(Expr::Array(arr)) => arr
+                .elems
+                .iter()
+                .flatten()
+                .filter_map(|elem| match &*elem.expr {
+                    Expr::Object(provider)
+                        if Self::get_bool_prop(provider, "multi") == Some(true) =>
+                    {
+                        match Self::find_prop(provider, "provide") {
+                            Some(Expr::Ident(token)) => Some(token.sym.to_string()),
+                            _ => None,
+                        }
                     }
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// `hostDirectives: [TooltipDirective, { directive: X, inputs: [...] }]`.
+    pub fn get_host_directives(obj: &swc_ecma_ast::ObjectLit) -> Vec<NgHostDirective> {
+        match Self::find_prop(obj, "hostDirectives") {
+            Some(Expr::Array(arr)) => arr
+                .elems
+                .iter()
+                .flatten()
+                .filter_map(|elem| match &*elem.expr {
+                    Expr::Ident(ident) => Some(NgHostDirective {
+                        directive: ident.sym.to_string(),
+                        inputs: Vec::new(),
+                        outputs: Vec::new(),
+                    }),
+                    Expr::Object(entry) => match Self::find_prop(entry, "directive") {
+                        Some(Expr::Ident(directive)) => Some(NgHostDirective {
+                            directive: directive.sym.to_string(),
+                            inputs: Self::get_string_array_prop(entry, "inputs"),
+                            outputs: Self::get_string_array_prop(entry, "outputs"),
+                        }),
+                        _ => None,
+                    },
+                    _ => None,
                 })
                 .collect(),
             _ => Vec::new(),
@@ -40,8 +40,11 @@ impl NgDirectiveAnalyzer {
                 .unwrap_or(default_standalone),
             host_bindings: Vec::new(),
             host_listeners: Vec::new(),
+            providers: DecoratorAnalyzer::get_provider_tokens(props, "providers"),
+            multi_providers: DecoratorAnalyzer::get_multi_provider_tokens(props, "providers"),
             inputs,
             outputs,
+            host_directives: DecoratorAnalyzer::get_host_directives(props),
         };
 
         if let Some(host_props) = props.props.iter().find_map(|p| {
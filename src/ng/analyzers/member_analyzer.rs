@@ -0,0 +1,142 @@
+use crate::ng::models::{ComponentMember, MemberKind, MemberVisibility};
+use std::collections::BTreeSet;
+use swc_ecma_ast::{
+    Accessibility, Class, ClassMember, Expr, Lit, MemberExpr, MemberProp, MethodKind,
+    ObjectPatProp, ParamOrTsParamProp, Pat, PropName, TsParamPropParam, VarDeclarator,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub struct NgMemberAnalyzer;
+
+impl NgMemberAnalyzer {
+    /// Instance members of `class` and the member names its own code reads
+    /// through `this` (`this.x`, `this['x']`, `const { x } = this`). Static
+    /// members are skipped — templates cannot read them.
+    pub fn analyze(class: &Class) -> (Vec<ComponentMember>, Vec<String>) {
+        let mut members = Vec::new();
+        for member in &class.body {
+            match member {
+                ClassMember::ClassProp(prop) if !prop.is_static => {
+                    if let Some(name) = prop_name(&prop.key) {
+                        members.push(ComponentMember {
+                            name,
+                            kind: MemberKind::Property,
+                            visibility: visibility(prop.accessibility),
+                            decorated: !prop.decorators.is_empty(),
+                        });
+                    }
+                }
+                ClassMember::Method(method) if !method.is_static => {
+                    if let Some(name) = prop_name(&method.key) {
+                        let kind = match method.kind {
+                            MethodKind::Method => MemberKind::Method,
+                            MethodKind::Getter | MethodKind::Setter => MemberKind::Accessor,
+                        };
+                        let duplicate = members
+                            .iter()
+                            .any(|m: &ComponentMember| m.name == name && m.kind == kind);
+                        if !duplicate {
+                            members.push(ComponentMember {
+                                name,
+                                kind,
+                                visibility: visibility(method.accessibility),
+                                decorated: !method.function.decorators.is_empty(),
+                            });
+                        }
+                    }
+                }
+                ClassMember::PrivateProp(prop) if !prop.is_static => {
+                    members.push(ComponentMember {
+                        name: format!("#{}", prop.key.name),
+                        kind: MemberKind::Property,
+                        visibility: MemberVisibility::Private,
+                        decorated: false,
+                    });
+                }
+                ClassMember::Constructor(constructor) => {
+                    // `constructor(private readonly api: ApiService)`
+                    for param in &constructor.params {
+                        let ParamOrTsParamProp::TsParamProp(prop) = param else {
+                            continue;
+                        };
+                        let name = match &prop.param {
+                            TsParamPropParam::Ident(ident) => ident.id.sym.to_string(),
+                            TsParamPropParam::Assign(assign) => match &*assign.left {
+                                Pat::Ident(ident) => ident.id.sym.to_string(),
+                                _ => continue,
+                            },
+                        };
+                        members.push(ComponentMember {
+                            name,
+                            kind: MemberKind::Property,
+                            visibility: visibility(prop.accessibility),
+                            decorated: false,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut collector = ThisRefCollector::default();
+        class.body.visit_with(&mut collector);
+        (members, collector.refs.into_iter().collect())
+    }
+}
+
+fn visibility(accessibility: Option<Accessibility>) -> MemberVisibility {
+    match accessibility {
+        Some(Accessibility::Private) => MemberVisibility::Private,
+        Some(Accessibility::Protected) => MemberVisibility::Protected,
+        _ => MemberVisibility::Public,
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(str_lit) => Some(str_lit.value.to_string()),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct ThisRefCollector {
+    refs: BTreeSet<String>,
+}
+
+impl Visit for ThisRefCollector {
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        if let Expr::This(_) = &*member.obj {
+            let name = match &member.prop {
+                MemberProp::Ident(ident) => Some(ident.sym.to_string()),
+                MemberProp::PrivateName(private) => Some(format!("#{}", private.name)),
+                MemberProp::Computed(computed) => match &*computed.expr {
+                    Expr::Lit(Lit::Str(str_lit)) => Some(str_lit.value.to_string()),
+                    _ => None,
+                },
+            };
+            self.refs.extend(name);
+        }
+        member.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        let destructures_this = declarator
+            .init
+            .as_deref()
+            .is_some_and(|init| matches!(init, Expr::This(_)));
+        if let (true, Pat::Object(pattern)) = (destructures_this, &declarator.name) {
+            for prop in &pattern.props {
+                match prop {
+                    ObjectPatProp::KeyValue(kv) => self.refs.extend(prop_name(&kv.key)),
+                    ObjectPatProp::Assign(assign) => {
+                        self.refs.insert(assign.key.sym.to_string());
+                    }
+                    ObjectPatProp::Rest(_) => {}
+                }
+            }
+        }
+        declarator.visit_children_with(self);
+    }
+}
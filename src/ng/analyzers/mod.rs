@@ -1,6 +1,14 @@
 pub mod component_analyzer;
 pub mod decorator_analyzer;
 pub mod directive_analyzer;
+pub mod member_analyzer;
 pub mod module_analyzer;
+pub mod nest_analyzer;
+pub mod ngrx_analyzer;
 pub mod pipe_analyzer;
+pub mod react_props_analyzer;
+pub mod route_analyzer;
 pub mod service_analyzer;
+pub mod translation_analyzer;
+pub mod url_import_analyzer;
+pub mod web_component_analyzer;
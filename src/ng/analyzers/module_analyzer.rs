@@ -29,8 +29,10 @@ impl NgModuleAnalyzer {
             ),
             declarations: DecoratorAnalyzer::get_ident_array_prop(props, "declarations"),
             imports_idents: DecoratorAnalyzer::get_ident_array_prop(props, "imports"),
+            configured_imports: DecoratorAnalyzer::get_configured_modules(props, "imports"),
             exports: DecoratorAnalyzer::get_ident_array_prop(props, "exports"),
-            providers: DecoratorAnalyzer::get_ident_array_prop(props, "providers"),
+            providers: DecoratorAnalyzer::get_provider_tokens(props, "providers"),
+            multi_providers: DecoratorAnalyzer::get_multi_provider_tokens(props, "providers"),
             bootstrap: DecoratorAnalyzer::get_ident_array_prop(props, "bootstrap"),
         })
     }
@@ -0,0 +1,223 @@
+use crate::ng::analyzers::decorator_analyzer::DecoratorAnalyzer;
+use crate::ng::models::nest::{NestControllerInfo, NestModuleInfo, NestProvider, NestRouteInfo};
+use crate::ng::models::ng_base::NgBaseInfo;
+use std::path::Path;
+use swc_ecma_ast::{
+    BlockStmtOrExpr, Callee, Class, ClassMember, Decorator, Expr, Lit, MemberProp, ObjectLit,
+    PropName,
+};
+
+/// Handler decorators of `@nestjs/common` and the HTTP method they map.
+const HTTP_METHODS: &[(&str, &str)] = &[
+    ("Get", "GET"),
+    ("Post", "POST"),
+    ("Put", "PUT"),
+    ("Patch", "PATCH"),
+    ("Delete", "DELETE"),
+    ("Options", "OPTIONS"),
+    ("Head", "HEAD"),
+    ("All", "ALL"),
+];
+
+/// NestJS `@Module` and `@Controller` classes. Nest's `@Injectable` shares
+/// Angular's spelling and is recorded as a service.
+pub struct NestAnalyzer;
+
+impl NestAnalyzer {
+    pub fn module(
+        class: &Class,
+        file_path: &Path,
+        project_root: &Path,
+        class_name: &str,
+        package_name: &str,
+    ) -> Option<NestModuleInfo> {
+        let (_, args) = class.decorators.iter().find_map(|decorator| {
+            decorator_call(decorator).filter(|(name, _)| *name == "Module")
+        })?;
+        let Some(Expr::Object(props)) = args.first().copied() else {
+            return None;
+        };
+        let global = class
+            .decorators
+            .iter()
+            .any(|decorator| decorator_call(decorator).is_some_and(|(name, _)| name == "Global"));
+
+        Some(NestModuleInfo {
+            base: base(file_path, project_root, class_name, package_name),
+            imports_idents: module_imports(props),
+            controllers: DecoratorAnalyzer::get_ident_array_prop(props, "controllers"),
+            providers: providers(props),
+            exports: DecoratorAnalyzer::get_ident_array_prop(props, "exports"),
+            global,
+        })
+    }
+
+    pub fn controller(
+        class: &Class,
+        file_path: &Path,
+        project_root: &Path,
+        class_name: &str,
+        package_name: &str,
+    ) -> Option<NestControllerInfo> {
+        let (_, args) = class.decorators.iter().find_map(|decorator| {
+            decorator_call(decorator).filter(|(name, _)| *name == "Controller")
+        })?;
+        let path = match args.first().copied() {
+            Some(Expr::Object(options)) => match DecoratorAnalyzer::find_prop(options, "path") {
+                Some(path) => paths(Some(path)).into_iter().next(),
+                None => None,
+            },
+            first => paths(first).into_iter().next(),
+        };
+
+        let mut routes = Vec::new();
+        for member in &class.body {
+            let ClassMember::Method(method) = member else {
+                continue;
+            };
+            let PropName::Ident(handler) = &method.key else {
+                continue;
+            };
+            for decorator in &method.function.decorators {
+                let Some((name, args)) = decorator_call(decorator) else {
+                    continue;
+                };
+                let Some((_, http_method)) = HTTP_METHODS.iter().find(|(n, _)| *n == name) else {
+                    continue;
+                };
+                let route_paths = match paths(args.first().copied()) {
+                    paths if paths.is_empty() => vec![String::new()],
+                    paths => paths,
+                };
+                routes.extend(route_paths.into_iter().map(|path| NestRouteInfo {
+                    method: http_method.to_string(),
+                    path,
+                    handler: handler.sym.to_string(),
+                }));
+            }
+        }
+
+        Some(NestControllerInfo {
+            base: base(file_path, project_root, class_name, package_name),
+            path: path.unwrap_or_default(),
+            routes,
+        })
+    }
+}
+
+fn base(file_path: &Path, project_root: &Path, class_name: &str, package_name: &str) -> NgBaseInfo {
+    NgBaseInfo::new(
+        class_name.to_string(),
+        Vec::new(),
+        file_path.to_path_buf(),
+        crate::analysis::utils::path_utils::get_relative_path(file_path, project_root),
+        package_name.to_string(),
+    )
+}
+
+/// `@Name(args)` → the name and arguments; `@Name` without a call has none.
+fn decorator_call(decorator: &Decorator) -> Option<(&str, Vec<&Expr>)> {
+    match &*decorator.expr {
+        Expr::Call(call) => match &call.callee {
+            Callee::Expr(callee) => match &**callee {
+                Expr::Ident(ident) => Some((
+                    &*ident.sym,
+                    call.args.iter().map(|arg| &*arg.expr).collect(),
+                )),
+                _ => None,
+            },
+            _ => None,
+        },
+        Expr::Ident(ident) => Some((&*ident.sym, Vec::new())),
+        _ => None,
+    }
+}
+
+/// A path argument: a string or an array of strings.
+fn paths(arg: Option<&Expr>) -> Vec<String> {
+    match arg {
+        Some(Expr::Lit(Lit::Str(path))) => vec![path.value.to_string()],
+        Some(Expr::Array(array)) => array
+            .elems
+            .iter()
+            .flatten()
+            .filter_map(|elem| match &*elem.expr {
+                Expr::Lit(Lit::Str(path)) => Some(path.value.to_string()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// `imports: [UsersModule, AuthModule.forRoot(...), forwardRef(() => X)]`.
+fn module_imports(props: &ObjectLit) -> Vec<String> {
+    fn module_name(expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Ident(ident) => Some(ident.sym.to_string()),
+            Expr::Call(call) => match &call.callee {
+                Callee::Expr(callee) => match &**callee {
+                    // `AuthModule.forRoot(...)`, `TypeOrmModule.forFeature(...)`
+                    Expr::Member(member) if matches!(member.prop, MemberProp::Ident(_)) => {
+                        module_name(&member.obj)
+                    }
+                    Expr::Ident(ident) if ident.sym == *"forwardRef" => {
+                        match call.args.first().map(|arg| &*arg.expr) {
+                            Some(Expr::Arrow(arrow)) => match &*arrow.body {
+                                BlockStmtOrExpr::Expr(body) => module_name(body),
+                                BlockStmtOrExpr::BlockStmt(_) => None,
+                            },
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+    match DecoratorAnalyzer::find_prop(props, "imports") {
+        Some(Expr::Array(array)) => array
+            .elems
+            .iter()
+            .flatten()
+            .filter_map(|elem| module_name(&elem.expr))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Bare classes and `{ provide, useClass | useExisting | useValue |
+/// useFactory }` objects.
+fn providers(props: &ObjectLit) -> Vec<NestProvider> {
+    let ident = |expr: Option<&Expr>| match expr {
+        Some(Expr::Ident(ident)) => Some(ident.sym.to_string()),
+        _ => None,
+    };
+    match DecoratorAnalyzer::find_prop(props, "providers") {
+        Some(Expr::Array(array)) => array
+            .elems
+            .iter()
+            .flatten()
+            .filter_map(|elem| match &*elem.expr {
+                Expr::Ident(class) => Some(NestProvider {
+                    token: class.sym.to_string(),
+                    class: None,
+                }),
+                Expr::Object(provider) => {
+                    let token = match DecoratorAnalyzer::find_prop(provider, "provide")? {
+                        Expr::Ident(token) => token.sym.to_string(),
+                        Expr::Lit(Lit::Str(token)) => token.value.to_string(),
+                        _ => return None,
+                    };
+                    let class = ident(DecoratorAnalyzer::find_prop(provider, "useClass"))
+                        .or_else(|| ident(DecoratorAnalyzer::find_prop(provider, "useExisting")));
+                    Some(NestProvider { token, class })
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,243 @@
+use crate::ng::models::ngrx::{NgRxDeclaration, NgRxHandlerKind, NgRxKind};
+use swc_ecma_ast::{BlockStmtOrExpr, CallExpr, Callee, Expr, MemberProp, Prop, PropName, Stmt};
+
+pub struct NgRxAnalyzer;
+
+impl NgRxAnalyzer {
+    /// What `name = init` declares: one action, the actions of an action
+    /// group, a selector (or selector factory), an effect, a reducer or a
+    /// feature.
+    pub fn declarations(name: &str, init: &Expr) -> Vec<NgRxDeclaration> {
+        let declaration = |kind, inputs| NgRxDeclaration {
+            name: name.to_string(),
+            kind,
+            inputs,
+        };
+        let call = match unwrap(init) {
+            Expr::Call(call) => call,
+            Expr::Arrow(arrow) => {
+                let returned = match &*arrow.body {
+                    BlockStmtOrExpr::Expr(expr) => Some(&**expr),
+                    BlockStmtOrExpr::BlockStmt(block) => {
+                        block.stmts.iter().find_map(|stmt| match stmt {
+                            Stmt::Return(ret) => ret.arg.as_deref(),
+                            _ => None,
+                        })
+                    }
+                };
+                return match returned.map(unwrap) {
+                    Some(Expr::Call(call)) if callee_is(call, "createSelector") => {
+                        vec![declaration(NgRxKind::Selector, selector_inputs(call))]
+                    }
+                    _ => Vec::new(),
+                };
+            }
+            _ => return Vec::new(),
+        };
+        let Some(function) = callee_ident(call) else {
+            return Vec::new();
+        };
+        match function {
+            "createAction" => vec![declaration(NgRxKind::Action, Vec::new())],
+            "createActionGroup" => group_events(call)
+                .into_iter()
+                .map(|event| NgRxDeclaration {
+                    name: format!("{name}.{}", to_action_name(&event)),
+                    kind: NgRxKind::Action,
+                    inputs: Vec::new(),
+                })
+                .collect(),
+            "createSelector" => vec![declaration(NgRxKind::Selector, selector_inputs(call))],
+            "createFeatureSelector" => vec![declaration(NgRxKind::Selector, Vec::new())],
+            "createEffect" => vec![declaration(NgRxKind::Effect, Vec::new())],
+            "createReducer" => vec![declaration(NgRxKind::Reducer, Vec::new())],
+            "createFeature" => vec![declaration(NgRxKind::Feature, Vec::new())],
+            _ => Vec::new(),
+        }
+    }
+
+    /// `on(a, b, (state) => …)` and `ofType(a, b)`: the actions handled.
+    pub fn handler(call: &CallExpr) -> Option<(NgRxHandlerKind, Vec<String>)> {
+        let (kind, args) = match callee_ident(call)? {
+            "on" => (
+                NgRxHandlerKind::On,
+                &call.args[..call.args.len().saturating_sub(1)],
+            ),
+            "ofType" => (NgRxHandlerKind::OfType, &call.args[..]),
+            _ => return None,
+        };
+        let actions: Vec<String> = args
+            .iter()
+            .filter_map(|arg| Self::reference(&arg.expr))
+            .collect();
+        (!actions.is_empty()).then_some((kind, actions))
+    }
+
+    /// The selector of `store.select(x)`, `store.selectSignal(x)` or the
+    /// `select(x)` operator; `selectById` for `select(selectById(id))`.
+    pub fn selection(call: &CallExpr) -> Option<String> {
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let method = match &**callee {
+            Expr::Ident(ident) => &*ident.sym,
+            Expr::Member(member) => match &member.prop {
+                MemberProp::Ident(prop) => &*prop.sym,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if method != "select" && method != "selectSignal" {
+            return None;
+        }
+        match unwrap(&call.args.first()?.expr) {
+            Expr::Call(inner) => Self::callee(inner),
+            selector => Self::reference(selector),
+        }
+    }
+
+    /// `store.dispatch(...)` / `dispatch(...)`.
+    pub fn is_dispatch(call: &CallExpr) -> bool {
+        let Callee::Expr(callee) = &call.callee else {
+            return false;
+        };
+        match &**callee {
+            Expr::Ident(ident) => ident.sym == *"dispatch",
+            Expr::Member(member) => {
+                matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == *"dispatch")
+            }
+            _ => false,
+        }
+    }
+
+    /// The callee of a call as a reference (`loadUsers`, `UserActions.load`).
+    pub fn callee(call: &CallExpr) -> Option<String> {
+        match &call.callee {
+            Callee::Expr(callee) => Self::reference(callee),
+            _ => None,
+        }
+    }
+
+    /// `name` or a chain of property reads off a name: `a.b.c`.
+    pub fn reference(expr: &Expr) -> Option<String> {
+        match unwrap(expr) {
+            Expr::Ident(ident) => Some(ident.sym.to_string()),
+            Expr::Member(member) => match &member.prop {
+                MemberProp::Ident(prop) => {
+                    Some(format!("{}.{}", Self::reference(&member.obj)?, prop.sym))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+fn unwrap(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(paren) => unwrap(&paren.expr),
+        Expr::TsAs(ts_as) => unwrap(&ts_as.expr),
+        Expr::TsNonNull(non_null) => unwrap(&non_null.expr),
+        expr => expr,
+    }
+}
+
+fn callee_ident(call: &CallExpr) -> Option<&str> {
+    match &call.callee {
+        Callee::Expr(callee) => match &**callee {
+            Expr::Ident(ident) => Some(&ident.sym),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn callee_is(call: &CallExpr, name: &str) -> bool {
+    callee_ident(call) == Some(name)
+}
+
+/// Selectors combined by `createSelector(a, b, projector)`,
+/// `createSelector([a, b], projector)` or `createSelector({ a, b })`.
+/// Called inputs are selector factories.
+fn selector_inputs(call: &CallExpr) -> Vec<String> {
+    fn input(expr: &Expr, inputs: &mut Vec<String>) {
+        match unwrap(expr) {
+            Expr::Call(call) => inputs.extend(NgRxAnalyzer::callee(call)),
+            Expr::Array(array) => {
+                for elem in array.elems.iter().flatten() {
+                    input(&elem.expr, inputs);
+                }
+            }
+            Expr::Object(object) => {
+                for prop in object.props.iter().filter_map(|prop| prop.as_prop()) {
+                    match &**prop {
+                        Prop::KeyValue(kv) => input(&kv.value, inputs),
+                        Prop::Shorthand(ident) => inputs.push(ident.sym.to_string()),
+                        _ => {}
+                    }
+                }
+            }
+            expr => inputs.extend(NgRxAnalyzer::reference(expr)),
+        }
+    }
+    let mut inputs = Vec::new();
+    for arg in &call.args {
+        input(&arg.expr, &mut inputs);
+    }
+    inputs
+}
+
+/// Keys of `events: { 'Load Users': emptyProps(), … }`.
+fn group_events(call: &CallExpr) -> Vec<String> {
+    let Some(Expr::Object(config)) = call.args.first().map(|arg| unwrap(&arg.expr)) else {
+        return Vec::new();
+    };
+    let events = config
+        .props
+        .iter()
+        .find_map(|prop| match prop.as_prop()?.as_ref() {
+            Prop::KeyValue(kv) if prop_name(&kv.key).as_deref() == Some("events") => {
+                match unwrap(&kv.value) {
+                    Expr::Object(events) => Some(events),
+                    _ => None,
+                }
+            }
+            _ => None,
+        });
+    events
+        .into_iter()
+        .flat_map(|events| &events.props)
+        .filter_map(|prop| match prop.as_prop()?.as_ref() {
+            Prop::KeyValue(kv) => prop_name(&kv.key),
+            Prop::Method(method) => prop_name(&method.key),
+            _ => None,
+        })
+        .collect()
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(str) => Some(str.value.to_string()),
+        _ => None,
+    }
+}
+
+/// NgRx's event name → action creator name: `'Load Users'` → `loadUsers`.
+fn to_action_name(event: &str) -> String {
+    event
+        .split_whitespace()
+        .enumerate()
+        .map(|(index, word)| {
+            let mut chars = word.chars();
+            let first = chars.next().map(|c| {
+                if index == 0 {
+                    c.to_lowercase().to_string()
+                } else {
+                    c.to_uppercase().to_string()
+                }
+            });
+            first.unwrap_or_default() + chars.as_str()
+        })
+        .collect()
+}
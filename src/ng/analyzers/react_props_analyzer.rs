@@ -0,0 +1,243 @@
+use crate::analysis::models::react::ReactProp;
+use std::collections::HashMap;
+use swc_ecma_ast::{
+    Decl, Expr, Module, ModuleDecl, ModuleItem, ObjectPatProp, Pat, PropName, Stmt, TsEntityName,
+    TsInterfaceDecl, TsType, TsTypeElement, TsTypeRef,
+};
+
+/// Wrappers that pass their first type argument through as the props type.
+const PROPS_WRAPPERS: &[&str] = &[
+    "FC",
+    "FunctionComponent",
+    "VFC",
+    "Readonly",
+    "PropsWithChildren",
+    "PropsWithoutRef",
+    "Partial",
+];
+
+/// Props of React function components, read from their first parameter:
+/// its type annotation (an inline type literal, or an interface / type alias
+/// declared in the same file) and its destructuring pattern.
+pub struct ReactPropsAnalyzer<'m> {
+    interfaces: HashMap<String, &'m TsInterfaceDecl>,
+    aliases: HashMap<String, &'m TsType>,
+}
+
+impl<'m> ReactPropsAnalyzer<'m> {
+    pub fn new(module: &'m Module) -> Self {
+        let mut interfaces = HashMap::new();
+        let mut aliases = HashMap::new();
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+                _ => continue,
+            };
+            match decl {
+                Decl::TsInterface(interface) => {
+                    interfaces.insert(interface.id.sym.to_string(), &**interface);
+                }
+                Decl::TsTypeAlias(alias) => {
+                    aliases.insert(alias.id.sym.to_string(), &*alias.type_ann);
+                }
+                _ => {}
+            }
+        }
+        Self {
+            interfaces,
+            aliases,
+        }
+    }
+
+    /// Props of a component whose first parameter is `param`. `declared` is
+    /// the props type given elsewhere — `React.FC<Props>` on the variable,
+    /// `forwardRef<Ref, Props>`. `None` when the props are not knowable
+    /// here (a type imported from another file, an untyped identifier).
+    pub fn props(&self, param: Option<&Pat>, declared: Option<&TsType>) -> Option<Vec<ReactProp>> {
+        let annotation = param
+            .and_then(|param| match param {
+                Pat::Ident(ident) => ident.type_ann.as_deref(),
+                Pat::Object(object) => object.type_ann.as_deref(),
+                Pat::Assign(assign) => match &*assign.left {
+                    Pat::Object(object) => object.type_ann.as_deref(),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .map(|annotation| &*annotation.type_ann)
+            .or(declared);
+        let mut props = annotation.and_then(|ty| self.type_props(ty, 0));
+
+        let pattern = match param {
+            Some(Pat::Object(object)) => Some(object),
+            Some(Pat::Assign(assign)) => match &*assign.left {
+                Pat::Object(object) => Some(object),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(pattern) = pattern {
+            let destructured: Vec<(String, bool)> = pattern
+                .props
+                .iter()
+                .filter_map(|prop| match prop {
+                    ObjectPatProp::Assign(assign) => {
+                        Some((assign.key.sym.to_string(), assign.value.is_some()))
+                    }
+                    ObjectPatProp::KeyValue(kv) => {
+                        Some((prop_name(&kv.key)?, matches!(&*kv.value, Pat::Assign(_))))
+                    }
+                    ObjectPatProp::Rest(_) => None,
+                })
+                .collect();
+            match &mut props {
+                // A default value makes a required prop optional to callers.
+                Some(props) => {
+                    for (name, defaulted) in &destructured {
+                        if let Some(prop) = props.iter_mut().find(|prop| &prop.name == name) {
+                            prop.optional |= defaulted;
+                        }
+                    }
+                }
+                // Untyped: the destructured names are the props, and nothing
+                // says which are required.
+                None if annotation.is_none() => {
+                    props = Some(
+                        destructured
+                            .into_iter()
+                            .map(|(name, _)| ReactProp {
+                                name,
+                                optional: true,
+                            })
+                            .collect(),
+                    );
+                }
+                None => {}
+            }
+        }
+        props
+    }
+
+    fn type_props(&self, ty: &TsType, depth: usize) -> Option<Vec<ReactProp>> {
+        if depth > 8 {
+            return None;
+        }
+        match ty {
+            TsType::TsTypeLit(literal) => Some(members(&literal.members)),
+            TsType::TsParenthesizedType(paren) => self.type_props(&paren.type_ann, depth + 1),
+            TsType::TsUnionOrIntersectionType(
+                swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(intersection),
+            ) => {
+                // Parts declared elsewhere (`ButtonHTMLAttributes<…>`) are
+                // left out; the known parts still declare props.
+                let parts: Vec<Vec<ReactProp>> = intersection
+                    .types
+                    .iter()
+                    .filter_map(|part| self.type_props(part, depth + 1))
+                    .collect();
+                (!parts.is_empty()).then(|| merge(parts.into_iter().flatten()))
+            }
+            TsType::TsTypeRef(reference) => self.reference_props(reference, depth),
+            _ => None,
+        }
+    }
+
+    fn reference_props(&self, reference: &TsTypeRef, depth: usize) -> Option<Vec<ReactProp>> {
+        let name = match &reference.type_name {
+            TsEntityName::Ident(ident) => &*ident.sym,
+            // `React.FC<P>`
+            TsEntityName::TsQualifiedName(qualified) => &*qualified.right.sym,
+        };
+        let argument = reference
+            .type_params
+            .as_ref()
+            .and_then(|params| params.params.first())
+            .map(|param| &**param);
+        self.named_props(name, argument, depth)
+    }
+
+    fn named_props(
+        &self,
+        name: &str,
+        argument: Option<&TsType>,
+        depth: usize,
+    ) -> Option<Vec<ReactProp>> {
+        if PROPS_WRAPPERS.contains(&name) {
+            let mut props = self.type_props(argument?, depth + 1)?;
+            match name {
+                "Partial" => props.iter_mut().for_each(|prop| prop.optional = true),
+                "PropsWithChildren" => props.push(ReactProp {
+                    name: "children".to_string(),
+                    optional: true,
+                }),
+                _ => {}
+            }
+            return Some(merge(props));
+        }
+        if let Some(alias) = self.aliases.get(name) {
+            return self.type_props(alias, depth + 1);
+        }
+        let interface = self.interfaces.get(name)?;
+        let mut props = members(&interface.body.body);
+        // Bases declared elsewhere add props this file cannot see.
+        for base in &interface.extends {
+            if let Expr::Ident(ident) = &*base.expr {
+                let argument = base
+                    .type_args
+                    .as_ref()
+                    .and_then(|params| params.params.first())
+                    .map(|param| &**param);
+                props.extend(
+                    self.named_props(&ident.sym, argument, depth + 1)
+                        .unwrap_or_default(),
+                );
+            }
+        }
+        Some(merge(props))
+    }
+}
+
+fn members(members: &[TsTypeElement]) -> Vec<ReactProp> {
+    members
+        .iter()
+        .filter_map(|member| match member {
+            TsTypeElement::TsPropertySignature(property) => Some(ReactProp {
+                name: key_name(&property.key)?,
+                optional: property.optional,
+            }),
+            TsTypeElement::TsMethodSignature(method) => Some(ReactProp {
+                name: key_name(&method.key)?,
+                optional: method.optional,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// First declaration of a name wins, as in an interface overriding its base.
+fn merge(props: impl IntoIterator<Item = ReactProp>) -> Vec<ReactProp> {
+    let mut merged: Vec<ReactProp> = Vec::new();
+    for prop in props {
+        if !merged.iter().any(|existing| existing.name == prop.name) {
+            merged.push(prop);
+        }
+    }
+    merged
+}
+
+fn key_name(key: &Expr) -> Option<String> {
+    match key {
+        Expr::Ident(ident) => Some(ident.sym.to_string()),
+        Expr::Lit(swc_ecma_ast::Lit::Str(str)) => Some(str.value.to_string()),
+        _ => None,
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(str) => Some(str.value.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,115 @@
+use crate::ng::analyzers::decorator_analyzer::DecoratorAnalyzer;
+use crate::ng::models::ng_route::{LazyTarget, NgRouteInfo};
+use swc_ecma_ast::{ArrayLit, BlockStmtOrExpr, Callee, Expr, Lit, MemberProp, ObjectLit};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub struct NgRouteAnalyzer;
+
+/// Keys that make an object literal with a `path` a route, not just any
+/// object that happens to have a `path` property.
+const ROUTE_KEYS: &[&str] = &[
+    "component",
+    "loadComponent",
+    "loadChildren",
+    "children",
+    "redirectTo",
+];
+
+impl NgRouteAnalyzer {
+    /// Parses an array literal as a route table. `None` when no element looks
+    /// like a route.
+    pub fn analyze(array: &ArrayLit) -> Option<Vec<NgRouteInfo>> {
+        let objects: Vec<&ObjectLit> = array
+            .elems
+            .iter()
+            .flatten()
+            .filter_map(|elem| match &*elem.expr {
+                Expr::Object(obj) => Some(obj),
+                _ => None,
+            })
+            .collect();
+
+        let is_table = objects.iter().any(|obj| {
+            DecoratorAnalyzer::find_prop(obj, "path").is_some()
+                && ROUTE_KEYS
+                    .iter()
+                    .any(|key| DecoratorAnalyzer::find_prop(obj, key).is_some())
+        });
+        if !is_table {
+            return None;
+        }
+
+        Some(objects.into_iter().map(Self::parse_route).collect())
+    }
+
+    fn parse_route(obj: &ObjectLit) -> NgRouteInfo {
+        let component = match DecoratorAnalyzer::find_prop(obj, "component") {
+            Some(Expr::Ident(ident)) => Some(ident.sym.to_string()),
+            _ => None,
+        };
+        let children = match DecoratorAnalyzer::find_prop(obj, "children") {
+            Some(Expr::Array(array)) => Self::analyze(array).unwrap_or_default(),
+            _ => Vec::new(),
+        };
+
+        NgRouteInfo {
+            path: DecoratorAnalyzer::get_string_prop(obj, "path"),
+            component,
+            load_component: DecoratorAnalyzer::find_prop(obj, "loadComponent")
+                .and_then(Self::lazy_target),
+            load_children: DecoratorAnalyzer::find_prop(obj, "loadChildren")
+                .and_then(Self::lazy_target),
+            children,
+            providers: DecoratorAnalyzer::get_provider_tokens(obj, "providers"),
+            multi_providers: DecoratorAnalyzer::get_multi_provider_tokens(obj, "providers"),
+        }
+    }
+
+    /// `() => import('x').then(m => m.X)` → (`x`, `X`); `() => import('x')`
+    /// → (`x`, default export).
+    fn lazy_target(expr: &Expr) -> Option<LazyTarget> {
+        #[derive(Default)]
+        struct LazyCollector {
+            specifier: Option<String>,
+            export: Option<String>,
+        }
+        impl Visit for LazyCollector {
+            fn visit_call_expr(&mut self, call: &swc_ecma_ast::CallExpr) {
+                match &call.callee {
+                    Callee::Import(_) => {
+                        if let Some(arg) = call.args.first() {
+                            if let Expr::Lit(Lit::Str(src)) = &*arg.expr {
+                                self.specifier.get_or_insert(src.value.to_string());
+                            }
+                        }
+                    }
+                    Callee::Expr(callee) => {
+                        if let Expr::Member(member) = &**callee {
+                            let is_then = matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == *"then");
+                            if let (true, Some(arg)) = (is_then, call.args.first()) {
+                                if let Expr::Arrow(arrow) = &*arg.expr {
+                                    if let BlockStmtOrExpr::Expr(body) = &*arrow.body {
+                                        if let Expr::Member(access) = &**body {
+                                            if let MemberProp::Ident(prop) = &access.prop {
+                                                self.export = Some(prop.sym.to_string());
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                call.visit_children_with(self);
+            }
+        }
+
+        let mut collector = LazyCollector::default();
+        expr.visit_with(&mut collector);
+        collector.specifier.map(|specifier| LazyTarget {
+            specifier,
+            export: collector.export,
+        })
+    }
+}
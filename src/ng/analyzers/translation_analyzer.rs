@@ -0,0 +1,104 @@
+use crate::analysis::models::file_facts::TranslationKey;
+use swc_ecma_ast::{BinaryOp, CallExpr, Callee, Expr, Lit, MemberProp, TaggedTpl};
+
+/// ngx-translate `TranslateService` and Transloco `TranslocoService` methods
+/// taking a key — only on a receiver whose name mentions "transl".
+const SERVICE_METHODS: &[&str] = &[
+    "instant",
+    "get",
+    "stream",
+    "translate",
+    "selectTranslate",
+    "translateObject",
+    "selectTranslateObject",
+];
+
+/// Free functions taking a key: Transloco's `translate()` and
+/// `translateSignal()`, the `t()` convention and the extraction `marker()`.
+const KEY_FUNCTIONS: &[&str] = &["translate", "translateSignal", "t", "marker"];
+
+pub struct NgTranslationAnalyzer;
+
+impl NgTranslationAnalyzer {
+    /// Keys passed to a translation call. The first argument is a literal
+    /// key, an array of them, or a key built at runtime — recorded by its
+    /// literal prefix (`'errors.' + code`, `` `errors.${code}` ``) when it has
+    /// one.
+    pub fn call_keys(call: &CallExpr) -> Vec<TranslationKey> {
+        let Callee::Expr(callee) = &call.callee else {
+            return Vec::new();
+        };
+        let is_translation = match &**callee {
+            Expr::Ident(ident) => KEY_FUNCTIONS.contains(&&*ident.sym),
+            Expr::Member(member) => match &member.prop {
+                MemberProp::Ident(method) => {
+                    SERVICE_METHODS.contains(&&*method.sym) && is_translation_service(&member.obj)
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+        let Some(first) = call.args.first().filter(|_| is_translation) else {
+            return Vec::new();
+        };
+        match &*first.expr {
+            Expr::Array(array) => array
+                .elems
+                .iter()
+                .flatten()
+                .filter_map(|elem| key_of(&elem.expr))
+                .collect(),
+            expr => key_of(expr).into_iter().collect(),
+        }
+    }
+
+    /// `$localize`:meaning|description@@custom.id:Text`` → `@@custom.id`,
+    /// spelled with its `@@` so it never meets an ngx-translate key.
+    /// Messages without a custom id get a computed one and are skipped.
+    pub fn localize_key(tagged: &TaggedTpl) -> Option<TranslationKey> {
+        let Expr::Ident(tag) = &*tagged.tag else {
+            return None;
+        };
+        if tag.sym != *"$localize" {
+            return None;
+        }
+        let first = tagged.tpl.quasis.first()?;
+        let metadata = first.raw.strip_prefix(':')?;
+        let metadata = &metadata[..metadata.find(':')?];
+        let id = metadata.split_once("@@")?.1;
+        (!id.is_empty()).then(|| TranslationKey::literal(format!("@@{id}")))
+    }
+}
+
+/// `translate`, `this.translate`, `this.translocoService`, …
+fn is_translation_service(receiver: &Expr) -> bool {
+    let name = match receiver {
+        Expr::Ident(ident) => ident.sym.to_string(),
+        Expr::Member(member) => match &member.prop {
+            MemberProp::Ident(prop) => prop.sym.to_string(),
+            _ => return false,
+        },
+        _ => return false,
+    };
+    name.to_ascii_lowercase().contains("transl")
+}
+
+fn key_of(expr: &Expr) -> Option<TranslationKey> {
+    match expr {
+        Expr::Lit(Lit::Str(key)) => Some(TranslationKey::literal(key.value.to_string())),
+        Expr::Tpl(tpl) => {
+            let head = tpl.quasis.first()?.raw.to_string();
+            if tpl.exprs.is_empty() {
+                Some(TranslationKey::literal(head))
+            } else {
+                (!head.is_empty()).then(|| TranslationKey::prefix(head))
+            }
+        }
+        Expr::Bin(bin) if bin.op == BinaryOp::Add => match key_of(&bin.left)? {
+            key if key.dynamic => Some(key),
+            key => (!key.key.is_empty()).then(|| TranslationKey::prefix(key.key)),
+        },
+        Expr::Paren(paren) => key_of(&paren.expr),
+        _ => None,
+    }
+}
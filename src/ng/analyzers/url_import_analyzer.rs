@@ -0,0 +1,63 @@
+use swc_ecma_ast::{CallExpr, Callee, Expr, Lit, MemberProp, MetaPropKind, NewExpr};
+
+/// Files a module loads by URL rather than by import: workers and assets
+/// referenced with `new URL('./x', import.meta.url)`, the form bundlers
+/// (Vite, webpack 5, esbuild and Angular's application builder) emit as
+/// separate chunks.
+pub struct UrlImportAnalyzer;
+
+impl UrlImportAnalyzer {
+    /// `new URL('./heavy.worker', import.meta.url)` → the relative
+    /// specifier. Absolute URLs and computed specifiers name no workspace
+    /// file.
+    pub fn specifier(new: &NewExpr) -> Option<String> {
+        if !matches!(&*new.callee, Expr::Ident(callee) if callee.sym == *"URL") {
+            return None;
+        }
+        let args = new.args.as_ref()?;
+        let [specifier, base] = args.as_slice() else {
+            return None;
+        };
+        let Expr::Member(base) = &*base.expr else {
+            return None;
+        };
+        let is_import_meta_url = matches!(
+            &*base.obj,
+            Expr::MetaProp(meta) if meta.kind == MetaPropKind::ImportMeta
+        ) && matches!(&base.prop, MemberProp::Ident(prop) if prop.sym == *"url");
+        match &*specifier.expr {
+            Expr::Lit(Lit::Str(specifier))
+                if is_import_meta_url && specifier.value.starts_with('.') =>
+            {
+                Some(specifier.value.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// `new Worker(...)` / `new SharedWorker(...)` — the Angular CLI's
+    /// `ng generate web-worker` writes exactly
+    /// `new Worker(new URL('./app.worker', import.meta.url))`.
+    pub fn is_worker_constructor(new: &NewExpr) -> bool {
+        matches!(
+            &*new.callee,
+            Expr::Ident(callee) if callee.sym == *"Worker" || callee.sym == *"SharedWorker"
+        )
+    }
+
+    /// `navigator.serviceWorker.register(...)`.
+    pub fn is_service_worker_registration(call: &CallExpr) -> bool {
+        let Callee::Expr(callee) = &call.callee else {
+            return false;
+        };
+        let Expr::Member(member) = &**callee else {
+            return false;
+        };
+        matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == *"register")
+            && matches!(
+                &*member.obj,
+                Expr::Member(container)
+                    if matches!(&container.prop, MemberProp::Ident(prop) if prop.sym == *"serviceWorker")
+            )
+    }
+}
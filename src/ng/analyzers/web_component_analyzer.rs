@@ -0,0 +1,94 @@
+use crate::ng::templates::scanner;
+use swc_ecma_ast::{CallExpr, Callee, Class, Expr, Lit, MemberProp, TaggedTpl};
+
+/// Custom element registrations and the tags Lit templates render.
+pub struct WebComponentAnalyzer;
+
+impl WebComponentAnalyzer {
+    /// `@customElement('ui-button')` on a class → the tag.
+    pub fn decorator_tag(class: &Class) -> Option<String> {
+        class.decorators.iter().find_map(|decorator| {
+            let Expr::Call(call) = &*decorator.expr else {
+                return None;
+            };
+            match &call.callee {
+                Callee::Expr(callee) => match &**callee {
+                    Expr::Ident(ident) if ident.sym == *"customElement" => first_string(call),
+                    _ => None,
+                },
+                _ => None,
+            }
+        })
+    }
+
+    /// `customElements.define('ui-button', UiButton)`, also off `window` or
+    /// `globalThis` → the tag and the registered class; `None` for a class
+    /// expression without a name.
+    pub fn define_call(call: &CallExpr) -> Option<(String, Option<String>)> {
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let Expr::Member(member) = &**callee else {
+            return None;
+        };
+        if !matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == *"define") {
+            return None;
+        }
+        let registry = match &*member.obj {
+            Expr::Ident(ident) => &ident.sym,
+            Expr::Member(outer) => match (&*outer.obj, &outer.prop) {
+                (Expr::Ident(global), MemberProp::Ident(prop))
+                    if global.sym == *"window" || global.sym == *"globalThis" =>
+                {
+                    &prop.sym
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if *registry != *"customElements" {
+            return None;
+        }
+        let tag = first_string(call)?;
+        let class = match call.args.get(1).map(|arg| &*arg.expr) {
+            Some(Expr::Ident(class)) => Some(class.sym.to_string()),
+            Some(Expr::Class(class)) => class.ident.as_ref().map(|ident| ident.sym.to_string()),
+            _ => return None,
+        };
+        Some((tag, class))
+    }
+
+    /// Custom element tags of a Lit `` html`…` `` template, one per
+    /// occurrence. Interpolations are blanked: `<${tag}>` names no element.
+    pub fn html_tags(tagged: &TaggedTpl) -> Vec<String> {
+        if !matches!(&*tagged.tag, Expr::Ident(ident) if ident.sym == *"html") {
+            return Vec::new();
+        }
+        let markup = tagged
+            .tpl
+            .quasis
+            .iter()
+            .map(|quasi| quasi.raw.to_string())
+            .collect::<Vec<_>>()
+            .join("_");
+        scanner::scan_template(&markup)
+            .elements
+            .into_iter()
+            .map(|element| element.tag)
+            .filter(|tag| is_custom_element_tag(tag))
+            .collect()
+    }
+}
+
+/// The HTML spec's rule: a custom element name contains a hyphen and starts
+/// with a lowercase letter.
+pub fn is_custom_element_tag(tag: &str) -> bool {
+    tag.contains('-') && tag.starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+fn first_string(call: &CallExpr) -> Option<String> {
+    match call.args.first().map(|arg| &*arg.expr) {
+        Some(Expr::Lit(Lit::Str(tag))) => Some(tag.value.to_string()),
+        _ => None,
+    }
+}
@@ -1,12 +1,15 @@
+pub mod nest;
 pub mod ng_base;
 mod ng_component;
 pub mod ng_directive;
 mod ng_module;
 pub mod ng_pipe;
 mod ng_results;
+pub mod ng_route;
 pub mod ng_service;
+pub mod ngrx;
 
-pub use ng_component::NgComponentInfo;
+pub use ng_component::{ComponentMember, MemberKind, MemberVisibility, NgComponentInfo};
 pub use ng_module::NgModuleInfo;
 pub use ng_results::NgAnalysisResults;
 pub use ng_service::NgServiceInfo;
@@ -0,0 +1,52 @@
+use crate::ng::models::ng_base::NgBaseInfo;
+use serde::{Deserialize, Serialize};
+
+/// A NestJS `@Module({ ... })` class.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NestModuleInfo {
+    #[serde(flatten)]
+    pub base: NgBaseInfo,
+    /// Modules from `imports: [...]`; `AuthModule.forRoot(...)` and
+    /// `forwardRef(() => AuthModule)` name `AuthModule`.
+    pub imports_idents: Vec<String>,
+    pub controllers: Vec<String>,
+    pub providers: Vec<NestProvider>,
+    pub exports: Vec<String>,
+    /// `@Global()` — its exported providers are injectable everywhere.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub global: bool,
+}
+
+/// An entry of a module's `providers: [...]`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct NestProvider {
+    /// The class itself, or the `provide:` token — an identifier or a
+    /// string.
+    pub token: String,
+    /// `useClass:` / `useExisting:` — the class Nest instantiates or
+    /// aliases for the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+}
+
+/// A NestJS `@Controller(...)` class with its request handlers.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NestControllerInfo {
+    #[serde(flatten)]
+    pub base: NgBaseInfo,
+    /// `@Controller('users')` / `@Controller({ path: 'users' })`; empty
+    /// for `@Controller()`.
+    pub path: String,
+    pub routes: Vec<NestRouteInfo>,
+}
+
+/// A method decorated with `@Get(...)`, `@Post(...)`, …
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct NestRouteInfo {
+    /// `GET`, `POST`, …; `ALL` for `@All()`.
+    pub method: String,
+    /// Path given to the decorator, relative to the controller's; one route
+    /// per entry of `@Get(['a', 'b'])`.
+    pub path: String,
+    pub handler: String,
+}
@@ -1,4 +1,6 @@
+use crate::analysis::models::file_facts::TranslationKey;
 use crate::ng::models::ng_base::NgBaseInfo;
+use crate::ng::models::ng_directive::NgHostDirective;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -18,10 +20,59 @@ pub struct NgComponentInfo {
     /// Identifiers from `providers: [...]`.
     #[serde(default)]
     pub providers: Vec<String>,
+    /// `multi: true` entries of `providers` — contributions to a token
+    /// the framework collects, not providers anyone injects by name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multi_providers: Vec<String>,
     /// Input names: `@Input()` properties and signal `input()`/`model()`.
     #[serde(default)]
     pub inputs: Vec<String>,
     /// Output names: `@Output()` properties and signal `output()`.
     #[serde(default)]
     pub outputs: Vec<String>,
+    /// Directives applied to the host element (`hostDirectives: [...]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host_directives: Vec<NgHostDirective>,
+    /// Superclass name — members may be inherited or overridden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    /// Instance members declared in the class body, constructor parameter
+    /// properties included.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<ComponentMember>,
+    /// Members the class itself reads (`this.x`) or its `host` bindings use.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub member_refs: Vec<String>,
+    /// Identifiers the template reads from the instance. `None` when the
+    /// template could not be read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_members: Option<Vec<String>>,
+    /// Translation keys the template looks up.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub template_translation_keys: Vec<TranslationKey>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ComponentMember {
+    pub name: String,
+    pub kind: MemberKind,
+    pub visibility: MemberVisibility,
+    /// `@Input()`, `@HostListener(...)`, `@ViewChild(...)`, … — Angular
+    /// wires it up.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub decorated: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MemberKind {
+    Property,
+    Method,
+    Accessor,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MemberVisibility {
+    Public,
+    Protected,
+    Private,
 }
@@ -9,8 +9,48 @@ pub struct NgDirectiveInfo {
     pub standalone: bool,
     pub host_bindings: Vec<String>,
     pub host_listeners: Vec<String>,
+    /// Tokens from `providers: [...]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub providers: Vec<String>,
+    /// `multi: true` entries of `providers` — contributions to a token
+    /// the framework collects, not providers anyone injects by name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multi_providers: Vec<String>,
     #[serde(default)]
     pub inputs: Vec<String>,
     #[serde(default)]
     pub outputs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host_directives: Vec<NgHostDirective>,
+}
+
+/// One `hostDirectives` entry: `TooltipDirective`, or
+/// `{ directive: MenuDirective, inputs: ['menuId: id'], outputs: [...] }`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NgHostDirective {
+    pub directive: String,
+    /// Forwarded inputs as written: `name` or `name: alias`, where `alias` is
+    /// what the host's element binds.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outputs: Vec<String>,
+}
+
+impl NgHostDirective {
+    /// Forwarded inputs as (directive input, name on the host element).
+    pub fn forwarded_inputs(&self) -> Vec<(&str, &str)> {
+        self.inputs.iter().map(|spec| split_alias(spec)).collect()
+    }
+
+    pub fn forwarded_outputs(&self) -> Vec<(&str, &str)> {
+        self.outputs.iter().map(|spec| split_alias(spec)).collect()
+    }
+}
+
+fn split_alias(spec: &str) -> (&str, &str) {
+    match spec.split_once(':') {
+        Some((name, alias)) => (name.trim(), alias.trim()),
+        None => (spec.trim(), spec.trim()),
+    }
 }
@@ -9,7 +9,15 @@ pub struct NgModuleInfo {
     /// Identifiers from the NgModule `imports: [...]` array.
     #[serde(default)]
     pub imports_idents: Vec<String>,
+    /// `imports` entries written as `X.forRoot(...)`, `X.forChild(...)` or
+    /// `X.forFeature(...)` (also listed in `imports_idents` as `X`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub configured_imports: Vec<String>,
     pub exports: Vec<String>,
     pub providers: Vec<String>,
+    /// `multi: true` entries of `providers` — contributions to a token
+    /// the framework collects, not providers anyone injects by name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multi_providers: Vec<String>,
     pub bootstrap: Vec<String>,
 }
@@ -1,11 +1,15 @@
 use super::{NgComponentInfo, NgModuleInfo, NgServiceInfo};
 use crate::analysis::models::file_facts::FileFactsInfo;
-use crate::analysis::models::react::ReactComponentInfo;
+use crate::analysis::models::react::{ReactComponentInfo, ReactContextInfo, ReactHookInfo};
+use crate::analysis::models::style::StyleFileInfo;
+use crate::analysis::models::vue::VueComponentInfo;
+use crate::analysis::models::web_component::CustomElementInfo;
 use crate::analysis::resolvers::import_resolver::ImportResolver;
 use crate::ng;
 
 use crate::analysis::processor::collector::AnalysisCollector;
 use crate::analysis::processor::context::AnalysisContext;
+use crate::ng::models::nest::{NestControllerInfo, NestModuleInfo};
 use crate::ng::models::ng_directive::NgDirectiveInfo;
 use crate::ng::models::ng_pipe::NgPipeInfo;
 use serde::{Deserialize, Serialize};
@@ -18,12 +22,33 @@ pub struct NgAnalysisResults {
     pub modules: Vec<NgModuleInfo>,
     pub pipes: Vec<NgPipeInfo>,
     pub services: Vec<NgServiceInfo>,
+    /// NestJS `@Module` classes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nest_modules: Vec<NestModuleInfo>,
+    /// NestJS `@Controller` classes with their routes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nest_controllers: Vec<NestControllerInfo>,
     /// Framework-agnostic per-file facts (exports, imports, dynamic imports).
     #[serde(default)]
     pub source_files: Vec<FileFactsInfo>,
     /// React function components found in .tsx files.
     #[serde(default)]
     pub react_components: Vec<ReactComponentInfo>,
+    /// Exported custom hooks (`useX` functions).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub react_hooks: Vec<ReactHookInfo>,
+    /// Contexts created with `createContext`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub react_contexts: Vec<ReactContextInfo>,
+    /// Vue single-file components, one per `.vue` file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vue_components: Vec<VueComponentInfo>,
+    /// Custom elements with their tags — Lit or plain `HTMLElement`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_elements: Vec<CustomElementInfo>,
+    /// Stylesheets with their `@use`/`@forward`/`@import` edges.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub style_files: Vec<StyleFileInfo>,
 }
 
 impl NgAnalysisResults {
@@ -45,9 +70,24 @@ impl NgAnalysisResults {
         self.services.sort_by(|a, b| {
             (&a.base.source_path, &a.base.name).cmp(&(&b.base.source_path, &b.base.name))
         });
+        self.nest_modules.sort_by(|a, b| {
+            (&a.base.source_path, &a.base.name).cmp(&(&b.base.source_path, &b.base.name))
+        });
+        self.nest_controllers.sort_by(|a, b| {
+            (&a.base.source_path, &a.base.name).cmp(&(&b.base.source_path, &b.base.name))
+        });
         self.source_files.sort_by(|a, b| a.path.cmp(&b.path));
         self.react_components
             .sort_by(|a, b| (&a.source_path, &a.name).cmp(&(&b.source_path, &b.name)));
+        self.react_hooks
+            .sort_by(|a, b| (&a.source_path, &a.name).cmp(&(&b.source_path, &b.name)));
+        self.react_contexts
+            .sort_by(|a, b| (&a.source_path, &a.name).cmp(&(&b.source_path, &b.name)));
+        self.vue_components
+            .sort_by(|a, b| (&a.source_path, &a.name).cmp(&(&b.source_path, &b.name)));
+        self.custom_elements
+            .sort_by(|a, b| (&a.source_path, &a.tag).cmp(&(&b.source_path, &b.tag)));
+        self.style_files.sort_by(|a, b| a.path.cmp(&b.path));
     }
 }
 
@@ -58,8 +98,15 @@ impl AnalysisCollector for NgAnalysisResults {
         self.modules.extend(other.modules);
         self.directives.extend(other.directives);
         self.pipes.extend(other.pipes);
+        self.nest_modules.extend(other.nest_modules);
+        self.nest_controllers.extend(other.nest_controllers);
         self.source_files.extend(other.source_files);
         self.react_components.extend(other.react_components);
+        self.react_hooks.extend(other.react_hooks);
+        self.react_contexts.extend(other.react_contexts);
+        self.vue_components.extend(other.vue_components);
+        self.custom_elements.extend(other.custom_elements);
+        self.style_files.extend(other.style_files);
     }
 
     fn process_file(
@@ -74,6 +121,7 @@ impl AnalysisCollector for NgAnalysisResults {
             context.project_name.as_ref().clone(),
             context.project_ts_config.clone(),
             context.default_standalone,
+            &context.component_openers,
             resolver,
             &context.file_reader,
         )
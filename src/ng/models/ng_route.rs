@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// One entry of an Angular route table (`Routes` array literal).
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NgRouteInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// `component: X` — identifier as written in the routes file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    /// `loadComponent: () => import('x').then(m => m.X)` — specifier and
+    /// export name (`None` for a default export).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_component: Option<LazyTarget>,
+    /// `loadChildren: () => import('x').then(m => m.ROUTES)` — the route
+    /// tables of the target file become this route's children.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_children: Option<LazyTarget>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<NgRouteInfo>,
+    /// Route-level `providers: [...]` tokens — an environment injector for
+    /// this route and everything below it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub providers: Vec<String>,
+    /// `multi: true` entries of `providers` — contributions to a token
+    /// the framework collects, not providers anyone injects by name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multi_providers: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LazyTarget {
+    pub specifier: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export: Option<String>,
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+/// NgRx store constructs declared and used in one file. References are
+/// written as in the source: `loadUsers`, `UserActions.loadSuccess`,
+/// `fromUsers.selectAll`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NgRxFacts {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub declarations: Vec<NgRxDeclaration>,
+    /// Action creators called where the action is dispatched: inside
+    /// `dispatch(...)` and in effect bodies (an effect emits what it maps to).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dispatches: Vec<String>,
+    /// `on(...)` in reducers and `ofType(...)` in effects.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub handlers: Vec<NgRxHandler>,
+    /// Selectors passed to `store.select`, `selectSignal` or the `select`
+    /// operator — called there when it is a selector factory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selections: Vec<String>,
+}
+
+impl NgRxFacts {
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+            && self.dispatches.is_empty()
+            && self.handlers.is_empty()
+            && self.selections.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct NgRxDeclaration {
+    /// Variable name; `Group.event` for the actions of a
+    /// `createActionGroup`, `Class.property` for an effect in a class.
+    pub name: String,
+    pub kind: NgRxKind,
+    /// Selectors a `createSelector` combines.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum NgRxKind {
+    /// `createAction`, or one event of `createActionGroup`.
+    Action,
+    /// `createSelector`, `createFeatureSelector`, or a function returning
+    /// `createSelector(...)` (a selector factory).
+    Selector,
+    Effect,
+    Reducer,
+    Feature,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct NgRxHandler {
+    /// Reducer, feature or effect the handler belongs to.
+    pub owner: String,
+    pub kind: NgRxHandlerKind,
+    pub actions: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum NgRxHandlerKind {
+    On,
+    OfType,
+}
@@ -0,0 +1,520 @@
+use crate::analysis::models::file_facts::TranslationKey;
+use std::collections::{HashMap, HashSet};
+
+/// Pipes whose input is a translation key (ngx-translate, Transloco).
+const TRANSLATION_PIPES: &[&str] = &["translate", "transloco"];
+
+/// Literals and keywords — never component members.
+const RESERVED: &[&str] = &[
+    "true",
+    "false",
+    "null",
+    "undefined",
+    "this",
+    "typeof",
+    "void",
+    "in",
+];
+
+/// Expressions and declared names of a binding that is more than one
+/// expression: `*ngFor`-style microsyntax or a control flow block's
+/// parameters.
+#[derive(Debug, Default, PartialEq)]
+pub struct Microsyntax<'a> {
+    pub expressions: Vec<&'a str>,
+    pub locals: Vec<&'a str>,
+}
+
+/// Collects the root identifiers an expression reads from the component:
+/// `user` in `user?.name | titlecase`, `save` in `save($event)`. Property
+/// names after `.`, pipe names, object-literal keys, string contents and
+/// `$`-prefixed template variables (`$event`, `$index`, `$any`) are
+/// skipped; `this.x` reads `x`.
+pub fn collect_reads(expr: &str, reads: &mut HashSet<String>) {
+    let bytes = expr.as_bytes();
+    let mut brackets: Vec<u8> = Vec::new();
+    // Last two significant bytes before the current token; identifiers,
+    // numbers and strings are collapsed to one marker byte.
+    let (mut prev, mut prev2) = (0u8, 0u8);
+    let mut after_this = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == b'\'' || c == b'"' || c == b'`' {
+            i += 1;
+            while i < bytes.len() && bytes[i] != c {
+                if bytes[i] == b'\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            (prev2, prev) = (prev, b'"');
+            after_this = false;
+            continue;
+        }
+        if c.is_ascii_digit() {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'.') {
+                i += 1;
+            }
+            (prev2, prev) = (prev, b'0');
+            after_this = false;
+            continue;
+        }
+        if is_ident_start(c) {
+            let start = i;
+            while i < bytes.len() && is_ident_part(bytes[i]) {
+                i += 1;
+            }
+            let name = &expr[start..i];
+            let member_access = prev == b'.';
+            let pipe_name = prev == b'|' && prev2 != b'|';
+            let next = bytes[i..].iter().find(|b| !b.is_ascii_whitespace());
+            let object_key = brackets.last() == Some(&b'{')
+                && matches!(prev, b'{' | b',')
+                && next == Some(&b':');
+
+            if member_access {
+                if after_this {
+                    reads.insert(name.to_string());
+                }
+            } else if !pipe_name
+                && !object_key
+                && !name.starts_with('$')
+                && !RESERVED.contains(&name)
+            {
+                reads.insert(name.to_string());
+            }
+            after_this = name == "this" && !member_access;
+            (prev2, prev) = (prev, b'a');
+            continue;
+        }
+
+        match c {
+            b'(' | b'[' | b'{' => brackets.push(c),
+            b')' | b']' | b'}' => {
+                brackets.pop();
+            }
+            _ => {}
+        }
+        // `this?.x` / `this!.x` still read `x`.
+        if !matches!(c, b'.' | b'?' | b'!') {
+            after_this = false;
+        }
+        (prev2, prev) = (prev, c);
+        i += 1;
+    }
+}
+
+/// Structural directive microsyntax:
+/// `let item of items; index as i; trackBy: byId` → expressions `items`,
+/// `byId`; locals `item`, `i`. `expr as alias` declares `alias`.
+pub fn parse_microsyntax(value: &str) -> Microsyntax<'_> {
+    let mut parsed = Microsyntax::default();
+    for (index, segment) in value.split(';').enumerate() {
+        let mut segment = segment.trim();
+        let mut keyed = index > 0;
+        if let Some(rest) = strip_keyword(segment, "let") {
+            let (name, after) = take_ident(rest);
+            if !name.is_empty() {
+                parsed.locals.push(name);
+            }
+            // `let i = index` binds a context value, not an expression.
+            if after.starts_with('=') {
+                continue;
+            }
+            segment = after;
+            keyed = true;
+        }
+        if segment.is_empty() {
+            continue;
+        }
+        // `index as i`
+        if keyed {
+            let (key, after) = take_ident(segment);
+            if let Some(alias) = strip_keyword(after, "as") {
+                let (alias, rest) = take_ident(alias);
+                if !key.is_empty() && rest.is_empty() {
+                    parsed.locals.push(alias);
+                    continue;
+                }
+            }
+            segment = after.strip_prefix(':').unwrap_or(after).trim_start();
+        }
+        push_aliased(segment, &mut parsed);
+    }
+    parsed
+}
+
+/// Parameters of a control flow block, by keyword: `@if (user(); as u)`,
+/// `@for (item of items; track item.id; let i = $index)`, `@switch (x)`,
+/// `@case (y)`, `@defer (when ready)`. Blocks without expressions
+/// (`@placeholder (minimum 1s)`) yield nothing.
+pub fn parse_block_params<'a>(keyword: &str, params: &'a str) -> Microsyntax<'a> {
+    let mut parsed = Microsyntax::default();
+    match keyword {
+        "if" => {
+            for (index, segment) in params.split(';').enumerate() {
+                let segment = segment.trim();
+                match strip_keyword(segment, "as") {
+                    Some(alias) if index > 0 => parsed.locals.push(take_ident(alias).0),
+                    _ if !segment.is_empty() => parsed.expressions.push(segment),
+                    _ => {}
+                }
+            }
+        }
+        "for" => {
+            for (index, segment) in params.split(';').enumerate() {
+                let segment = segment.trim();
+                if index == 0 {
+                    let (item, after) = take_ident(segment);
+                    parsed.locals.push(item);
+                    if let Some(items) = strip_keyword(after, "of") {
+                        parsed.expressions.push(items);
+                    }
+                } else if let Some(track) = strip_keyword(segment, "track") {
+                    parsed.expressions.push(track);
+                } else if let Some(aliases) = strip_keyword(segment, "let") {
+                    for alias in aliases.split(',') {
+                        let (name, _) = take_ident(alias.trim());
+                        parsed.locals.push(name);
+                    }
+                }
+            }
+        }
+        "switch" | "case" => parsed.expressions.push(params.trim()),
+        "defer" => {
+            for segment in params.split(';') {
+                let segment = segment.trim();
+                let segment = strip_keyword(segment, "prefetch").unwrap_or(segment);
+                if let Some(condition) = strip_keyword(segment, "when") {
+                    parsed.expressions.push(condition);
+                }
+            }
+        }
+        _ => {}
+    }
+    parsed.locals.retain(|name| !name.is_empty());
+    parsed
+}
+
+/// Translation keys an expression looks up: the input of a `translate` /
+/// `transloco` pipe, and the first argument of a `*transloco` function
+/// (`functions` maps its local name to the `read:`/`prefix:` scope).
+pub fn collect_translation_keys(
+    expr: &str,
+    functions: &HashMap<String, String>,
+    keys: &mut Vec<TranslationKey>,
+) {
+    let bytes = expr.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' | b'"' => i = skip_string(bytes, i),
+            b'|' if bytes.get(i + 1) == Some(&b'|') => i += 2,
+            b'|' => {
+                let (name, _) = take_ident(expr[i + 1..].trim_start());
+                if TRANSLATION_PIPES.contains(&name) {
+                    if let Some(operand) = pipe_operand(expr, i) {
+                        keys.extend(translation_key_of(operand));
+                    }
+                }
+                i += 1;
+            }
+            c if is_ident_start(c) => {
+                let start = i;
+                while i < bytes.len() && is_ident_part(bytes[i]) {
+                    i += 1;
+                }
+                let member_access = expr[..start].trim_end().ends_with('.');
+                let Some(scope) = functions.get(&expr[start..i]).filter(|_| !member_access) else {
+                    continue;
+                };
+                let rest = expr[i..].trim_start();
+                if let Some(args) = rest.strip_prefix('(') {
+                    let end = top_level_end(args, b",)");
+                    for key in translation_key_of(&args[..end]) {
+                        keys.push(match scope.as_str() {
+                            "" => key,
+                            scope => TranslationKey {
+                                key: format!("{scope}.{}", key.key),
+                                dynamic: key.dynamic,
+                            },
+                        });
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+}
+
+/// The key(s) an expression evaluates to: a string literal, either branch
+/// of a ternary, or the literal prefix of a concatenation. Anything else
+/// is unknown.
+pub fn translation_key_of(expr: &str) -> Vec<TranslationKey> {
+    let expr = strip_parens(expr.trim());
+    if let Some(question) = find_top_level(expr, b'?') {
+        let branches = &expr[question + 1..];
+        return match find_top_level(branches, b':') {
+            Some(colon) => {
+                let mut keys = translation_key_of(&branches[..colon]);
+                keys.extend(translation_key_of(&branches[colon + 1..]));
+                keys
+            }
+            None => Vec::new(),
+        };
+    }
+    let bytes = expr.as_bytes();
+    if !matches!(bytes.first(), Some(b'\'' | b'"')) {
+        return Vec::new();
+    }
+    let end = skip_string(bytes, 0);
+    let literal = &expr[1..end.saturating_sub(1).max(1)];
+    let rest = expr[end.min(expr.len())..].trim_start();
+    if rest.is_empty() {
+        vec![TranslationKey::literal(literal)]
+    } else if rest.starts_with('+') && !literal.is_empty() {
+        vec![TranslationKey::prefix(literal)]
+    } else {
+        Vec::new()
+    }
+}
+
+/// `*transloco="let t; read: 'home'"` → (`t`, `home`). `prefix:` is the
+/// newer spelling of `read:`.
+pub fn parse_transloco(value: &str) -> Option<(&str, &str)> {
+    let mut segments = value.split(';');
+    let (name, _) = take_ident(strip_keyword(segments.next()?.trim(), "let")?);
+    let scope = segments
+        .filter_map(|segment| {
+            let segment = segment.trim();
+            strip_keyword(segment, "read").or_else(|| strip_keyword(segment, "prefix"))
+        })
+        .filter_map(|value| value.strip_prefix(':'))
+        .map(|value| value.trim().trim_matches(['\'', '"']))
+        .next()
+        .unwrap_or("");
+    (!name.is_empty()).then_some((name, scope))
+}
+
+/// Text the pipe at `pipe` applies to — back to the enclosing bracket, a
+/// comma or the start. `None` when the input is itself piped.
+fn pipe_operand(expr: &str, pipe: usize) -> Option<&str> {
+    let bytes = expr.as_bytes();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut i = pipe;
+    while i > 0 {
+        i -= 1;
+        match bytes[i] {
+            b'\'' | b'"' => {
+                let quote = bytes[i];
+                while i > 0 {
+                    i -= 1;
+                    if bytes[i] == quote {
+                        break;
+                    }
+                }
+            }
+            b')' | b']' | b'}' => depth += 1,
+            b'(' | b'[' | b'{' if depth == 0 => {
+                start = i + 1;
+                break;
+            }
+            b'(' | b'[' | b'{' => depth -= 1,
+            b',' | b';' if depth == 0 => {
+                start = i + 1;
+                break;
+            }
+            b'|' if depth == 0 => {
+                let logical_or = i > 0 && bytes[i - 1] == b'|';
+                if !logical_or && bytes.get(i + 1) != Some(&b'|') {
+                    return None;
+                }
+                i -= usize::from(logical_or);
+            }
+            _ => {}
+        }
+    }
+    Some(&expr[start..pipe])
+}
+
+/// Index just past the string literal opening at `start`.
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() && bytes[i] != quote {
+        if bytes[i] == b'\\' {
+            i += 1;
+        }
+        i += 1;
+    }
+    i + 1
+}
+
+/// First top-level (outside strings and brackets) occurrence of one of
+/// `stops`, or the length.
+fn top_level_end(expr: &str, stops: &[u8]) -> usize {
+    let bytes = expr.as_bytes();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' | b'"' => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            c if depth == 0 && stops.contains(&c) => return i,
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+fn find_top_level(expr: &str, byte: u8) -> Option<usize> {
+    let end = top_level_end(expr, &[byte]);
+    (end < expr.len()).then_some(end)
+}
+
+/// `('a' + b)` → `'a' + b` when the parentheses wrap the whole expression.
+fn strip_parens(expr: &str) -> &str {
+    let Some(inner) = expr.strip_prefix('(') else {
+        return expr;
+    };
+    if top_level_end(inner, b")") + 1 == expr.len() - 1 {
+        strip_parens(inner[..inner.len() - 1].trim())
+    } else {
+        expr
+    }
+}
+
+/// `expr as alias` → expression `expr`, local `alias`.
+fn push_aliased<'a>(segment: &'a str, parsed: &mut Microsyntax<'a>) {
+    if let Some((expr, alias)) = segment.rsplit_once(" as ") {
+        let (alias, rest) = take_ident(alias.trim());
+        if !alias.is_empty() && rest.is_empty() {
+            parsed.locals.push(alias);
+            parsed.expressions.push(expr.trim());
+            return;
+        }
+    }
+    parsed.expressions.push(segment);
+}
+
+/// `keyword rest` → `rest` (trimmed); `None` unless `keyword` is a whole word.
+fn strip_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    let rest = text.strip_prefix(keyword)?;
+    match rest.bytes().next() {
+        Some(b) if is_ident_part(b) => None,
+        _ => Some(rest.trim()),
+    }
+}
+
+/// Leading identifier and the trimmed rest.
+fn take_ident(text: &str) -> (&str, &str) {
+    let end = text
+        .bytes()
+        .position(|b| !is_ident_part(b))
+        .unwrap_or(text.len());
+    (&text[..end], text[end..].trim_start())
+}
+
+fn is_ident_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || b == b'$'
+}
+
+fn is_ident_part(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reads(expr: &str) -> Vec<String> {
+        let mut reads = HashSet::new();
+        collect_reads(expr, &mut reads);
+        let mut reads: Vec<String> = reads.into_iter().collect();
+        reads.sort();
+        reads
+    }
+
+    #[test]
+    fn reads_root_identifiers_only() {
+        assert_eq!(
+            reads("user?.name | titlecase: locale"),
+            vec!["locale", "user"]
+        );
+        assert_eq!(reads("save($event, item.id)"), vec!["item", "save"]);
+        assert_eq!(
+            reads("{ active: isActive, 'x': 1 } && this.total() > 0"),
+            vec!["isActive", "total"]
+        );
+        assert_eq!(reads("a || b ? 'yes' : null"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn microsyntax_separates_locals_from_expressions() {
+        let parsed = parse_microsyntax("let item of items | slice:0:5; index as i; trackBy: byId");
+        assert_eq!(parsed.locals, vec!["item", "i"]);
+        assert_eq!(parsed.expressions, vec!["items | slice:0:5", "byId"]);
+
+        let parsed = parse_microsyntax("user$ | async as user; else loading");
+        assert_eq!(parsed.locals, vec!["user"]);
+        assert_eq!(parsed.expressions, vec!["user$ | async", "loading"]);
+    }
+
+    #[test]
+    fn control_flow_parameters() {
+        let parsed = parse_block_params(
+            "for",
+            "row of rows(); track row.id; let i = $index, e = $even",
+        );
+        assert_eq!(parsed.locals, vec!["row", "i", "e"]);
+        assert_eq!(parsed.expressions, vec!["rows()", "row.id"]);
+
+        let parsed = parse_block_params("if", "session(); as s");
+        assert_eq!(parsed.locals, vec!["s"]);
+        assert_eq!(parsed.expressions, vec!["session()"]);
+
+        let parsed = parse_block_params("defer", "on viewport; when ready");
+        assert_eq!(parsed.expressions, vec!["ready"]);
+    }
+
+    #[test]
+    fn translation_keys_from_pipes_and_transloco_functions() {
+        let functions = HashMap::from([("t".to_string(), "cart".to_string())]);
+        let mut keys = Vec::new();
+        collect_translation_keys(
+            "(ok ? 'a.yes' : 'a.no') | translate: params || 'x' | uppercase",
+            &functions,
+            &mut keys,
+        );
+        collect_translation_keys("'errors.' + code | transloco", &functions, &mut keys);
+        collect_translation_keys("t('total') + user.t('ignored')", &functions, &mut keys);
+        assert_eq!(
+            keys,
+            vec![
+                TranslationKey::literal("a.yes"),
+                TranslationKey::literal("a.no"),
+                TranslationKey::prefix("errors."),
+                TranslationKey::literal("cart.total"),
+            ]
+        );
+
+        assert_eq!(parse_transloco("let t; read: 'home'"), Some(("t", "home")));
+        assert_eq!(
+            parse_transloco("let tr; prefix: \"a.b\""),
+            Some(("tr", "a.b"))
+        );
+    }
+}
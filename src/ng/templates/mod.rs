@@ -1,13 +1,21 @@
+pub mod expression;
 pub mod scanner;
 pub mod selector;
+pub mod sfc;
 
+use crate::analysis::models::file_facts::{FileFactsInfo, TranslationKey};
+use crate::analysis::models::web_component::{CustomElementInfo, ElementTagVia};
 use crate::analysis::resolvers::import_graph::ImportGraph;
-use crate::ng::models::NgAnalysisResults;
+use crate::ng::models::ng_base::NgBaseInfo;
+use crate::ng::models::ng_directive::{NgDirectiveInfo, NgHostDirective};
+use crate::ng::models::{NgAnalysisResults, NgComponentInfo};
 use selector::SimpleSelector;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
-/// A usage of an Angular entity inside a component template.
+/// A usage of an entity inside a template: Angular and Vue component
+/// templates, and custom element tags in JSX and Lit templates.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TemplateUsageInfo {
     pub component: String,
@@ -16,6 +24,17 @@ pub struct TemplateUsageInfo {
     pub target_path: PathBuf,
     pub target_kind: TemplateTargetKind,
     pub via: TemplateUsageVia,
+    /// One entry per matching element, with the target's declared inputs and
+    /// outputs bound on it. Empty for pipe usages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bindings: Vec<ElementBindings>,
+}
+
+/// Inputs and outputs of the target bound on one element occurrence.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ElementBindings {
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -23,12 +42,28 @@ pub enum TemplateTargetKind {
     Component,
     Directive,
     Pipe,
+    /// A custom element, matched by its tag.
+    CustomElement,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum TemplateUsageVia {
     Selector,
     Pipe,
+    /// Created in code (`createComponent(X)`, `dialog.open(X)`,
+    /// `new ComponentPortal(X)`, …) or through `ngComponentOutlet`.
+    /// `component` is the creating class, which need not be a component.
+    Dynamic,
+    /// Applied by `hostDirectives` of a component or directive matched in
+    /// the template; bindings are the forwarded inputs/outputs.
+    HostDirective,
+    /// A Vue SFC template tag, `<UserCard>` or `<user-card>`.
+    Tag,
+    /// A custom element tag in JSX; `component` is the enclosing top-level
+    /// declaration.
+    Jsx,
+    /// A custom element tag in a Lit `` html`…` `` template.
+    LitHtml,
 }
 
 struct SelectorEntry {
@@ -36,15 +71,87 @@ struct SelectorEntry {
     path: PathBuf,
     kind: TemplateTargetKind,
     selectors: Vec<SimpleSelector>,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl SelectorEntry {
+    /// Declared inputs/outputs of this entry bound on `element`, in
+    /// declaration order.
+    fn bindings_on(&self, element: &scanner::ElementUsage) -> ElementBindings {
+        ElementBindings {
+            inputs: self
+                .inputs
+                .iter()
+                .filter(|input| element.inputs.contains(*input))
+                .cloned()
+                .collect(),
+            outputs: self
+                .outputs
+                .iter()
+                .filter(|output| element.outputs.contains(*output))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// A directive a host applies through `hostDirectives`, with forwarded
+/// inputs/outputs as (declared name, name bound on the host element).
+struct ComposedDirective<'a> {
+    directive: &'a NgDirectiveInfo,
+    inputs: Vec<(&'a str, &'a str)>,
+    outputs: Vec<(&'a str, &'a str)>,
+}
+
+impl ComposedDirective<'_> {
+    fn bindings_on(&self, element: &scanner::ElementUsage) -> ElementBindings {
+        // Inputs forwarded from a nested host directive are reported there.
+        let bound = |pairs: &[(&str, &str)], declared: &[String], names: &HashSet<String>| {
+            pairs
+                .iter()
+                .filter(|(name, on_host)| {
+                    names.contains(*on_host) && declared.iter().any(|d| d == name)
+                })
+                .map(|(name, _)| name.to_string())
+                .collect()
+        };
+        ElementBindings {
+            inputs: bound(&self.inputs, &self.directive.inputs, &element.inputs),
+            outputs: bound(&self.outputs, &self.directive.outputs, &element.outputs),
+        }
+    }
 }
 
 /// Matches every component template (external or inline) against the
 /// workspace-wide registry of selectors and pipe names. Template usages are
 /// also recorded as dependency edges — a component used only in HTML is not
-/// dead code.
+/// dead code. Each component's `template_members` and
+/// `template_translation_keys` are filled on the way. Vue SFC templates are
+/// matched by tag instead.
 pub fn analyze_templates(
+    results: &mut NgAnalysisResults,
+    import_graph: &ImportGraph,
+) -> Vec<TemplateUsageInfo> {
+    let mut scanned: Vec<ScannedTemplate> = Vec::new();
+    let usages = match_templates(results, import_graph, &mut scanned);
+    for (index, members, mut keys) in scanned {
+        keys.sort();
+        keys.dedup();
+        let component = &mut results.components[index];
+        component.template_members = Some(members);
+        component.template_translation_keys = keys;
+    }
+    usages
+}
+
+/// Component index, instance reads and translation keys of one template.
+type ScannedTemplate = (usize, Vec<String>, Vec<TranslationKey>);
+
+fn match_templates(
     results: &NgAnalysisResults,
     import_graph: &ImportGraph,
+    scanned: &mut Vec<ScannedTemplate>,
 ) -> Vec<TemplateUsageInfo> {
     let mut registry: Vec<SelectorEntry> = Vec::new();
 
@@ -57,6 +164,8 @@ pub fn analyze_templates(
             path: component.base.source_path.clone(),
             kind: TemplateTargetKind::Component,
             selectors: selector::parse_selector(&component.selector),
+            inputs: component.inputs.clone(),
+            outputs: component.outputs.clone(),
         });
     }
     for directive in &results.directives {
@@ -68,6 +177,19 @@ pub fn analyze_templates(
             path: directive.base.source_path.clone(),
             kind: TemplateTargetKind::Directive,
             selectors: selector::parse_selector(&directive.selector),
+            inputs: directive.inputs.clone(),
+            outputs: directive.outputs.clone(),
+        });
+    }
+
+    for element in &results.custom_elements {
+        registry.push(SelectorEntry {
+            name: element.name.clone(),
+            path: element.source_path.clone(),
+            kind: TemplateTargetKind::CustomElement,
+            selectors: selector::parse_selector(&element.tag),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
         });
     }
 
@@ -84,9 +206,138 @@ pub fn analyze_templates(
         })
         .collect();
 
+    let files: HashMap<&Path, &FileFactsInfo> = results
+        .source_files
+        .iter()
+        .map(|file| (file.path.as_path(), file))
+        .collect();
+
+    // Host → directives it composes, transitively.
+    let mut composition: HashMap<(&Path, &str), Vec<ComposedDirective>> = HashMap::new();
+    let hosts = results
+        .components
+        .iter()
+        .map(|c| (&c.base, &c.host_directives))
+        .chain(
+            results
+                .directives
+                .iter()
+                .map(|d| (&d.base, &d.host_directives)),
+        );
+    for (base, host_directives) in hosts {
+        if !host_directives.is_empty() {
+            let composed = compose(
+                &base.source_path,
+                host_directives,
+                &files,
+                results,
+                &mut HashSet::new(),
+            );
+            composition.insert((base.source_path.as_path(), base.name.as_str()), composed);
+        }
+    }
+
     let mut usages = Vec::new();
+    for file in &results.source_files {
+        for render in &file.dynamic_renders {
+            if let Some(target) = resolve_class(
+                &render.component,
+                &file.path,
+                &files,
+                &results.components,
+                |c| &c.base,
+            ) {
+                // Top-level code (a route resolver, a plain function) is
+                // attributed to its file.
+                let stem = file.path.file_stem().and_then(|stem| stem.to_str());
+                let creator = render.class.as_deref().or(stem).unwrap_or_default();
+                import_graph.add_dependency(file.path.clone(), target.base.source_path.clone());
+                usages.push(dynamic_usage(creator, &file.path, target));
+            }
+        }
+    }
 
-    for component in &results.components {
+    // Custom element tags in JSX and Lit templates, one usage per
+    // rendering declaration and element.
+    for file in &results.source_files {
+        let stem = file.path.file_stem().and_then(|stem| stem.to_str());
+        let mut occurrences: BTreeMap<(&str, &Path, &str, ElementTagVia), usize> = BTreeMap::new();
+        for usage in &file.element_tags {
+            let Some(element) = custom_element(&results.custom_elements, &usage.tag, file) else {
+                continue;
+            };
+            let caller = usage.caller.as_deref().or(stem).unwrap_or_default();
+            let key = (
+                caller,
+                element.source_path.as_path(),
+                element.name.as_str(),
+                usage.via,
+            );
+            *occurrences.entry(key).or_insert(0) += 1;
+        }
+        for ((caller, path, name, via), count) in occurrences {
+            if path != file.path {
+                import_graph.add_dependency(file.path.clone(), path.to_path_buf());
+            }
+            usages.push(TemplateUsageInfo {
+                component: caller.to_string(),
+                component_path: file.path.clone(),
+                target: name.to_string(),
+                target_path: path.to_path_buf(),
+                target_kind: TemplateTargetKind::CustomElement,
+                via: match via {
+                    ElementTagVia::Jsx => TemplateUsageVia::Jsx,
+                    ElementTagVia::LitHtml => TemplateUsageVia::LitHtml,
+                },
+                bindings: vec![ElementBindings::default(); count],
+            });
+        }
+    }
+
+    // A Vue tag renders the component imported under that name, else the
+    // only workspace SFC of that name — registered globally with
+    // `app.component(...)` or auto-imported.
+    for vue in &results.vue_components {
+        let facts = files.get(vue.source_path.as_path());
+        for tag in &vue.template_components {
+            let import = facts.and_then(|facts| {
+                facts
+                    .imports
+                    .iter()
+                    .find(|import| import.imported_item.name == *tag)
+            });
+            let target = match import {
+                Some(import) => results
+                    .vue_components
+                    .iter()
+                    .find(|target| target.source_path == import.resolved_path),
+                None => {
+                    let mut named = results
+                        .vue_components
+                        .iter()
+                        .filter(|target| target.name == *tag);
+                    named.next().filter(|_| named.next().is_none())
+                }
+            };
+            let Some(target) = target else {
+                continue;
+            };
+            if target.source_path != vue.source_path {
+                import_graph.add_dependency(vue.source_path.clone(), target.source_path.clone());
+            }
+            usages.push(TemplateUsageInfo {
+                component: vue.name.clone(),
+                component_path: vue.source_path.clone(),
+                target: target.name.clone(),
+                target_path: target.source_path.clone(),
+                target_kind: TemplateTargetKind::Component,
+                via: TemplateUsageVia::Tag,
+                bindings: Vec::new(),
+            });
+        }
+    }
+
+    for (index, component) in results.components.iter().enumerate() {
         let template = match &component.template_inline {
             Some(inline) => Some(inline.clone()),
             None if !component.template_path.is_empty() => {
@@ -115,21 +366,80 @@ pub fn analyze_templates(
         };
 
         let scan = scanner::scan_template(&template);
+        scanned.push((
+            index,
+            scan.instance_reads()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            scan.translation_keys.clone(),
+        ));
+
+        // `*ngComponentOutlet="panel"`: the component class picks what to
+        // render, so every workspace component its file references counts.
+        let has_outlet = scan
+            .elements
+            .iter()
+            .any(|element| element.inputs.contains("ngComponentOutlet"));
+        if let Some(facts) = files
+            .get(component.base.source_path.as_path())
+            .filter(|_| has_outlet)
+        {
+            for name in &facts.used_import_names {
+                if let Some(target) =
+                    resolve_class(name, &facts.path, &files, &results.components, |c| &c.base)
+                {
+                    import_graph.add_dependency(
+                        component.base.source_path.clone(),
+                        target.base.source_path.clone(),
+                    );
+                    usages.push(dynamic_usage(
+                        &component.base.name,
+                        &component.base.source_path,
+                        target,
+                    ));
+                }
+            }
+        }
 
         for entry in &registry {
-            let matched = scan
+            let elements: Vec<&scanner::ElementUsage> = scan
                 .elements
                 .iter()
-                .any(|element| selector::matches(&entry.selectors, element));
-            if matched {
-                import_graph.add_dependency(component.base.source_path.clone(), entry.path.clone());
+                .filter(|element| selector::matches(&entry.selectors, element))
+                .collect();
+            if elements.is_empty() {
+                continue;
+            }
+            import_graph.add_dependency(component.base.source_path.clone(), entry.path.clone());
+            usages.push(TemplateUsageInfo {
+                component: component.base.name.clone(),
+                component_path: component.base.source_path.clone(),
+                target: entry.name.clone(),
+                target_path: entry.path.clone(),
+                target_kind: entry.kind.clone(),
+                via: TemplateUsageVia::Selector,
+                bindings: elements.iter().map(|e| entry.bindings_on(e)).collect(),
+            });
+
+            let composed = composition
+                .get(&(entry.path.as_path(), entry.name.as_str()))
+                .into_iter()
+                .flatten();
+            for composed in composed {
+                let directive = &composed.directive.base;
+                import_graph.add_dependency(
+                    component.base.source_path.clone(),
+                    directive.source_path.clone(),
+                );
                 usages.push(TemplateUsageInfo {
                     component: component.base.name.clone(),
                     component_path: component.base.source_path.clone(),
-                    target: entry.name.clone(),
-                    target_path: entry.path.clone(),
-                    target_kind: entry.kind.clone(),
-                    via: TemplateUsageVia::Selector,
+                    target: directive.name.clone(),
+                    target_path: directive.source_path.clone(),
+                    target_kind: TemplateTargetKind::Directive,
+                    via: TemplateUsageVia::HostDirective,
+                    bindings: elements.iter().map(|e| composed.bindings_on(e)).collect(),
                 });
             }
         }
@@ -145,6 +455,7 @@ pub fn analyze_templates(
                     target_path: (*pipe_path).clone(),
                     target_kind: TemplateTargetKind::Pipe,
                     via: TemplateUsageVia::Pipe,
+                    bindings: Vec::new(),
                 });
             }
         }
@@ -159,3 +470,135 @@ pub fn analyze_templates(
     });
     usages
 }
+
+/// Directives `host_directives` (declared in `host_path`) apply, following
+/// directives that compose further ones; `seen` breaks cycles.
+fn compose<'a>(
+    host_path: &Path,
+    host_directives: &'a [NgHostDirective],
+    files: &HashMap<&Path, &FileFactsInfo>,
+    results: &'a NgAnalysisResults,
+    seen: &mut HashSet<(&'a Path, &'a str)>,
+) -> Vec<ComposedDirective<'a>> {
+    let mut composed = Vec::new();
+    for host_directive in host_directives {
+        let Some(directive) = resolve_class(
+            &host_directive.directive,
+            host_path,
+            files,
+            &results.directives,
+            |d| &d.base,
+        ) else {
+            continue;
+        };
+        if !seen.insert((&directive.base.source_path, &directive.base.name)) {
+            continue;
+        }
+        let inputs = host_directive.forwarded_inputs();
+        let outputs = host_directive.forwarded_outputs();
+        let nested = compose(
+            &directive.base.source_path,
+            &directive.host_directives,
+            files,
+            results,
+            seen,
+        );
+        // A nested directive's input reaches this host's element only if
+        // `directive` forwards it further.
+        let forward = |pairs: Vec<(&'a str, &'a str)>, via: &[(&'a str, &'a str)]| {
+            pairs
+                .into_iter()
+                .filter_map(|(name, on_directive)| {
+                    via.iter()
+                        .find(|(forwarded, _)| *forwarded == on_directive)
+                        .map(|(_, on_host)| (name, *on_host))
+                })
+                .collect()
+        };
+        for inner in nested {
+            composed.push(ComposedDirective {
+                directive: inner.directive,
+                inputs: forward(inner.inputs, &inputs),
+                outputs: forward(inner.outputs, &outputs),
+            });
+        }
+        composed.push(ComposedDirective {
+            directive,
+            inputs,
+            outputs,
+        });
+    }
+    composed
+}
+
+fn dynamic_usage(
+    creator: &str,
+    creator_path: &Path,
+    target: &NgComponentInfo,
+) -> TemplateUsageInfo {
+    TemplateUsageInfo {
+        component: creator.to_string(),
+        component_path: creator_path.to_path_buf(),
+        target: target.base.name.clone(),
+        target_path: target.base.source_path.clone(),
+        target_kind: TemplateTargetKind::Component,
+        via: TemplateUsageVia::Dynamic,
+        bindings: Vec::new(),
+    }
+}
+
+/// The element registered under `tag`; a registration in the using file's
+/// own package wins when a tag is defined twice.
+fn custom_element<'a>(
+    elements: &'a [CustomElementInfo],
+    tag: &str,
+    file: &FileFactsInfo,
+) -> Option<&'a CustomElementInfo> {
+    let mut defined = elements.iter().filter(|element| element.tag == tag);
+    let first = defined.next()?;
+    Some(
+        std::iter::once(first)
+            .chain(defined)
+            .find(|element| element.package_name == file.package_name)
+            .unwrap_or(first),
+    )
+}
+
+/// The workspace class `name` refers to in `file`: an import (through a
+/// barrel the target is found by package) or a class of the file itself.
+fn resolve_class<'a, T>(
+    name: &str,
+    file: &Path,
+    files: &HashMap<&Path, &FileFactsInfo>,
+    candidates: &'a [T],
+    base: impl Fn(&T) -> &NgBaseInfo,
+) -> Option<&'a T> {
+    let import = files.get(file).and_then(|facts| {
+        facts
+            .imports
+            .iter()
+            .find(|import| import.imported_item.name == name)
+    });
+    match import {
+        Some(import) => {
+            let exported = import
+                .imported_item
+                .alias
+                .as_deref()
+                .unwrap_or(&import.imported_item.name);
+            let package = files
+                .get(import.resolved_path.as_path())
+                .map(|facts| facts.package_name.as_str());
+            candidates.iter().find(|candidate| {
+                let candidate = base(candidate);
+                candidate.name == exported
+                    && (candidate.source_path == import.resolved_path
+                        || package == Some(candidate.package_name.as_str()))
+            })
+        }
+        None => candidates.iter().find(|candidate| {
+            let candidate = base(candidate);
+            candidate.name == name && candidate.source_path == file
+        }),
+    }
+}
@@ -1,18 +1,49 @@
-use std::collections::HashSet;
+use super::expression;
+use crate::analysis::models::file_facts::TranslationKey;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 /// One element occurrence in a template with everything selector matching
-/// needs: tag name, normalized attribute names, classes.
+/// needs: tag name, normalized attribute names, classes — plus which names
+/// are bound as inputs and outputs, for input/output usage analytics.
 #[derive(Debug)]
 pub struct ElementUsage {
     pub tag: String,
     pub attributes: HashSet<String>,
     pub classes: HashSet<String>,
+    /// `[x]`, `[(x)]`, `*x` and static `x="..."` — a static attribute
+    /// initializes an input of the same name.
+    pub inputs: HashSet<String>,
+    /// `(x)`, plus the implicit `xChange` of a `[(x)]` two-way binding.
+    pub outputs: HashSet<String>,
 }
 
 #[derive(Debug, Default)]
 pub struct TemplateScan {
     pub elements: Vec<ElementUsage>,
     pub pipes: HashSet<String>,
+    /// Root identifiers read by bindings, interpolations and control flow
+    /// parameters.
+    pub reads: HashSet<String>,
+    /// Names the template declares itself: `#ref`, `let-x`, microsyntax and
+    /// `@for`/`@let` variables, `as` aliases.
+    pub locals: HashSet<String>,
+    /// Keys looked up through translation pipes, directives, `*transloco`
+    /// functions and `i18n="@@id"` attributes.
+    pub translation_keys: Vec<TranslationKey>,
+    /// `*transloco="let t; read: 'scope'"` → `t` → `scope`.
+    translate_functions: HashMap<String, String>,
+}
+
+impl TemplateScan {
+    /// Identifiers read from the component instance: reads that are not
+    /// template locals.
+    pub fn instance_reads(&self) -> BTreeSet<&str> {
+        self.reads
+            .iter()
+            .filter(|name| !self.locals.contains(*name))
+            .map(String::as_str)
+            .collect()
+    }
 }
 
 /// Lightweight Angular-template scanner. Handles binding sugar (`[prop]`,
@@ -46,8 +77,8 @@ pub fn scan_template(html: &str) -> TemplateScan {
                 .find('<')
                 .map(|off| i + off)
                 .unwrap_or(bytes.len());
-            scan_interpolations(&html[i..end], &mut scan.pipes);
-            scan_control_flow_expressions(&html[i..end], &mut scan.pipes);
+            scan_interpolations(&html[i..end], &mut scan);
+            scan_control_flow_expressions(&html[i..end], &mut scan);
             i = end;
         }
     }
@@ -77,6 +108,8 @@ fn parse_element(html: &str, start: usize, scan: &mut TemplateScan) -> usize {
         tag,
         attributes: HashSet::new(),
         classes: HashSet::new(),
+        inputs: HashSet::new(),
+        outputs: HashSet::new(),
     };
 
     while i < bytes.len() && bytes[i] != b'>' {
@@ -131,7 +164,7 @@ fn parse_element(html: &str, start: usize, scan: &mut TemplateScan) -> usize {
             }
         }
 
-        process_attribute(raw_name, value, &mut element, &mut scan.pipes);
+        process_attribute(raw_name, value, &mut element, scan);
     }
 
     scan.elements.push(element);
@@ -139,16 +172,23 @@ fn parse_element(html: &str, start: usize, scan: &mut TemplateScan) -> usize {
 }
 
 /// Normalizes binding sugar and records the attribute. Binding values are
-/// expressions — scanned for pipes.
+/// expressions — scanned for pipes and reads.
 fn process_attribute(
     raw_name: &str,
     value: Option<&str>,
     element: &mut ElementUsage,
-    pipes: &mut HashSet<String>,
+    scan: &mut TemplateScan,
 ) {
     if raw_name.is_empty() {
         return;
     }
+    let local = raw_name
+        .strip_prefix('#')
+        .or_else(|| raw_name.strip_prefix("ref-"))
+        .or_else(|| raw_name.strip_prefix("let-"));
+    if let Some(local) = local {
+        scan.locals.insert(local.to_string());
+    }
 
     let (normalized, is_binding) = normalize_attribute_name(raw_name);
 
@@ -160,18 +200,74 @@ fn process_attribute(
                 }
             }
         }
+        record_binding(raw_name, &name, element);
         element.attributes.insert(name);
     }
 
     if let Some(value) = value {
-        if is_binding {
-            extract_pipes(value, pipes);
-        } else {
-            scan_interpolations(value, pipes);
+        record_translation_attribute(raw_name, value, is_binding, scan);
+        if raw_name.starts_with('*') {
+            let parsed = expression::parse_microsyntax(value);
+            scan.locals
+                .extend(parsed.locals.iter().map(|name| name.to_string()));
+            for expr in parsed.expressions {
+                scan_expression(expr, scan);
+            }
+        } else if is_binding {
+            scan_expression(value, scan);
+        } else if local.is_none() {
+            scan_interpolations(value, scan);
         }
     }
 }
 
+fn scan_expression(expr: &str, scan: &mut TemplateScan) {
+    extract_pipes(expr, &mut scan.pipes);
+    expression::collect_reads(expr, &mut scan.reads);
+    expression::collect_translation_keys(
+        expr,
+        &scan.translate_functions,
+        &mut scan.translation_keys,
+    );
+}
+
+/// `*transloco="let t"` declares a translate function; `translate="key"`,
+/// `transloco="key"` and their `[bound]="'key'"` forms name a key, as does
+/// the custom id of `i18n="meaning|description@@id"` / `i18n-title`.
+fn record_translation_attribute(
+    raw_name: &str,
+    value: &str,
+    is_binding: bool,
+    scan: &mut TemplateScan,
+) {
+    if raw_name == "*transloco" {
+        if let Some((name, scope)) = expression::parse_transloco(value) {
+            scan.translate_functions
+                .insert(name.to_string(), scope.to_string());
+        }
+        return;
+    }
+    if raw_name == "i18n" || raw_name.starts_with("i18n-") {
+        if let Some((_, id)) = value.split_once("@@") {
+            let id = id.trim();
+            if !id.is_empty() {
+                scan.translation_keys
+                    .push(TranslationKey::literal(format!("@@{id}")));
+            }
+        }
+        return;
+    }
+    match raw_name {
+        "[translate]" | "[transloco]" if is_binding => scan
+            .translation_keys
+            .extend(expression::translation_key_of(value)),
+        "translate" | "transloco" if !value.trim().is_empty() && !value.contains("{{") => scan
+            .translation_keys
+            .push(TranslationKey::literal(value.trim())),
+        _ => {}
+    }
+}
+
 /// `[prop]` / `(event)` / `[(model)]` / `*structural` / `attr` →
 /// (normalized name, is the value an expression).
 fn normalize_attribute_name(raw: &str) -> (Option<String>, bool) {
@@ -195,10 +291,24 @@ fn normalize_attribute_name(raw: &str) -> (Option<String>, bool) {
     (Some(raw.to_string()), false)
 }
 
+/// Classifies a normalized attribute as an input and/or output binding by the
+/// sugar it was written with.
+fn record_binding(raw: &str, name: &str, element: &mut ElementUsage) {
+    if raw.starts_with("[(") {
+        element.inputs.insert(name.to_string());
+        element.outputs.insert(format!("{}Change", name));
+    } else if raw.starts_with('(') {
+        element.outputs.insert(name.to_string());
+    } else {
+        element.inputs.insert(name.to_string());
+    }
+}
+
 /// Angular control flow blocks carry expressions in parentheses:
 /// `@if (items | uiHas)`, `@for (item of list | uiSort; track item)`,
-/// `@switch (mode | uiMap)`. Extracts pipes from those expressions.
-fn scan_control_flow_expressions(text: &str, pipes: &mut HashSet<String>) {
+/// `@switch (mode | uiMap)`, plus `@let name = expr;` declarations. Extracts
+/// pipes, reads and declared locals.
+fn scan_control_flow_expressions(text: &str, scan: &mut TemplateScan) {
     let bytes = text.as_bytes();
     let mut i = 0;
     while i < bytes.len() {
@@ -215,10 +325,30 @@ fn scan_control_flow_expressions(text: &str, pipes: &mut HashSet<String>) {
             i += 1;
             continue;
         }
-        // Optional whitespace, then a parenthesized expression.
+        let mut keyword = &text[i + 1..j];
         while j < bytes.len() && bytes[j].is_ascii_whitespace() {
             j += 1;
         }
+        if keyword == "else" && text[j..].starts_with("if") {
+            keyword = "if";
+            j += 2;
+            while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+                j += 1;
+            }
+        }
+        if keyword == "let" {
+            let end = text[j..]
+                .find(';')
+                .map(|off| j + off)
+                .unwrap_or(bytes.len());
+            if let Some((name, expr)) = text[j..end].split_once('=') {
+                scan.locals.insert(name.trim().to_string());
+                scan_expression(expr, scan);
+            }
+            i = end;
+            continue;
+        }
+        // Optional whitespace, then a parenthesized expression.
         if j >= bytes.len() || bytes[j] != b'(' {
             i = j;
             continue;
@@ -235,20 +365,32 @@ fn scan_control_flow_expressions(text: &str, pipes: &mut HashSet<String>) {
             j += 1;
         }
         let expr_end = if depth == 0 { j - 1 } else { j };
-        extract_pipes(&text[expr_start..expr_end], pipes);
+        let params = &text[expr_start..expr_end];
+        extract_pipes(params, &mut scan.pipes);
+        let parsed = expression::parse_block_params(keyword, params);
+        scan.locals
+            .extend(parsed.locals.iter().map(|name| name.to_string()));
+        for expr in parsed.expressions {
+            expression::collect_reads(expr, &mut scan.reads);
+            expression::collect_translation_keys(
+                expr,
+                &scan.translate_functions,
+                &mut scan.translation_keys,
+            );
+        }
         i = j;
     }
 }
 
-/// Finds `{{ expr }}` interpolations in text and extracts pipes from them.
-fn scan_interpolations(text: &str, pipes: &mut HashSet<String>) {
+/// Finds `{{ expr }}` interpolations in text and scans them.
+fn scan_interpolations(text: &str, scan: &mut TemplateScan) {
     let mut rest = text;
     while let Some(start) = rest.find("{{") {
         let after = &rest[start + 2..];
         let Some(end) = after.find("}}") else {
             break;
         };
-        extract_pipes(&after[..end], pipes);
+        scan_expression(&after[..end], scan);
         rest = &after[end + 2..];
     }
 }
@@ -332,9 +474,41 @@ mod tests {
         assert!(!tags.contains(&"fake-tag"));
     }
 
+    #[test]
+    fn records_input_and_output_bindings() {
+        let scan = scan_template(
+            r#"<ui-card title="x" [elevation]="2" (closed)="close()" [(open)]="isOpen"></ui-card>"#,
+        );
+        let card = &scan.elements[0];
+        assert!(card.inputs.contains("title"));
+        assert!(card.inputs.contains("elevation"));
+        assert!(card.inputs.contains("open"));
+        assert!(!card.inputs.contains("closed"));
+        assert!(card.outputs.contains("closed"));
+        assert!(card.outputs.contains("openChange"));
+    }
+
     #[test]
     fn structural_directive_value_pipes_are_found() {
         let scan = scan_template(r#"<li *ngFor="let u of users | uiSort"></li>"#);
         assert!(scan.pipes.contains("uiSort"));
     }
+
+    #[test]
+    fn collects_instance_reads_without_template_locals() {
+        let scan = scan_template(
+            r#"<input #query (keyup)="search(query.value)" [disabled]="busy">
+            <li *ngFor="let row of rows; index as i">{{ i }} {{ row.label | uiLabel }}</li>
+            <ng-template let-ctx><b>{{ ctx }}</b></ng-template>
+            @if (user(); as u) { {{ u.name }} } @else if (guest) { }
+            @for (item of items; track trackBy(item)) { {{ item }} }
+            @let total = price * qty; {{ total }}"#,
+        );
+        let reads: Vec<&str> = scan.instance_reads().into_iter().collect();
+        assert_eq!(
+            reads,
+            vec!["busy", "guest", "items", "price", "qty", "rows", "search", "trackBy", "user"]
+        );
+        assert!(scan.pipes.contains("uiLabel"));
+    }
 }
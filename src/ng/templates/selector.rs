@@ -8,6 +8,24 @@ pub struct SimpleSelector {
     pub classes: Vec<String>,
 }
 
+impl SimpleSelector {
+    /// Every element `other` matches is also matched by `self` — `[fixBtn]`
+    /// subsumes `button[fixBtn]`, and identical selectors subsume each other.
+    pub fn subsumes(&self, other: &SimpleSelector) -> bool {
+        self.element
+            .as_ref()
+            .is_none_or(|tag| other.element.as_ref() == Some(tag))
+            && self
+                .attributes
+                .iter()
+                .all(|attr| other.attributes.contains(attr))
+            && self
+                .classes
+                .iter()
+                .all(|class| other.classes.contains(class))
+    }
+}
+
 /// Parses an Angular selector list (`fix-btn, button[fixBtn]:not([link])`).
 /// `:not(...)` parts are ignored (conservative: may over-match, never
 /// under-matches — safer for unused-code detection).
@@ -127,6 +145,8 @@ mod tests {
             tag: tag.to_string(),
             attributes: attrs.iter().map(|s| s.to_string()).collect(),
             classes: HashSet::new(),
+            inputs: HashSet::new(),
+            outputs: HashSet::new(),
         }
     }
 
@@ -167,6 +187,16 @@ mod tests {
         assert!(matches(&sel, &element("input", &[])));
     }
 
+    #[test]
+    fn subsumption_is_directional() {
+        let general = &parse_selector("[fixBtn]")[0];
+        let specific = &parse_selector("button[fixBtn]")[0];
+        assert!(general.subsumes(specific));
+        assert!(!specific.subsumes(general));
+        assert!(specific.subsumes(specific));
+        assert!(!general.subsumes(&parse_selector("fix-button")[0]));
+    }
+
     #[test]
     fn attr_with_value_matches_on_presence() {
         let sel = parse_selector("[fixVariant=primary]");
@@ -0,0 +1,290 @@
+use std::collections::HashSet;
+use std::ops::Range;
+use std::path::Path;
+
+/// A Vue single-file component, split into what the analysis reads.
+#[derive(Debug, Default)]
+pub struct Sfc {
+    /// The `<script>` and `<script setup>` contents at their byte offsets in
+    /// the `.vue` file, everything else blanked to spaces (newlines kept):
+    /// swc spans and line numbers point into the SFC itself.
+    pub script: String,
+    /// A script block declares `lang="tsx"` or `lang="jsx"`.
+    pub jsx: bool,
+    /// Has a `<script setup>` block — no `export default` in the source.
+    pub setup: bool,
+    /// Components the template renders, in PascalCase: `<UserCard>` and
+    /// `<user-card>` are both `UserCard`.
+    pub components: Vec<String>,
+    /// Identifiers read by template expressions — interpolations and the
+    /// values of `:prop`, `@event`, `v-*` and `#slot` attributes.
+    pub template_reads: HashSet<String>,
+}
+
+struct Block<'s> {
+    name: &'s str,
+    attrs: &'s str,
+    content: Range<usize>,
+}
+
+pub fn is_vue(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "vue")
+}
+
+/// `user-card.vue` / `UserCard.vue` → `UserCard`, the name the SFC is
+/// imported and rendered under.
+pub fn component_name(path: &Path) -> String {
+    pascal_case(
+        &path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy())
+            .unwrap_or_default(),
+    )
+}
+
+impl Sfc {
+    pub fn parse(source: &str) -> Self {
+        let blocks = top_level_blocks(source);
+
+        let mut script: Vec<u8> = source
+            .bytes()
+            .map(|byte| if byte == b'\n' { b'\n' } else { b' ' })
+            .collect();
+        let mut sfc = Sfc::default();
+        for block in blocks.iter().filter(|block| block.name == "script") {
+            // Block bounds sit on ASCII `>` / `<`, so the copy keeps UTF-8
+            // intact.
+            script[block.content.clone()]
+                .copy_from_slice(&source.as_bytes()[block.content.clone()]);
+            sfc.jsx |= matches!(attribute(block.attrs, "lang"), Some("tsx" | "jsx"));
+            sfc.setup |= attribute(block.attrs, "setup").is_some();
+        }
+        sfc.script = String::from_utf8(script).unwrap_or_default();
+
+        if let Some(template) = blocks.iter().find(|block| block.name == "template") {
+            let template = &source[template.content.clone()];
+            let mut components: Vec<String> = super::scanner::scan_template(template)
+                .elements
+                .iter()
+                .filter(|element| is_component_tag(&element.tag))
+                .map(|element| pascal_case(&element.tag))
+                .collect();
+            components.sort();
+            components.dedup();
+            sfc.components = components;
+            sfc.template_reads = template_reads(template);
+        }
+        sfc
+    }
+}
+
+/// `<template>`, `<script>`, `<style>` and custom blocks. A `<template>`
+/// nests further `<template>`s (slots, `v-if` groups); other blocks end at
+/// their first closing tag.
+fn top_level_blocks(source: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while let Some(offset) = source[i..].find('<') {
+        let start = i + offset;
+        if source[start..].starts_with("<!--") {
+            i = source[start..]
+                .find("-->")
+                .map_or(source.len(), |end| start + end + 3);
+            continue;
+        }
+        let name_len = source[start + 1..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(source.len() - start - 1);
+        let name = &source[start + 1..start + 1 + name_len];
+        let Some(open_end) = source[start..].find('>').map(|end| start + end) else {
+            break;
+        };
+        if name.is_empty() || source[..open_end].ends_with('/') {
+            i = open_end + 1;
+            continue;
+        }
+        let attrs = &source[start + 1 + name_len..open_end];
+        let content_start = open_end + 1;
+        let Some(content_end) = closing_tag(source, content_start, name) else {
+            break;
+        };
+        blocks.push(Block {
+            name,
+            attrs,
+            content: content_start..content_end,
+        });
+        i = source[content_end..]
+            .find('>')
+            .map_or(source.len(), |end| content_end + end + 1);
+    }
+    blocks
+}
+
+/// Offset of the `</name` closing the block whose content starts at `from`.
+fn closing_tag(source: &str, from: usize, name: &str) -> Option<usize> {
+    let open = format!("<{name}");
+    let close = format!("</{name}");
+    let mut depth = 0;
+    let mut i = from;
+    loop {
+        let next_close = source[i..].find(&close)? + i;
+        // Only templates nest; a `<script>` body may well contain "<script".
+        let next_open = (name == "template")
+            .then(|| source[i..next_close].find(&open).map(|offset| i + offset))
+            .flatten()
+            .filter(|&at| {
+                source[at + open.len()..].starts_with(|c: char| c.is_ascii_whitespace() || c == '>')
+            });
+        match next_open {
+            Some(at) => {
+                depth += 1;
+                i = at + open.len();
+            }
+            None if depth == 0 => return Some(next_close),
+            None => {
+                depth -= 1;
+                i = next_close + close.len();
+            }
+        }
+    }
+}
+
+/// The value of `name` in a block's attributes; `Some("")` for a bare
+/// attribute like `setup`.
+fn attribute<'s>(attrs: &'s str, name: &str) -> Option<&'s str> {
+    attrs.split_ascii_whitespace().find_map(|attr| {
+        let (key, value) = attr.split_once('=').unwrap_or((attr, ""));
+        (key == name).then(|| value.trim_matches(|c| c == '"' || c == '\''))
+    })
+}
+
+/// PascalCase tags and kebab-case ones — HTML elements have neither.
+fn is_component_tag(tag: &str) -> bool {
+    tag.starts_with(|c: char| c.is_ascii_uppercase()) || tag.contains('-')
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(['-', '_', '.'])
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+fn template_reads(template: &str) -> HashSet<String> {
+    let mut expressions = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let inner = &rest[start + 2..];
+        let Some(end) = inner.find("}}") else {
+            break;
+        };
+        expressions.push(&inner[..end]);
+        rest = &inner[end + 2..];
+    }
+    for (eq, _) in template.match_indices('=') {
+        let name_start = template[..eq]
+            .rfind(|c: char| c.is_ascii_whitespace())
+            .map_or(0, |ws| ws + 1);
+        let name = &template[name_start..eq];
+        if !(name.starts_with([':', '@', '#']) || name.starts_with("v-")) {
+            continue;
+        }
+        let Some(quote) = template[eq + 1..]
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')
+        else {
+            continue;
+        };
+        let value = &template[eq + 2..];
+        expressions.push(value.find(quote).map_or(value, |end| &value[..end]));
+    }
+
+    let mut reads = HashSet::new();
+    for expression in expressions {
+        let mut previous = ' ';
+        let mut chars = expression.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if c.is_ascii_alphabetic() || c == '_' || c == '$' {
+                let mut end = start + c.len_utf8();
+                while let Some(&(at, next)) = chars.peek() {
+                    if !(next.is_ascii_alphanumeric() || next == '_' || next == '$') {
+                        break;
+                    }
+                    end = at + next.len_utf8();
+                    chars.next();
+                }
+                // `user.name` reads `user`, not `name`.
+                if previous != '.' {
+                    reads.insert(expression[start..end].to_string());
+                }
+                previous = 'a';
+            } else if !c.is_whitespace() {
+                previous = c;
+            }
+        }
+    }
+    reads
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SFC: &str = r#"<template>
+  <div>
+    <UserCard :user="currentUser" @select="onSelect" />
+    <template v-if="open"><base-button>{{ label(user.name) }}</base-button></template>
+  </div>
+</template>
+
+<script setup lang="ts">
+import UserCard from './UserCard.vue';
+const café = 'é';
+</script>
+
+<style scoped>
+div { color: red; }
+</style>
+"#;
+
+    #[test]
+    fn script_keeps_offsets_and_blanks_other_blocks() {
+        let sfc = Sfc::parse(SFC);
+        assert_eq!(sfc.script.len(), SFC.len());
+        assert_eq!(sfc.script.lines().count(), SFC.lines().count());
+        let import = SFC.find("import UserCard").unwrap();
+        assert_eq!(&sfc.script[import..import + 15], "import UserCard");
+        assert!(!sfc.script.contains("<template>"));
+        assert!(!sfc.script.contains("color: red"));
+        assert!(sfc.setup);
+        assert!(!sfc.jsx);
+    }
+
+    #[test]
+    fn template_components_are_pascal_cased() {
+        let sfc = Sfc::parse(SFC);
+        assert_eq!(sfc.components, vec!["BaseButton", "UserCard"]);
+    }
+
+    #[test]
+    fn template_reads_skip_member_names() {
+        let sfc = Sfc::parse(SFC);
+        for read in ["currentUser", "onSelect", "open", "label", "user"] {
+            assert!(sfc.template_reads.contains(read), "{read}");
+        }
+        assert!(!sfc.template_reads.contains("name"));
+    }
+
+    #[test]
+    fn component_name_from_file_stem() {
+        assert_eq!(component_name(Path::new("src/user-card.vue")), "UserCard");
+        assert_eq!(component_name(Path::new("src/App.vue")), "App");
+    }
+}
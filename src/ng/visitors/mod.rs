@@ -6,6 +6,7 @@ use crate::ng::visitors::visitor::AngularVisitor;
 
 use crate::analysis::resolvers::import_resolver::ImportResolver;
 use crate::file_cache_reader::CachedFileReader;
+use crate::ng::templates::sfc::{self, Sfc};
 use std::path::Path;
 use std::sync::Arc;
 use swc_common::input::StringInput;
@@ -17,10 +18,14 @@ mod visitor;
 
 /// JSX syntax for .tsx/.jsx files, plain TS otherwise.
 pub fn syntax_for(path: &Path) -> Syntax {
-    let tsx = path
-        .extension()
-        .map(|ext| ext == "tsx" || ext == "jsx")
-        .unwrap_or(false);
+    syntax(
+        path.extension()
+            .map(|ext| ext == "tsx" || ext == "jsx")
+            .unwrap_or(false),
+    )
+}
+
+pub fn syntax(tsx: bool) -> Syntax {
     Syntax::Typescript(TsSyntax {
         tsx,
         decorators: true,
@@ -36,17 +41,26 @@ pub fn analyze_file(
     project_name: String,
     tsconfig: TSConfig,
     default_standalone: bool,
+    component_openers: &[String],
     import_resolver: &mut ImportResolver,
     file_reader: &CachedFileReader,
 ) -> Result<NgAnalysisResults, Box<dyn std::error::Error>> {
-    let source = file_reader.read_file(path)?;
+    let mut source = file_reader.read_file(path)?;
+    let mut syntax = syntax_for(path);
+    let mut vue = None;
+    if sfc::is_vue(path) {
+        let mut parsed = Sfc::parse(&source);
+        source = std::mem::take(&mut parsed.script);
+        syntax = self::syntax(parsed.jsx);
+        vue = Some(parsed);
+    }
     let source_file = source_map.new_source_file(
         Arc::from(swc_common::FileName::Real(path.to_path_buf())),
         source,
     );
 
     let lexer = Lexer::new(
-        syntax_for(path),
+        syntax,
         Default::default(),
         StringInput::from(&*source_file),
         None,
@@ -61,8 +75,10 @@ pub fn analyze_file(
                 project_name,
                 tsconfig.clone(),
                 default_standalone,
+                component_openers,
                 import_resolver,
-            );
+            )
+            .with_sfc(vue);
             module.visit_with(&mut visitor);
 
             Ok(visitor.results)
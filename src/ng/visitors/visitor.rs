@@ -1,22 +1,48 @@
-use crate::analysis::models::file_facts::{ExportInfo, ExportKind, FileFactsInfo, LocalReference};
-use crate::analysis::models::import::{ImportKind, ImportedItem, ResolvedImport, UnresolvedImport};
-use crate::analysis::models::react::{JsxUsageInfo, ReactComponentInfo};
+use crate::analysis::models::file_facts::{
+    DynamicRender, ExportInfo, ExportKind, FileFactsInfo, InjectionSite, LocalReference,
+    ModuleDirective, TranslationKey,
+};
+use crate::analysis::models::import::{
+    ImportKind, ImportedItem, ResolvedImport, UnresolvedImport, UnresolvedScope,
+};
+use crate::analysis::models::react::{
+    CompoundComponent, ContextRead, HookCall, JsxUsageInfo, ReactComponentInfo, ReactContextInfo,
+    ReactHookInfo,
+};
+use crate::analysis::models::style::CssModuleUsage;
 use crate::analysis::models::ts_config::TSConfig;
+use crate::analysis::models::vue::VueComponentInfo;
+use crate::analysis::models::web_component::{CustomElementInfo, ElementTagUsage, ElementTagVia};
 use crate::analysis::resolvers::import_resolver::ImportResolver;
 use crate::analysis::resolvers::resolver::classify_unresolved;
+use crate::analysis::resolvers::style_resolver::is_css_module;
 use crate::ng::analyzers::component_analyzer::NgComponentAnalyzer;
 use crate::ng::analyzers::decorator_analyzer::DecoratorAnalyzer;
 use crate::ng::analyzers::directive_analyzer::NgDirectiveAnalyzer;
+use crate::ng::analyzers::member_analyzer::NgMemberAnalyzer;
 use crate::ng::analyzers::module_analyzer::NgModuleAnalyzer;
+use crate::ng::analyzers::nest_analyzer::NestAnalyzer;
+use crate::ng::analyzers::ngrx_analyzer::NgRxAnalyzer;
 use crate::ng::analyzers::pipe_analyzer::NgPipeAnalyzer;
+use crate::ng::analyzers::react_props_analyzer::ReactPropsAnalyzer;
+use crate::ng::analyzers::route_analyzer::NgRouteAnalyzer;
 use crate::ng::analyzers::service_analyzer::NgServiceAnalyzer;
+use crate::ng::analyzers::translation_analyzer::NgTranslationAnalyzer;
+use crate::ng::analyzers::url_import_analyzer::UrlImportAnalyzer;
+use crate::ng::analyzers::web_component_analyzer::{self, WebComponentAnalyzer};
+use crate::ng::models::ng_route::NgRouteInfo;
+use crate::ng::models::ngrx::{NgRxFacts, NgRxHandler, NgRxKind};
 use crate::ng::models::NgAnalysisResults;
-use std::collections::HashSet;
-use std::path::Path;
+use crate::ng::templates::sfc::{self, Sfc};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use swc_ecma_ast::{
-    CallExpr, Callee, Class, ClassDecl, ClassMember, Decl, DefaultDecl, ExportDefaultDecl, Expr,
-    ImportDecl, JSXAttrName, JSXAttrOrSpread, JSXElementName, JSXOpeningElement, Lit, MemberProp,
-    Module, ModuleDecl, ModuleExportName, ModuleItem, PropName, Stmt,
+    ArrayLit, ArrowExpr, AssignTarget, BlockStmtOrExpr, CallExpr, Callee, Class, ClassDecl,
+    ClassMember, ClassProp, Decl, DefaultDecl, ExportDefaultDecl, Expr, Function, ImportDecl,
+    JSXAttrName, JSXAttrOrSpread, JSXElement, JSXElementChild, JSXElementName, JSXMemberExpr,
+    JSXObject, Lit, MemberExpr, MemberProp, Module, ModuleDecl, ModuleExportName, ModuleItem,
+    NewExpr, ObjectLit, ParamOrTsParamProp, Pat, Prop, PropName, SimpleAssignTarget, Stmt,
+    TaggedTpl, TsEntityName, TsParamPropParam, TsType, TsTypeRef, VarDeclarator,
 };
 use swc_ecma_visit::{Visit, VisitWith};
 
@@ -26,12 +52,53 @@ pub struct AngularVisitor<'a> {
     pub results: NgAnalysisResults,
     imports: Vec<ResolvedImport>,
     dynamic_imports: Vec<ResolvedImport>,
+    url_imports: Vec<ResolvedImport>,
     unresolved_imports: Vec<UnresolvedImport>,
+    package_imports: Vec<String>,
     exports: Vec<ExportInfo>,
     local_references: Vec<LocalReference>,
     used_idents: HashSet<String>,
     jsx_usages: Vec<JsxUsageInfo>,
+    compound_components: Vec<CompoundComponent>,
+    /// Tags of the component elements being visited, outermost first.
+    jsx_parents: Vec<String>,
+    context_reads: Vec<ContextRead>,
+    hook_calls: Vec<HookCall>,
+    /// Top-level declaration being visited — the caller of a hook call.
+    current_top_level: Option<String>,
+    bootstrap_calls: Vec<String>,
+    routes: Vec<NgRouteInfo>,
+    injections: Vec<InjectionSite>,
+    dynamic_renders: Vec<DynamicRender>,
+    translation_keys: Vec<TranslationKey>,
+    ngrx: NgRxFacts,
+    directive: Option<ModuleDirective>,
+    server_actions: Vec<String>,
+    element_tags: Vec<ElementTagUsage>,
+    /// Local names of CSS module imports (`import styles from
+    /// './x.module.css'`) → the module.
+    css_module_bindings: HashMap<String, PathBuf>,
+    css_modules: BTreeMap<PathBuf, CssModuleUsage>,
+    /// NgRx declaration being visited — owner of `on(...)` / `ofType(...)`;
+    /// calls inside an effect emit actions.
+    ngrx_owner: Option<(String, NgRxKind)>,
+    /// Inside the arguments of `dispatch(...)`.
+    dispatching: bool,
+    /// Inside `new Worker(...)` or a service worker registration — a
+    /// `new URL(...)` here loads a worker, not an asset.
+    starting_worker: bool,
+    /// Names declared at the top level, for telling workspace calls from
+    /// library operators.
+    top_level_names: HashSet<String>,
+    component_openers: &'a [String],
+    /// Class whose body is being visited — owner of `inject(X)` calls.
+    current_class: Option<String>,
+    /// Inside a route table already recorded — its `children` arrays are
+    /// part of it, not tables of their own.
+    in_route_table: bool,
     is_jsx_file: bool,
+    /// The `.vue` file's template side; its script is what is visited.
+    sfc: Option<Sfc>,
     package_name: String,
     ts_config: TSConfig,
     default_standalone: bool,
@@ -45,6 +112,7 @@ impl<'a> AngularVisitor<'a> {
         package_name: String,
         tsconfig: TSConfig,
         default_standalone: bool,
+        component_openers: &'a [String],
         import_resolver: &'a mut ImportResolver,
     ) -> Self {
         let is_jsx_file = file_path
@@ -58,12 +126,38 @@ impl<'a> AngularVisitor<'a> {
             results: NgAnalysisResults::default(),
             imports: Vec::new(),
             dynamic_imports: Vec::new(),
+            url_imports: Vec::new(),
             unresolved_imports: Vec::new(),
+            package_imports: Vec::new(),
             exports: Vec::new(),
             local_references: Vec::new(),
             used_idents: HashSet::new(),
             jsx_usages: Vec::new(),
+            compound_components: Vec::new(),
+            jsx_parents: Vec::new(),
+            context_reads: Vec::new(),
+            hook_calls: Vec::new(),
+            current_top_level: None,
+            bootstrap_calls: Vec::new(),
+            routes: Vec::new(),
+            injections: Vec::new(),
+            dynamic_renders: Vec::new(),
+            translation_keys: Vec::new(),
+            ngrx: NgRxFacts::default(),
+            directive: None,
+            server_actions: Vec::new(),
+            element_tags: Vec::new(),
+            css_module_bindings: HashMap::new(),
+            css_modules: BTreeMap::new(),
+            ngrx_owner: None,
+            dispatching: false,
+            starting_worker: false,
+            top_level_names: HashSet::new(),
+            component_openers,
+            current_class: None,
+            in_route_table: false,
             is_jsx_file,
+            sfc: None,
             package_name,
             ts_config: tsconfig,
             default_standalone,
@@ -71,11 +165,66 @@ impl<'a> AngularVisitor<'a> {
         }
     }
 
+    pub fn with_sfc(mut self, sfc: Option<Sfc>) -> Self {
+        self.sfc = sfc;
+        self
+    }
+
     fn process_decorated_class(&mut self, class: &Class, class_name: &str) {
+        for token in Self::constructor_injections(class) {
+            self.injections.push(InjectionSite {
+                class: Some(class_name.to_string()),
+                token,
+            });
+        }
         let (inputs, outputs) = Self::extract_inputs_outputs(class);
+        let first_new_component = self.results.components.len();
         for decorator in &class.decorators {
             self.process_decorator(decorator, class_name, inputs.clone(), outputs.clone());
         }
+        if let Some(module) = NestAnalyzer::module(
+            class,
+            &self.file_path,
+            &self.project_root,
+            class_name,
+            &self.package_name,
+        ) {
+            self.results.nest_modules.push(module);
+        }
+        if let Some(controller) = NestAnalyzer::controller(
+            class,
+            &self.file_path,
+            &self.project_root,
+            class_name,
+            &self.package_name,
+        ) {
+            self.results.nest_controllers.push(controller);
+        }
+        if let Some(tag) = WebComponentAnalyzer::decorator_tag(class) {
+            self.record_custom_element(tag, class_name.to_string());
+        }
+
+        if self.results.components.len() > first_new_component {
+            let (members, this_refs) = NgMemberAnalyzer::analyze(class);
+            let extends = class
+                .super_class
+                .as_deref()
+                .map(|super_class| match super_class {
+                    Expr::Ident(ident) => ident.sym.to_string(),
+                    Expr::Member(member) => match &member.prop {
+                        MemberProp::Ident(prop) => prop.sym.to_string(),
+                        _ => String::new(),
+                    },
+                    _ => String::new(),
+                });
+            for component in &mut self.results.components[first_new_component..] {
+                component.members = members.clone();
+                component.member_refs.extend(this_refs.iter().cloned());
+                component.member_refs.sort();
+                component.member_refs.dedup();
+                component.extends = extends.clone();
+            }
+        }
     }
 
     fn process_decorator(
@@ -160,6 +309,89 @@ impl<'a> AngularVisitor<'a> {
         }
     }
 
+    fn record_custom_element(&mut self, tag: String, name: String) {
+        let known = self
+            .results
+            .custom_elements
+            .iter()
+            .any(|element| element.tag == tag && element.name == name);
+        if !known {
+            self.results.custom_elements.push(CustomElementInfo {
+                name,
+                tag,
+                source_path: self.file_path.clone(),
+                package_name: self.package_name.clone(),
+            });
+        }
+    }
+
+    /// Records `opener(X, ...)` when `opener` is a configured component
+    /// opener and `X` a capitalized identifier — a class, not a value.
+    fn record_dynamic_render(&mut self, opener: String, first_arg: Option<&Expr>) {
+        let Some(Expr::Ident(component)) = first_arg else {
+            return;
+        };
+        let is_class = component.sym.starts_with(|c: char| c.is_ascii_uppercase());
+        if is_class && self.component_openers.contains(&opener) {
+            self.dynamic_renders.push(DynamicRender {
+                class: self.current_class.clone(),
+                component: component.sym.to_string(),
+                opener,
+            });
+        }
+    }
+
+    /// Constructor DI: `@Inject(TOKEN)` wins over the parameter type
+    /// (`constructor(private api: ApiService)`).
+    fn constructor_injections(class: &Class) -> Vec<String> {
+        let Some(constructor) = class.body.iter().find_map(|member| match member {
+            ClassMember::Constructor(constructor) => Some(constructor),
+            _ => None,
+        }) else {
+            return Vec::new();
+        };
+
+        constructor
+            .params
+            .iter()
+            .filter_map(|param| {
+                let (decorators, binding) = match param {
+                    ParamOrTsParamProp::Param(param) => (&param.decorators, param.pat.as_ident()),
+                    ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+                        TsParamPropParam::Ident(binding) => (&prop.decorators, Some(binding)),
+                        TsParamPropParam::Assign(assign) => {
+                            (&prop.decorators, assign.left.as_ident())
+                        }
+                    },
+                };
+                let injected = decorators.iter().find_map(|decorator| {
+                    let Expr::Call(call) = &*decorator.expr else {
+                        return None;
+                    };
+                    let Callee::Expr(callee) = &call.callee else {
+                        return None;
+                    };
+                    match (&**callee, call.args.first().map(|arg| &*arg.expr)) {
+                        (Expr::Ident(name), Some(Expr::Ident(token))) if name.sym == *"Inject" => {
+                            Some(token.sym.to_string())
+                        }
+                        _ => None,
+                    }
+                });
+                injected.or_else(|| {
+                    let type_ann = binding?.type_ann.as_ref()?;
+                    match &*type_ann.type_ann {
+                        TsType::TsTypeRef(TsTypeRef {
+                            type_name: TsEntityName::Ident(ident),
+                            ..
+                        }) => Some(ident.sym.to_string()),
+                        _ => None,
+                    }
+                })
+            })
+            .collect()
+    }
+
     /// `@Input()`/`@Output()` decorated properties and signal-based
     /// `input()`/`output()`/`model()` (including `input.required()`).
     fn extract_inputs_outputs(class: &Class) -> (Vec<String>, Vec<String>) {
@@ -236,6 +468,22 @@ impl<'a> AngularVisitor<'a> {
         resolved
     }
 
+    /// A file loaded by URL: a lazy edge of its own kind in the graph.
+    fn record_url_import(&mut self, specifier: &str, kind: ImportKind) {
+        let Some(mut resolved) = self.resolve_or_record(specifier, "*") else {
+            return;
+        };
+        self.import_resolver
+            .import_graph()
+            .add_url_dependency(self.file_path.clone(), resolved.resolved_path.clone());
+        resolved.imported_item = ImportedItem {
+            name: "*".to_string(),
+            alias: None,
+            import_kind: kind,
+        };
+        self.url_imports.push(resolved);
+    }
+
     fn record_unresolved(&mut self, src: &str) {
         let empty = std::collections::HashMap::new();
         let ts_paths = self
@@ -246,15 +494,17 @@ impl<'a> AngularVisitor<'a> {
             .unwrap_or(&empty);
         let scope = classify_unresolved(src, ts_paths);
 
-        if !self
+        match self
             .unresolved_imports
-            .iter()
-            .any(|unresolved| unresolved.specifier == src)
+            .iter_mut()
+            .find(|unresolved| unresolved.specifier == src)
         {
-            self.unresolved_imports.push(UnresolvedImport {
+            Some(unresolved) => unresolved.type_only = false,
+            None => self.unresolved_imports.push(UnresolvedImport {
                 specifier: src.to_string(),
                 scope,
-            });
+                type_only: false,
+            }),
         }
     }
 
@@ -275,12 +525,36 @@ impl<'a> AngularVisitor<'a> {
             return;
         }
 
+        let known = self
+            .unresolved_imports
+            .iter()
+            .any(|unresolved| unresolved.specifier == src);
         for specifier in &import_decl.specifiers {
-            self.process_import_specifier(specifier, &src);
+            self.process_import_specifier(specifier, &src, import_decl.type_only);
+        }
+        let type_only = import_decl.type_only
+            || import_decl.specifiers.iter().all(|specifier| {
+                matches!(specifier, swc_ecma_ast::ImportSpecifier::Named(named) if named.is_type_only)
+            });
+        if type_only && !known {
+            if let Some(unresolved) = self
+                .unresolved_imports
+                .iter_mut()
+                .find(|unresolved| unresolved.specifier == src)
+            {
+                unresolved.type_only = true;
+            }
         }
     }
 
-    fn process_import_specifier(&mut self, specifier: &swc_ecma_ast::ImportSpecifier, src: &str) {
+    fn process_import_specifier(
+        &mut self,
+        specifier: &swc_ecma_ast::ImportSpecifier,
+        src: &str,
+        type_only: bool,
+    ) {
+        let type_only = type_only
+            || matches!(specifier, swc_ecma_ast::ImportSpecifier::Named(named) if named.is_type_only);
         let imported_item = match specifier {
             swc_ecma_ast::ImportSpecifier::Named(named) => ImportedItem {
                 name: named.local.sym.to_string(),
@@ -310,9 +584,48 @@ impl<'a> AngularVisitor<'a> {
             .unwrap_or(&imported_item.name)
             .to_string();
 
-        if let Some(mut resolved_import) = self.resolve_or_record(src, &exported_name) {
-            resolved_import.imported_item = imported_item;
-            self.imports.push(resolved_import);
+        let Some(mut resolved_import) = self.resolve_or_record(src, &exported_name) else {
+            if self.unresolved_imports.iter().any(|unresolved| {
+                unresolved.specifier == src && unresolved.scope == UnresolvedScope::External
+            }) {
+                self.package_imports.push(imported_item.name);
+            }
+            return;
+        };
+        if resolved_import
+            .resolved_path
+            .components()
+            .any(|component| component.as_os_str() == "node_modules")
+        {
+            self.package_imports.push(imported_item.name.clone());
+        }
+        if is_css_module(&resolved_import.resolved_path) {
+            self.record_css_module_import(&resolved_import.resolved_path, &imported_item);
+        }
+        resolved_import.imported_item = imported_item;
+        resolved_import.type_only = type_only;
+        self.imports.push(resolved_import);
+    }
+
+    /// A default or namespace import binds the class map; a named import
+    /// (`namedExports` mode) reads one class.
+    fn record_css_module_import(&mut self, path: &Path, item: &ImportedItem) {
+        let usage = self
+            .css_modules
+            .entry(path.to_path_buf())
+            .or_insert_with(|| CssModuleUsage {
+                path: path.to_path_buf(),
+                classes: Vec::new(),
+                dynamic: false,
+            });
+        match item.import_kind {
+            ImportKind::Named => usage
+                .classes
+                .push(item.alias.clone().unwrap_or_else(|| item.name.clone())),
+            _ => {
+                self.css_module_bindings
+                    .insert(item.name.clone(), path.to_path_buf());
+            }
         }
     }
 
@@ -370,9 +683,14 @@ impl<'a> AngularVisitor<'a> {
                                 match &named.src {
                                     Some(src) => {
                                         let source = src.value.to_string();
-                                        self.push_reexport(exported, ExportKind::ReExport, &source);
                                         let original = Self::export_name_to_string(&spec.orig);
-                                        self.resolve_or_record(&source, &original);
+                                        let resolved = self.resolve_or_record(&source, &original);
+                                        self.push_reexport(
+                                            exported,
+                                            ExportKind::ReExport,
+                                            &source,
+                                            resolved,
+                                        );
                                     }
                                     // `class X {}; export { X }` — declared here.
                                     None => self.push_export(exported, ExportKind::ReExport),
@@ -383,8 +701,13 @@ impl<'a> AngularVisitor<'a> {
                                 match &named.src {
                                     Some(src) => {
                                         let source = src.value.to_string();
-                                        self.push_reexport(exported, ExportKind::ReExport, &source);
-                                        self.resolve_or_record(&source, "*");
+                                        let resolved = self.resolve_or_record(&source, "*");
+                                        self.push_reexport(
+                                            exported,
+                                            ExportKind::ReExport,
+                                            &source,
+                                            resolved,
+                                        );
                                     }
                                     None => self.push_export(exported, ExportKind::ReExport),
                                 }
@@ -397,12 +720,13 @@ impl<'a> AngularVisitor<'a> {
                 }
                 ModuleDecl::ExportAll(export_all) => {
                     let source = export_all.src.value.to_string();
+                    let resolved = self.resolve_or_record(&source, "*");
                     self.push_reexport(
                         format!("* from {}", source),
                         ExportKind::ReExportAll,
                         &source,
+                        resolved,
                     );
-                    self.resolve_or_record(&source, "*");
                 }
                 ModuleDecl::ExportDefaultDecl(default_decl) => {
                     let name = match &default_decl.decl {
@@ -432,17 +756,25 @@ impl<'a> AngularVisitor<'a> {
             name,
             kind,
             from_module: None,
+            resolved_path: None,
         });
     }
 
     /// `export { X } from './y'` / `export * from './y'` — carries the source,
     /// which is what separates a pass-through barrel from a file that declares
     /// its own symbols and merely exports them in a separate statement.
-    fn push_reexport(&mut self, name: String, kind: ExportKind, from_module: &str) {
+    fn push_reexport(
+        &mut self,
+        name: String,
+        kind: ExportKind,
+        from_module: &str,
+        resolved: Option<ResolvedImport>,
+    ) {
         self.exports.push(ExportInfo {
             name,
             kind,
             from_module: Some(from_module.to_string()),
+            resolved_path: resolved.map(|resolved| resolved.resolved_path),
         });
     }
 
@@ -452,13 +784,15 @@ impl<'a> AngularVisitor<'a> {
     fn collect_react_components(&mut self, module: &Module) {
         let is_capitalized =
             |name: &str| name.chars().next().is_some_and(|c| c.is_ascii_uppercase());
+        let analyzer = ReactPropsAnalyzer::new(module);
 
-        let push = |name: String, wrapped: bool, this: &mut Self| {
+        let push = |name: String, wrapped: bool, props, this: &mut Self| {
             this.results.react_components.push(ReactComponentInfo {
                 name,
                 source_path: this.file_path.clone(),
                 package_name: this.package_name.clone(),
                 wrapped,
+                props,
             });
         };
 
@@ -471,7 +805,9 @@ impl<'a> AngularVisitor<'a> {
                         if let Some(ident) = &fn_expr.ident {
                             let name = ident.sym.to_string();
                             if is_capitalized(&name) {
-                                push(name, false, self);
+                                let props =
+                                    analyzer.props(first_function_param(&fn_expr.function), None);
+                                push(name, false, props, self);
                             }
                         }
                     }
@@ -484,7 +820,8 @@ impl<'a> AngularVisitor<'a> {
                 Decl::Fn(fn_decl) => {
                     let name = fn_decl.ident.sym.to_string();
                     if is_capitalized(&name) {
-                        push(name, false, self);
+                        let props = analyzer.props(first_function_param(&fn_decl.function), None);
+                        push(name, false, props, self);
                     }
                 }
                 Decl::Var(var_decl) => {
@@ -497,8 +834,18 @@ impl<'a> AngularVisitor<'a> {
                             continue;
                         }
                         let Some(init) = &var.init else { continue };
+                        // `const Button: React.FC<ButtonProps> = …`
+                        let annotated = ident.type_ann.as_ref().map(|ann| &*ann.type_ann);
                         match &**init {
-                            Expr::Arrow(_) | Expr::Fn(_) => push(name, false, self),
+                            Expr::Arrow(arrow) => {
+                                let props = analyzer.props(arrow.params.first(), annotated);
+                                push(name, false, props, self);
+                            }
+                            Expr::Fn(fn_expr) => {
+                                let props = analyzer
+                                    .props(first_function_param(&fn_expr.function), annotated);
+                                push(name, false, props, self);
+                            }
                             Expr::Call(call) => {
                                 let wrapper = match &call.callee {
                                     Callee::Expr(callee) => match &**callee {
@@ -516,9 +863,27 @@ impl<'a> AngularVisitor<'a> {
                                     },
                                     _ => None,
                                 };
-                                if matches!(wrapper.as_deref(), Some("memo") | Some("forwardRef")) {
-                                    push(name, true, self);
+                                if !matches!(wrapper.as_deref(), Some("memo") | Some("forwardRef"))
+                                {
+                                    continue;
                                 }
+                                // `forwardRef<Ref, Props>(…)`
+                                let declared = call
+                                    .type_args
+                                    .as_ref()
+                                    .and_then(|args| args.params.get(1))
+                                    .map(|param| &**param)
+                                    .or(annotated);
+                                let param = call.args.first().and_then(|arg| match &*arg.expr {
+                                    Expr::Arrow(arrow) => arrow.params.first(),
+                                    Expr::Fn(fn_expr) => first_function_param(&fn_expr.function),
+                                    _ => None,
+                                });
+                                let props = match param {
+                                    Some(param) => analyzer.props(Some(param), declared),
+                                    None => None,
+                                };
+                                push(name, true, props, self);
                             }
                             _ => {}
                         }
@@ -529,6 +894,206 @@ impl<'a> AngularVisitor<'a> {
         }
     }
 
+    /// Exported top-level `useX` functions and arrow/function consts, in
+    /// any script file — hooks live in `.ts` files as often as in `.tsx`.
+    fn collect_react_hooks(&mut self, module: &Module) {
+        for item in &module.body {
+            let names: Vec<String> = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(default)) => {
+                    match &default.decl {
+                        DefaultDecl::Fn(fn_expr) => {
+                            fn_expr.ident.iter().map(|i| i.sym.to_string()).collect()
+                        }
+                        _ => Vec::new(),
+                    }
+                }
+                ModuleItem::Stmt(Stmt::Decl(decl))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(swc_ecma_ast::ExportDecl {
+                    decl,
+                    ..
+                })) => {
+                    let exported = |name: &str| {
+                        self.exports
+                            .iter()
+                            .any(|export| export.name == name && export.from_module.is_none())
+                    };
+                    match decl {
+                        Decl::Fn(fn_decl) => vec![fn_decl.ident.sym.to_string()],
+                        Decl::Var(var_decl) => var_decl
+                            .decls
+                            .iter()
+                            .filter(|var| {
+                                matches!(var.init.as_deref(), Some(Expr::Arrow(_) | Expr::Fn(_)))
+                            })
+                            .filter_map(|var| match &var.name {
+                                Pat::Ident(ident) => Some(ident.id.sym.to_string()),
+                                _ => None,
+                            })
+                            .collect(),
+                        _ => Vec::new(),
+                    }
+                    .into_iter()
+                    .filter(|name| exported(name))
+                    .collect()
+                }
+                _ => Vec::new(),
+            };
+            for name in names.into_iter().filter(|name| is_hook_name(name)) {
+                self.results.react_hooks.push(ReactHookInfo {
+                    name,
+                    source_path: self.file_path.clone(),
+                    package_name: self.package_name.clone(),
+                });
+            }
+        }
+    }
+
+    /// Top-level `const X = createContext(…)` / `React.createContext(…)`.
+    fn collect_react_contexts(&mut self, module: &Module) {
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+                _ => continue,
+            };
+            let Decl::Var(var_decl) = decl else { continue };
+            for var in &var_decl.decls {
+                let (Pat::Ident(ident), Some(Expr::Call(call))) = (&var.name, var.init.as_deref())
+                else {
+                    continue;
+                };
+                if react_function(call).as_deref() == Some("createContext") {
+                    self.results.react_contexts.push(ReactContextInfo {
+                        name: ident.id.sym.to_string(),
+                        source_path: self.file_path.clone(),
+                        package_name: self.package_name.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// `const Form = Object.assign(FormRoot, { Field })`, `const Form =
+    /// { Field: FormField }` and `FormRoot.Field = FormField` at the top
+    /// level. Only capitalized names holding capitalized names count.
+    fn collect_compound_components(&mut self, module: &Module) {
+        let is_capitalized =
+            |name: &str| name.chars().next().is_some_and(|c| c.is_ascii_uppercase());
+        let members = |object: &ObjectLit| -> BTreeMap<String, String> {
+            object
+                .props
+                .iter()
+                .filter_map(|prop| match prop.as_prop()?.as_ref() {
+                    Prop::KeyValue(kv) => match (&kv.key, &*kv.value) {
+                        (PropName::Ident(key), Expr::Ident(value)) => {
+                            Some((key.sym.to_string(), value.sym.to_string()))
+                        }
+                        _ => None,
+                    },
+                    Prop::Shorthand(ident) => Some((ident.sym.to_string(), ident.sym.to_string())),
+                    _ => None,
+                })
+                .filter(|(key, value)| is_capitalized(key) && is_capitalized(value))
+                .collect()
+        };
+
+        let mut compounds: Vec<CompoundComponent> = Vec::new();
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+                // `FormRoot.Field = FormField;`
+                ModuleItem::Stmt(Stmt::Expr(stmt)) => {
+                    let Expr::Assign(assign) = &*stmt.expr else {
+                        continue;
+                    };
+                    let (
+                        AssignTarget::Simple(SimpleAssignTarget::Member(member)),
+                        Expr::Ident(value),
+                    ) = (&assign.left, &*assign.right)
+                    else {
+                        continue;
+                    };
+                    let (Expr::Ident(object), MemberProp::Ident(prop)) =
+                        (&*member.obj, &member.prop)
+                    else {
+                        continue;
+                    };
+                    if !is_capitalized(&object.sym)
+                        || !is_capitalized(&prop.sym)
+                        || !is_capitalized(&value.sym)
+                    {
+                        continue;
+                    }
+                    let name = object.sym.to_string();
+                    let index = match compounds.iter().position(|c| c.name == name) {
+                        Some(index) => index,
+                        None => {
+                            compounds.push(CompoundComponent {
+                                name,
+                                root: None,
+                                members: BTreeMap::new(),
+                            });
+                            compounds.len() - 1
+                        }
+                    };
+                    compounds[index]
+                        .members
+                        .insert(prop.sym.to_string(), value.sym.to_string());
+                    continue;
+                }
+                _ => continue,
+            };
+            let Decl::Var(var_decl) = decl else { continue };
+            for var in &var_decl.decls {
+                let (Pat::Ident(ident), Some(init)) = (&var.name, var.init.as_deref()) else {
+                    continue;
+                };
+                let name = ident.id.sym.to_string();
+                if !is_capitalized(&name) {
+                    continue;
+                }
+                let compound = match init {
+                    Expr::Object(object) => CompoundComponent {
+                        name,
+                        root: None,
+                        members: members(object),
+                    },
+                    Expr::Call(call) => {
+                        let Some(callee) = call.callee.as_expr().and_then(|c| c.as_member()) else {
+                            continue;
+                        };
+                        let is_assign = matches!(&*callee.obj, Expr::Ident(obj) if obj.sym == *"Object")
+                            && matches!(&callee.prop, MemberProp::Ident(prop) if prop.sym == *"assign");
+                        let [root, rest @ ..] = &call.args[..] else {
+                            continue;
+                        };
+                        let (true, Expr::Ident(root)) = (is_assign, &*root.expr) else {
+                            continue;
+                        };
+                        CompoundComponent {
+                            name,
+                            root: Some(root.sym.to_string()),
+                            members: rest
+                                .iter()
+                                .filter_map(|arg| match &*arg.expr {
+                                    Expr::Object(object) => Some(members(object)),
+                                    _ => None,
+                                })
+                                .flatten()
+                                .collect(),
+                        }
+                    }
+                    _ => continue,
+                };
+                if !compound.members.is_empty() {
+                    compounds.push(compound);
+                }
+            }
+        }
+        self.compound_components = compounds;
+    }
+
     /// Names a top-level item declares (empty for statements/imports).
     /// A default export with an ident declares that ident.
     fn top_level_declared_names(item: &ModuleItem) -> Vec<String> {
@@ -640,6 +1205,84 @@ impl<'a> AngularVisitor<'a> {
         }
     }
 
+    /// Records NgRx declarations and visits their initializer as their
+    /// owner. An action group declares many actions but owns no handlers.
+    fn visit_ngrx_declaration(
+        &mut self,
+        declarations: Vec<crate::ng::models::ngrx::NgRxDeclaration>,
+        visit: impl FnOnce(&mut Self),
+    ) {
+        let owner = match declarations.as_slice() {
+            [declaration] => Some((declaration.name.clone(), declaration.kind)),
+            _ => None,
+        };
+        self.ngrx.declarations.extend(declarations);
+        let outer = match owner {
+            Some(owner) => self.ngrx_owner.replace(owner),
+            None => self.ngrx_owner.clone(),
+        };
+        visit(self);
+        self.ngrx_owner = outer;
+    }
+
+    /// An inline server action, under the top-level declaration holding it.
+    fn record_server_action(&mut self) {
+        self.server_actions
+            .push(self.current_top_level.clone().unwrap_or_default());
+    }
+
+    /// Declared at the top level of this file or imported from the workspace.
+    fn is_workspace_name(&self, name: &str) -> bool {
+        self.top_level_names.contains(name)
+            || self
+                .imports
+                .iter()
+                .any(|import| import.imported_item.name == name)
+    }
+
+    /// A `.vue` file is a component whatever its script holds. Its default
+    /// export — written, or implied by `<script setup>` — goes by the file
+    /// name it is imported under, and the names its template reads count as
+    /// used.
+    fn collect_vue_component(&mut self) {
+        let Some(sfc) = self.sfc.take() else {
+            return;
+        };
+        self.used_idents.extend(sfc.template_reads);
+        self.used_idents.extend(sfc.components.iter().cloned());
+
+        // `export default class UserCard extends Vue` keeps its own name.
+        let default = self
+            .exports
+            .iter_mut()
+            .find(|export| export.kind == ExportKind::Default);
+        let name = match default {
+            Some(export) if export.name != "default" => export.name.clone(),
+            Some(export) => {
+                export.name = sfc::component_name(&self.file_path);
+                for reference in &mut self.local_references {
+                    if reference.from == "default" {
+                        reference.from = export.name.clone();
+                    }
+                }
+                export.name.clone()
+            }
+            None => {
+                let name = sfc::component_name(&self.file_path);
+                self.push_export(name.clone(), ExportKind::Default);
+                name
+            }
+        };
+
+        self.results.vue_components.push(VueComponentInfo {
+            name,
+            source_path: self.file_path.clone(),
+            package_name: self.package_name.clone(),
+            setup: sfc.setup,
+            template_components: sfc.components,
+        });
+    }
+
     fn assemble_file_facts(&mut self) {
         let mut used_import_names: Vec<String> = self
             .imports
@@ -653,22 +1296,110 @@ impl<'a> AngularVisitor<'a> {
         let mut unresolved_imports = std::mem::take(&mut self.unresolved_imports);
         unresolved_imports.sort();
 
+        let mut translation_keys = std::mem::take(&mut self.translation_keys);
+        translation_keys.sort();
+        translation_keys.dedup();
+
+        // Effect bodies call operators too (`map`, `of`): keep calls of
+        // workspace names only.
+        let mut ngrx = std::mem::take(&mut self.ngrx);
+        ngrx.dispatches
+            .retain(|action| self.is_workspace_name(action.split('.').next().unwrap_or_default()));
+        ngrx.dispatches.sort();
+        ngrx.dispatches.dedup();
+        ngrx.selections.sort();
+        ngrx.selections.dedup();
+
+        // `useState` and friends come from React, not from the workspace.
+        let mut hook_calls = std::mem::take(&mut self.hook_calls);
+        hook_calls.retain(|call| self.is_workspace_name(&call.hook));
+        hook_calls.sort();
+
+        let mut context_reads = std::mem::take(&mut self.context_reads);
+        context_reads.retain(|read| {
+            self.is_workspace_name(read.context.split('.').next().unwrap_or_default())
+        });
+        context_reads.sort();
+        context_reads.dedup();
+
+        let mut server_actions = std::mem::take(&mut self.server_actions);
+        server_actions.sort();
+        server_actions.dedup();
+
+        let mut element_tags = std::mem::take(&mut self.element_tags);
+        element_tags.sort();
+
+        let css_modules = std::mem::take(&mut self.css_modules)
+            .into_values()
+            .map(|mut usage| {
+                usage.classes.sort();
+                usage.classes.dedup();
+                usage
+            })
+            .collect();
+
         self.results.source_files.push(FileFactsInfo {
             path: self.file_path.clone(),
             package_name: self.package_name.clone(),
             exports: std::mem::take(&mut self.exports),
             imports: self.imports.clone(),
             dynamic_imports: std::mem::take(&mut self.dynamic_imports),
+            url_imports: std::mem::take(&mut self.url_imports),
             used_import_names,
             unresolved_imports,
+            package_imports: std::mem::take(&mut self.package_imports),
             jsx_usages: std::mem::take(&mut self.jsx_usages),
+            compound_components: std::mem::take(&mut self.compound_components),
+            hook_calls,
+            context_reads,
             local_references: std::mem::take(&mut self.local_references),
+            bootstrap_calls: std::mem::take(&mut self.bootstrap_calls),
+            routes: std::mem::take(&mut self.routes),
+            injections: std::mem::take(&mut self.injections),
+            dynamic_renders: std::mem::take(&mut self.dynamic_renders),
+            translation_keys,
+            ngrx,
+            directive: self.directive,
+            server_actions,
+            element_tags,
+            css_modules,
         });
     }
 }
 
+/// The directive prologue: string-literal statements opening a module or a
+/// function body (`'use client'`, `'use strict'`).
+fn directives<'s>(stmts: impl IntoIterator<Item = Option<&'s Stmt>>) -> Vec<&'s str> {
+    stmts
+        .into_iter()
+        .map_while(|stmt| match stmt? {
+            Stmt::Expr(expr) => match &*expr.expr {
+                Expr::Lit(Lit::Str(str)) => Some(&*str.value),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// React's rule: `use` followed by a capital letter or digit.
+fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
 impl<'a> Visit for AngularVisitor<'a> {
     fn visit_module(&mut self, module: &Module) {
+        let prologue = directives(module.body.iter().map(ModuleItem::as_stmt));
+        self.directive = if prologue.contains(&"use client") {
+            Some(ModuleDirective::UseClient)
+        } else if prologue.contains(&"use server") {
+            Some(ModuleDirective::UseServer)
+        } else {
+            None
+        };
+
         // Imports first: decorated classes below reference them.
         for item in &module.body {
             if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item {
@@ -678,15 +1409,28 @@ impl<'a> Visit for AngularVisitor<'a> {
 
         self.collect_exports(module);
         self.collect_local_references(module);
+        self.top_level_names = module
+            .body
+            .iter()
+            .flat_map(Self::top_level_declared_names)
+            .collect();
 
         if self.is_jsx_file {
             self.collect_react_components(module);
         }
+        self.collect_react_hooks(module);
+        self.collect_react_contexts(module);
+        self.collect_compound_components(module);
 
         // Walk the whole module: classes in every position, dynamic imports,
         // identifier usage, JSX elements.
-        module.visit_children_with(self);
+        for item in &module.body {
+            self.current_top_level = Self::top_level_declared_names(item).into_iter().next();
+            item.visit_with(self);
+        }
+        self.current_top_level = None;
 
+        self.collect_vue_component();
         self.assemble_file_facts();
     }
 
@@ -694,10 +1438,29 @@ impl<'a> Visit for AngularVisitor<'a> {
     /// their local names out of `used_idents`.
     fn visit_import_decl(&mut self, _import_decl: &ImportDecl) {}
 
+    fn visit_function(&mut self, function: &Function) {
+        let body = function.body.iter().flat_map(|body| &body.stmts);
+        if directives(body.map(Some)).contains(&"use server") {
+            self.record_server_action();
+        }
+        function.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        if let BlockStmtOrExpr::BlockStmt(body) = &*arrow.body {
+            if directives(body.stmts.iter().map(Some)).contains(&"use server") {
+                self.record_server_action();
+            }
+        }
+        arrow.visit_children_with(self);
+    }
+
     fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
         let class_name = class_decl.ident.sym.to_string();
         self.process_decorated_class(&class_decl.class, &class_name);
+        let outer = self.current_class.replace(class_name);
         class_decl.visit_children_with(self);
+        self.current_class = outer;
     }
 
     fn visit_export_default_decl(&mut self, export_default: &ExportDefaultDecl) {
@@ -708,6 +1471,10 @@ impl<'a> Visit for AngularVisitor<'a> {
                 .map(|ident| ident.sym.to_string())
                 .unwrap_or_else(|| "default".to_string());
             self.process_decorated_class(&class_expr.class, &class_name);
+            let outer = self.current_class.replace(class_name);
+            export_default.visit_children_with(self);
+            self.current_class = outer;
+            return;
         }
         export_default.visit_children_with(self);
     }
@@ -729,35 +1496,312 @@ impl<'a> Visit for AngularVisitor<'a> {
                 }
             }
         }
+
+        // `bootstrapApplication(AppComponent, appConfig)` — standalone root;
+        // `inject(ApiService)` — functional DI.
+        if let Callee::Expr(callee) = &call.callee {
+            if let Expr::Ident(ident) = &**callee {
+                if let Some(Expr::Ident(arg)) = call.args.first().map(|arg| &*arg.expr) {
+                    if ident.sym == *"bootstrapApplication" {
+                        self.bootstrap_calls.push(arg.sym.to_string());
+                    } else if ident.sym == *"inject" {
+                        self.injections.push(InjectionSite {
+                            class: self.current_class.clone(),
+                            token: arg.sym.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        // `vcr.createComponent(X)`, `dialog.open(X)`, `createComponent(X)`.
+        let opener = match &call.callee {
+            Callee::Expr(callee) => match &**callee {
+                Expr::Ident(ident) => Some(ident.sym.to_string()),
+                Expr::Member(member) => match &member.prop {
+                    MemberProp::Ident(prop) => Some(prop.sym.to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(opener) = opener {
+            self.record_dynamic_render(opener, call.args.first().map(|arg| &*arg.expr));
+        }
+        self.translation_keys
+            .extend(NgTranslationAnalyzer::call_keys(call));
+        if let Some((tag, class)) = WebComponentAnalyzer::define_call(call) {
+            let name = class.unwrap_or_else(|| tag.clone());
+            self.record_custom_element(tag, name);
+        }
+
+        if matches!(react_function(call).as_deref(), Some("useContext" | "use")) {
+            let context = call.args.first().and_then(|arg| match &*arg.expr {
+                Expr::Ident(ident) => Some(ident.sym.to_string()),
+                // `contexts.ThemeContext` off a namespace import
+                Expr::Member(member) => match (&*member.obj, &member.prop) {
+                    (Expr::Ident(object), MemberProp::Ident(prop)) => {
+                        Some(format!("{}.{}", object.sym, prop.sym))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            });
+            if let Some(context) = context {
+                self.context_reads.push(ContextRead {
+                    context,
+                    caller: self.current_top_level.clone(),
+                });
+            }
+        }
+
+        if let Callee::Expr(callee) = &call.callee {
+            if let Expr::Ident(ident) = &**callee {
+                if is_hook_name(&ident.sym) {
+                    self.hook_calls.push(HookCall {
+                        hook: ident.sym.to_string(),
+                        caller: self.current_top_level.clone(),
+                    });
+                }
+            }
+        }
+
+        if let Some((kind, actions)) = NgRxAnalyzer::handler(call) {
+            let owner = self
+                .ngrx_owner
+                .as_ref()
+                .map(|(name, _)| name.clone())
+                .or_else(|| self.current_class.clone())
+                .unwrap_or_default();
+            self.ngrx.handlers.push(NgRxHandler {
+                owner,
+                kind,
+                actions,
+            });
+        }
+        self.ngrx.selections.extend(NgRxAnalyzer::selection(call));
+        let in_effect = matches!(self.ngrx_owner, Some((_, NgRxKind::Effect)));
+        if self.dispatching || in_effect {
+            self.ngrx.dispatches.extend(NgRxAnalyzer::callee(call));
+        }
+
+        let dispatching = self.dispatching;
+        let starting_worker = self.starting_worker;
+        self.dispatching |= NgRxAnalyzer::is_dispatch(call);
+        self.starting_worker = UrlImportAnalyzer::is_service_worker_registration(call);
         call.visit_children_with(self);
+        self.dispatching = dispatching;
+        self.starting_worker = starting_worker;
+    }
+
+    /// `export const loadUsers = createAction(...)` and friends.
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        let (Pat::Ident(name), Some(init)) = (&declarator.name, &declarator.init) else {
+            declarator.visit_children_with(self);
+            return;
+        };
+        let declarations = NgRxAnalyzer::declarations(&name.id.sym, init);
+        self.visit_ngrx_declaration(declarations, |visitor| {
+            declarator.visit_children_with(visitor)
+        });
+    }
+
+    /// `loadUsers$ = createEffect(...)` in an effects class.
+    fn visit_class_prop(&mut self, prop: &ClassProp) {
+        let (PropName::Ident(key), Some(value), Some(class)) =
+            (&prop.key, &prop.value, &self.current_class)
+        else {
+            prop.visit_children_with(self);
+            return;
+        };
+        let declarations = NgRxAnalyzer::declarations(&format!("{class}.{}", key.sym), value);
+        self.visit_ngrx_declaration(declarations, |visitor| prop.visit_children_with(visitor));
+    }
+
+    fn visit_tagged_tpl(&mut self, tagged: &TaggedTpl) {
+        self.translation_keys
+            .extend(NgTranslationAnalyzer::localize_key(tagged));
+        for tag in WebComponentAnalyzer::html_tags(tagged) {
+            self.element_tags.push(ElementTagUsage {
+                tag,
+                via: ElementTagVia::LitHtml,
+                caller: self.current_top_level.clone(),
+            });
+        }
+        tagged.visit_children_with(self);
+    }
+
+    fn visit_array_lit(&mut self, array: &ArrayLit) {
+        if self.in_route_table {
+            array.visit_children_with(self);
+            return;
+        }
+        match NgRouteAnalyzer::analyze(array) {
+            Some(routes) => {
+                self.routes.extend(routes);
+                self.in_route_table = true;
+                array.visit_children_with(self);
+                self.in_route_table = false;
+            }
+            None => array.visit_children_with(self),
+        }
+    }
+
+    /// `new ComponentPortal(X)`; `new URL('./x', import.meta.url)`, on its
+    /// own or inside `new Worker(...)`.
+    fn visit_new_expr(&mut self, new: &NewExpr) {
+        if let Expr::Ident(callee) = &*new.callee {
+            let first = new
+                .args
+                .as_ref()
+                .and_then(|args| args.first())
+                .map(|arg| &*arg.expr);
+            self.record_dynamic_render(callee.sym.to_string(), first);
+        }
+        if let Some(specifier) = UrlImportAnalyzer::specifier(new) {
+            let kind = if self.starting_worker {
+                ImportKind::Worker
+            } else {
+                ImportKind::Url
+            };
+            self.record_url_import(&specifier, kind);
+        }
+        let starting_worker = self.starting_worker;
+        self.starting_worker = UrlImportAnalyzer::is_worker_constructor(new);
+        new.visit_children_with(self);
+        self.starting_worker = starting_worker;
+    }
+
+    /// `styles.card`, `styles['card-title']` on a CSS module binding.
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        let Expr::Ident(object) = &*member.obj else {
+            member.visit_children_with(self);
+            return;
+        };
+        let Some(path) = self.css_module_bindings.get(&*object.sym) else {
+            member.visit_children_with(self);
+            return;
+        };
+        let class = match &member.prop {
+            MemberProp::Ident(prop) => Some(prop.sym.to_string()),
+            MemberProp::Computed(computed) => match &*computed.expr {
+                Expr::Lit(Lit::Str(name)) => Some(name.value.to_string()),
+                _ => None,
+            },
+            MemberProp::PrivateName(_) => None,
+        };
+        if let Some(usage) = self.css_modules.get_mut(path) {
+            match class {
+                Some(class) => usage.classes.push(class),
+                None => usage.dynamic = true,
+            }
+        }
+        self.used_idents.insert(object.sym.to_string());
+        member.prop.visit_with(self);
     }
 
     fn visit_ident(&mut self, ident: &swc_ecma_ast::Ident) {
+        // A CSS module binding used whole: any class may be read.
+        if let Some(path) = self.css_module_bindings.get(&*ident.sym) {
+            if let Some(usage) = self.css_modules.get_mut(path) {
+                usage.dynamic = true;
+            }
+        }
         self.used_idents.insert(ident.sym.to_string());
     }
 
-    fn visit_jsx_opening_element(&mut self, element: &JSXOpeningElement) {
-        if let JSXElementName::Ident(ident) = &element.name {
-            let name = ident.sym.to_string();
-            // Capitalized tags are components; lowercase are DOM elements.
-            if name.chars().next().is_some_and(|c| c.is_ascii_uppercase()) {
-                let props = element
-                    .attrs
-                    .iter()
-                    .filter_map(|attr| match attr {
-                        JSXAttrOrSpread::JSXAttr(attr) => match &attr.name {
-                            JSXAttrName::Ident(ident) => Some(ident.sym.to_string()),
-                            _ => None,
-                        },
-                        _ => None,
-                    })
-                    .collect();
-                self.jsx_usages.push(JsxUsageInfo {
-                    component: name,
-                    props,
+    fn visit_jsx_element(&mut self, element: &JSXElement) {
+        let opening = &element.opening;
+        if let JSXElementName::Ident(ident) = &opening.name {
+            if web_component_analyzer::is_custom_element_tag(&ident.sym) {
+                self.element_tags.push(ElementTagUsage {
+                    tag: ident.sym.to_string(),
+                    via: ElementTagVia::Jsx,
+                    caller: self.current_top_level.clone(),
                 });
             }
         }
-        element.visit_children_with(self);
+        let is_capitalized =
+            |name: &str| name.chars().next().is_some_and(|c| c.is_ascii_uppercase());
+        let name = match &opening.name {
+            // Capitalized tags are components; lowercase are DOM elements.
+            JSXElementName::Ident(ident) => {
+                Some(ident.sym.to_string()).filter(|name| is_capitalized(name))
+            }
+            // `<Form.Field>`, `<UI.Button>` — but not `<motion.div>`, nor
+            // members of a library object.
+            JSXElementName::JSXMemberExpr(member) => {
+                let name = jsx_member_name(member);
+                let root = name.split('.').next().unwrap_or_default();
+                (is_capitalized(&member.prop.sym) && self.is_workspace_name(root)).then_some(name)
+            }
+            JSXElementName::JSXNamespacedName(_) => None,
+        };
+        if let Some(name) = name {
+            let props = opening
+                .attrs
+                .iter()
+                .filter_map(|attr| match attr {
+                    JSXAttrOrSpread::JSXAttr(attr) => match &attr.name {
+                        JSXAttrName::Ident(ident) => Some(ident.sym.to_string()),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect();
+            let children = element.children.iter().any(|child| match child {
+                JSXElementChild::JSXText(text) => !text.value.trim().is_empty(),
+                _ => true,
+            });
+            let spread = opening
+                .attrs
+                .iter()
+                .any(|attr| matches!(attr, JSXAttrOrSpread::SpreadElement(_)));
+            self.jsx_usages.push(JsxUsageInfo {
+                component: name.clone(),
+                props,
+                children,
+                spread,
+                caller: self.current_top_level.clone(),
+                within: self.jsx_parents.clone(),
+            });
+            self.jsx_parents.push(name);
+            element.visit_children_with(self);
+            self.jsx_parents.pop();
+        } else {
+            element.visit_children_with(self);
+        }
     }
 }
+
+fn first_function_param(function: &Function) -> Option<&swc_ecma_ast::Pat> {
+    function.params.first().map(|param| &param.pat)
+}
+
+/// `createContext` / `React.createContext` → `"createContext"`: the name
+/// of a function called bare or off the `React` namespace.
+fn react_function(call: &CallExpr) -> Option<String> {
+    let Callee::Expr(callee) = &call.callee else {
+        return None;
+    };
+    match &**callee {
+        Expr::Ident(ident) => Some(ident.sym.to_string()),
+        Expr::Member(member) => match (&*member.obj, &member.prop) {
+            (Expr::Ident(object), MemberProp::Ident(prop)) if object.sym == *"React" => {
+                Some(prop.sym.to_string())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `Form.Field`, `UI.Form.Field`.
+fn jsx_member_name(member: &JSXMemberExpr) -> String {
+    let object = match &member.obj {
+        JSXObject::Ident(ident) => ident.sym.to_string(),
+        JSXObject::JSXMemberExpr(inner) => jsx_member_name(inner),
+    };
+    format!("{object}.{}", member.prop.sym)
+}
@@ -13,9 +13,59 @@ pub struct NxProjectConfig {
     pub tags: Option<Vec<String>>,
     #[serde(rename = "projectType")]
     pub project_type: Option<String>,
+    /// Only the `options` of each target are read (see `asset_references`
+    /// and `style_include_paths`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub targets: Option<serde_json::Value>,
 }
 
 impl NxProjectConfig {
+    /// Files and directories the build serves or bundles, as written
+    /// (workspace-relative): `index`, `styles` and `assets` of every
+    /// target's `options`. Object entries contribute their `input`
+    /// (`{ "glob": "**/*", "input": "libs/ui/assets", "output": "assets" }`).
+    pub fn asset_references(&self) -> Vec<String> {
+        let Some(targets) = self.targets.as_ref().and_then(|t| t.as_object()) else {
+            return Vec::new();
+        };
+        let mut references = Vec::new();
+        for options in targets.values().filter_map(|target| target.get("options")) {
+            if let Some(index) = options.get("index").and_then(|i| i.as_str()) {
+                references.push(index.to_string());
+            }
+            for key in ["styles", "assets"] {
+                let entries = options.get(key).and_then(|e| e.as_array());
+                for entry in entries.into_iter().flatten() {
+                    let path = entry
+                        .as_str()
+                        .or_else(|| entry.get("input").and_then(|i| i.as_str()));
+                    references.extend(path.map(str::to_string));
+                }
+            }
+        }
+        references
+    }
+
+    /// `stylePreprocessorOptions.includePaths` of every target's `options`,
+    /// as written (workspace-relative).
+    pub fn style_include_paths(&self) -> Vec<String> {
+        let Some(targets) = self.targets.as_ref().and_then(|t| t.as_object()) else {
+            return Vec::new();
+        };
+        let mut include_paths: Vec<String> = Vec::new();
+        for options in targets.values().filter_map(|target| target.get("options")) {
+            let paths = options
+                .pointer("/stylePreprocessorOptions/includePaths")
+                .and_then(|p| p.as_array());
+            for path in paths.into_iter().flatten().filter_map(|p| p.as_str()) {
+                if !include_paths.iter().any(|known| known == path) {
+                    include_paths.push(path.to_string());
+                }
+            }
+        }
+        include_paths
+    }
+
     /// Project name: explicit `name` field, otherwise the directory name.
     pub fn resolved_name(&self, project_root: &Path) -> String {
         self.name.clone().unwrap_or_else(|| {
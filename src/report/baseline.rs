@@ -1,3 +1,4 @@
+use crate::analyses::stats::DependencyKind;
 use crate::report::FullReport;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
@@ -49,6 +50,24 @@ pub fn finding_keys(report: &FullReport) -> BTreeSet<String> {
     for file in &report.analysis.unused.orphan_files {
         keys.insert(format!("orphan:{}", file.display()));
     }
+    for import in &report.analysis.unused.unused_module_imports {
+        keys.insert(format!(
+            "unused-module-import:{}:{}:{}",
+            import.file.display(),
+            import.module,
+            import.import
+        ));
+    }
+    for module in &report.analysis.unused.reexport_modules {
+        keys.insert(format!(
+            "reexport-module:{}:{}",
+            module.file.display(),
+            module.module
+        ));
+    }
+    for asset in &report.analysis.unused.orphan_assets {
+        keys.insert(format!("orphan-asset:{}", asset.file.display()));
+    }
     for cycle in &report.import_graph.circular_dependencies {
         let joined: Vec<String> = cycle.iter().map(|p| p.display().to_string()).collect();
         keys.insert(format!("cycle:{}", joined.join("->")));
@@ -57,9 +76,121 @@ pub fn finding_keys(report: &FullReport) -> BTreeSet<String> {
         keys.insert(format!("project-cycle:{}", cycle.join("->")));
     }
     for violation in &report.analysis.boundary_violations {
+        // Code violations keep their original key so existing baselines hold.
+        let suffix = match violation.kind {
+            DependencyKind::Code => "",
+            DependencyKind::Style => ":style",
+        };
+        keys.insert(format!(
+            "boundary:{}->{}:{}{}",
+            violation.from, violation.to, violation.source_tag, suffix
+        ));
+    }
+    for violation in &report.analysis.selectors.prefix_violations {
+        keys.insert(format!(
+            "selector-prefix:{}:{}",
+            violation.file.display(),
+            violation.name
+        ));
+    }
+    for collision in &report.analysis.selectors.collisions {
+        keys.insert(format!(
+            "selector-collision:{}:{}<->{}:{}",
+            collision.first.file.display(),
+            collision.first.name,
+            collision.second.file.display(),
+            collision.second.name
+        ));
+    }
+    for provider in &report.analysis.providers.unused {
+        keys.insert(format!(
+            "provider-unused:{}:{}:{}",
+            provider.file.display(),
+            provider.owner,
+            provider.provider
+        ));
+    }
+    for duplicate in &report.analysis.providers.duplicates {
+        keys.insert(format!(
+            "provider-duplicate:{}:{}:{}",
+            duplicate.file.display(),
+            duplicate.owner,
+            duplicate.service
+        ));
+    }
+    for member in &report.analysis.template_members.unused {
+        keys.insert(format!(
+            "member-unused:{}:{}:{}",
+            member.file.display(),
+            member.component,
+            member.member
+        ));
+    }
+    for missing in &report.analysis.template_members.missing {
+        keys.insert(format!(
+            "member-missing:{}:{}:{}",
+            missing.file.display(),
+            missing.component,
+            missing.member
+        ));
+    }
+    for unused in &report.analysis.translations.unused {
+        keys.insert(format!(
+            "translation-unused:{}:{}",
+            unused.file.display(),
+            unused.key
+        ));
+    }
+    for missing in &report.analysis.translations.missing {
+        keys.insert(format!(
+            "translation-missing:{}:{}",
+            missing.file.display(),
+            missing.key
+        ));
+    }
+    for action in &report.analysis.ngrx.undispatched_actions {
+        keys.insert(format!(
+            "ngrx-action:{}:{}",
+            action.file.display(),
+            action.name
+        ));
+    }
+    for handler in &report.analysis.ngrx.dead_handlers {
+        keys.insert(format!(
+            "ngrx-handler:{}:{}:{}",
+            handler.file.display(),
+            handler.owner,
+            handler.actions.join(",")
+        ));
+    }
+    for selector in &report.analysis.ngrx.unselected_selectors {
+        keys.insert(format!(
+            "ngrx-selector:{}:{}",
+            selector.file.display(),
+            selector.name
+        ));
+    }
+    for violation in &report.analysis.client_boundaries {
+        keys.insert(format!(
+            "client-boundary:{}:{:?}:{}",
+            violation.file.display(),
+            violation.kind,
+            violation.target
+        ));
+    }
+    for unused in &report.analysis.css_modules.unused {
+        keys.insert(format!(
+            "css-class-unused:{}:{}",
+            unused.file.display(),
+            unused.class
+        ));
+    }
+    for missing in &report.analysis.css_modules.missing {
         keys.insert(format!(
-            "boundary:{}->{}:{}",
-            violation.from, violation.to, violation.source_tag
+            "css-class-missing:{}:{}:{}",
+            missing.file.display(),
+            missing.module.display(),
+            missing.class
         ));
     }
 
@@ -1,3 +1,5 @@
+use crate::analyses::render_tree::{RenderNode, RenderRoot, RenderVia};
+use crate::analyses::stats::DependencyKind;
 use crate::report::FullReport;
 use std::fmt::Write;
 
@@ -9,7 +11,11 @@ pub fn project_graph_mermaid(report: &FullReport) -> String {
         let _ = writeln!(out, "  {}[\"{}\"]", node_id(&project.name), project.name);
     }
     for dep in &report.analysis.stats.dependencies {
-        let arrow = if dep.lazy { "-. lazy .->" } else { "-->" };
+        let arrow = match (dep.kind, dep.lazy) {
+            (DependencyKind::Style, _) => "-. style .->",
+            (_, true) => "-. lazy .->",
+            _ => "-->",
+        };
         let _ = writeln!(
             out,
             "  {} {}|{}| {}",
@@ -28,7 +34,11 @@ pub fn project_graph_dot(report: &FullReport) -> String {
         let _ = writeln!(out, "  \"{}\";", project.name);
     }
     for dep in &report.analysis.stats.dependencies {
-        let style = if dep.lazy { ", style=dashed" } else { "" };
+        let style = match (dep.kind, dep.lazy) {
+            (DependencyKind::Style, _) => ", style=dotted",
+            (_, true) => ", style=dashed",
+            _ => "",
+        };
         let _ = writeln!(
             out,
             "  \"{}\" -> \"{}\" [label=\"{}\"{}];",
@@ -44,11 +54,17 @@ pub fn file_graph_dot(report: &FullReport) -> String {
     let mut out = String::from("digraph files {\n  rankdir=LR;\n  node [shape=box, fontsize=9];\n");
     for edge in &report.import_graph.edges {
         for target in &edge.to {
+            let style = if edge.url.contains(target) {
+                " [style=dashed]"
+            } else {
+                ""
+            };
             let _ = writeln!(
                 out,
-                "  \"{}\" -> \"{}\";",
+                "  \"{}\" -> \"{}\"{};",
                 edge.from.display(),
-                target.display()
+                target.display(),
+                style
             );
         }
     }
@@ -56,6 +72,58 @@ pub fn file_graph_dot(report: &FullReport) -> String {
     out
 }
 
+/// Render tree as a top-down Mermaid flowchart. A component rendered in
+/// several places gets one node per occurrence, so the shape stays a tree.
+pub fn render_tree_mermaid(roots: &[RenderRoot]) -> String {
+    fn emit(node: &RenderNode, parent: Option<&str>, next_id: &mut usize, out: &mut String) {
+        let id = format!("n{}", next_id);
+        *next_id += 1;
+        let suffix = if node.recursive {
+            " ↺"
+        } else if node.repeated {
+            " ↑"
+        } else {
+            ""
+        };
+        let _ = writeln!(out, "  {}[\"{}{}\"]", id, node.component, suffix);
+        if let Some(parent) = parent {
+            match (&node.via, &node.route_path) {
+                (RenderVia::LazyRoute, path) => {
+                    let _ = writeln!(
+                        out,
+                        "  {} -. \"/{}\" .-> {}",
+                        parent,
+                        path.as_deref().unwrap_or(""),
+                        id
+                    );
+                }
+                (RenderVia::Route, path) => {
+                    let _ = writeln!(
+                        out,
+                        "  {} -->|\"/{}\"| {}",
+                        parent,
+                        path.as_deref().unwrap_or(""),
+                        id
+                    );
+                }
+                _ => {
+                    let _ = writeln!(out, "  {} --> {}", parent, id);
+                }
+            }
+        }
+        for child in &node.children {
+            emit(child, Some(&id), next_id, out);
+        }
+    }
+
+    let mut out = String::from("graph TD\n");
+    let mut next_id = 0;
+    for root in roots {
+        emit(&root.node, None, &mut next_id, &mut out);
+    }
+    out
+}
+
 /// Mermaid node ids must be alphanumeric-ish.
 fn node_id(name: &str) -> String {
     name.chars()
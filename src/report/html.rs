@@ -62,6 +62,7 @@ const TEMPLATE: &str = r##"<!DOCTYPE html>
   svg text { font: 11px -apple-system, sans-serif; fill: var(--fg); }
   .edge { stroke: var(--line); stroke-width: 1.2; fill: none; marker-end: url(#arrow); }
   .edge.lazy { stroke-dasharray: 4 3; }
+  .edge.style { stroke-dasharray: 1 3; }
   .edge.hl { stroke: var(--accent); stroke-width: 2; }
   .node circle { fill: var(--card); stroke: var(--muted); stroke-width: 1.5; cursor: pointer; }
   .node.app circle { stroke: var(--accent); }
@@ -126,7 +127,7 @@ const TEMPLATE: &str = r##"<!DOCTYPE html>
 </div>
 
 <div class="card" id="card-graph">
-  <h2 onclick="toggleCard(this)"><span class="caret">▼</span> Project graph <span class="muted">(click a node to filter; dashed = lazy)</span></h2>
+  <h2 onclick="toggleCard(this)"><span class="caret">▼</span> Project graph <span class="muted">(click a node to filter; dashed = lazy, dotted = style)</span></h2>
   <div class="body"><svg id="graph" width="100%" height="480"></svg></div>
 </div>
 
@@ -233,6 +234,7 @@ const SECTIONS = {
       { label: 'from', text: d => d.from, html: d => hl(d.from) },
       { label: 'to', text: d => d.to, html: d => hl(d.to) },
       { label: 'refs', num: true, text: d => d.count },
+      { label: 'kind', text: d => d.kind },
       { label: 'lazy', text: d => d.lazy ? 'yes' : '', html: d => d.lazy ? 'yes' : '' },
       { label: 'symbols', text: d => d.symbols.map(s => s.name).join(' '), html: d => `<code>${d.symbols.map(s => `${hl(s.name)}×${s.count}`).join(', ')}</code>` },
     ],
@@ -633,7 +635,7 @@ const edgeEls = [];
     if (!a || !b) continue;
     const mx = (a.x + b.x) / 2 + (b.y - a.y) * 0.08;
     const my = (a.y + b.y) / 2 - (b.x - a.x) * 0.08;
-    const path = el('path', { d: `M ${a.x} ${a.y} Q ${mx} ${my} ${b.x} ${b.y}`, class: 'edge' + (dep.lazy ? ' lazy' : '') });
+    const path = el('path', { d: `M ${a.x} ${a.y} Q ${mx} ${my} ${b.x} ${b.y}`, class: 'edge' + (dep.kind === 'Style' ? ' style' : dep.lazy ? ' lazy' : '') });
     path.dataset.from = dep.from; path.dataset.to = dep.to;
     svg.appendChild(path);
     edgeEls.push(path);
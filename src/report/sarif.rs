@@ -1,3 +1,5 @@
+use crate::analyses::client_boundary::ClientBoundaryKind;
+use crate::analyses::stats::DependencyKind;
 use crate::report::FullReport;
 use serde_json::{json, Value};
 
@@ -36,6 +38,27 @@ pub fn to_sarif(report: &FullReport) -> Value {
             &symbol.file.display().to_string(),
         ));
     }
+    for import in &report.analysis.unused.unused_module_imports {
+        results.push(result(
+            "unused-module-import",
+            &format!(
+                "`{}` imports `{}`, but none of its declarables, exports or providers are used.",
+                import.module, import.import
+            ),
+            &import.file.display().to_string(),
+        ));
+    }
+    for module in &report.analysis.unused.reexport_modules {
+        results.push(result(
+            "reexport-module",
+            &format!(
+                "`{}` only re-exports {} — import those modules directly.",
+                module.module,
+                module.reexports.join(", ")
+            ),
+            &module.file.display().to_string(),
+        ));
+    }
     for file in &report.analysis.unused.orphan_files {
         results.push(result(
             "orphan-file",
@@ -43,6 +66,13 @@ pub fn to_sarif(report: &FullReport) -> Value {
             &file.display().to_string(),
         ));
     }
+    for asset in &report.analysis.unused.orphan_assets {
+        results.push(result(
+            "orphan-asset",
+            "Template, stylesheet or asset nothing references.",
+            &asset.file.display().to_string(),
+        ));
+    }
     for cycle in &report.import_graph.circular_dependencies {
         let joined: Vec<String> = cycle.iter().map(|p| p.display().to_string()).collect();
         results.push(result(
@@ -55,15 +85,176 @@ pub fn to_sarif(report: &FullReport) -> Value {
         results.push(result(
             "boundary-violation",
             &format!(
-                "Project `{}` (tag `{}`) must not depend on `{}` (tags: {}).",
+                "Project `{}` (tag `{}`) must not depend on `{}`{} (tags: {}).",
                 violation.from,
                 violation.source_tag,
                 violation.to,
+                match violation.kind {
+                    DependencyKind::Code => "",
+                    DependencyKind::Style => " through stylesheets",
+                },
                 violation.to_tags.join(", ")
             ),
             &violation.from,
         ));
     }
+    for violation in &report.analysis.selectors.prefix_violations {
+        results.push(result(
+            "selector-prefix",
+            &format!(
+                "{} `{}` selector `{}` does not use project prefix `{}`.",
+                violation.kind, violation.name, violation.selector, violation.expected_prefix
+            ),
+            &violation.file.display().to_string(),
+        ));
+    }
+    for collision in &report.analysis.selectors.collisions {
+        results.push(result(
+            "selector-collision",
+            &format!(
+                "Selector `{}` of `{}` overlaps selector `{}` of `{}` ({}).",
+                collision.first.selector,
+                collision.first.name,
+                collision.second.selector,
+                collision.second.name,
+                collision.second.file.display()
+            ),
+            &collision.first.file.display().to_string(),
+        ));
+    }
+    for provider in &report.analysis.providers.unused {
+        results.push(result(
+            "provider-unused",
+            &format!(
+                "`{}` is provided by {:?} `{}`, but nothing in that scope injects it.",
+                provider.provider, provider.scope, provider.owner
+            ),
+            &provider.file.display().to_string(),
+        ));
+    }
+    for duplicate in &report.analysis.providers.duplicates {
+        results.push(result(
+            "provider-duplicate",
+            &format!(
+                "`{}` is `providedIn: '{}'` and provided again by {:?} `{}` — a second instance.",
+                duplicate.service, duplicate.provided_in, duplicate.scope, duplicate.owner
+            ),
+            &duplicate.file.display().to_string(),
+        ));
+    }
+    for member in &report.analysis.template_members.unused {
+        results.push(result(
+            "member-unused",
+            &format!(
+                "`{}.{}` is not read by the template or the class.",
+                member.component, member.member
+            ),
+            &member.file.display().to_string(),
+        ));
+    }
+    for missing in &report.analysis.template_members.missing {
+        results.push(result(
+            "member-missing",
+            &format!(
+                "The template of `{}` reads `{}`, which the class does not declare.",
+                missing.component, missing.member
+            ),
+            &missing.file.display().to_string(),
+        ));
+    }
+    for unused in &report.analysis.translations.unused {
+        results.push(result(
+            "translation-unused",
+            &format!(
+                "Translation key `{}` ({}) is never looked up.",
+                unused.key,
+                unused.languages.join(", ")
+            ),
+            &unused.file.display().to_string(),
+        ));
+    }
+    for missing in &report.analysis.translations.missing {
+        results.push(result(
+            "translation-missing",
+            &format!(
+                "Translation key `{}` is looked up in {} but has no `{}` translation.",
+                missing.key,
+                missing.used_in.display(),
+                missing.language
+            ),
+            &missing.file.display().to_string(),
+        ));
+    }
+    for action in &report.analysis.ngrx.undispatched_actions {
+        results.push(result(
+            "ngrx-action",
+            &format!(
+                "Action `{}` is never dispatched, nor emitted by an effect.",
+                action.name
+            ),
+            &action.file.display().to_string(),
+        ));
+    }
+    for handler in &report.analysis.ngrx.dead_handlers {
+        results.push(result(
+            "ngrx-handler",
+            &format!(
+                "`{}` handles only actions nothing dispatches: {}.",
+                handler.owner,
+                handler.actions.join(", ")
+            ),
+            &handler.file.display().to_string(),
+        ));
+    }
+    for selector in &report.analysis.ngrx.unselected_selectors {
+        results.push(result(
+            "ngrx-selector",
+            &format!("Selector `{}` is never selected.", selector.name),
+            &selector.file.display().to_string(),
+        ));
+    }
+    for violation in &report.analysis.client_boundaries {
+        let problem = match violation.kind {
+            ClientBoundaryKind::ServerOnly => "imports `server-only`".to_string(),
+            ClientBoundaryKind::NodeBuiltin => {
+                format!("imports the Node built-in `{}`", violation.target)
+            }
+            ClientBoundaryKind::InlineServerAction => {
+                format!("declares an inline server action in `{}`", violation.target)
+            }
+        };
+        let chain: Vec<String> = violation
+            .chain
+            .iter()
+            .map(|file| file.display().to_string())
+            .collect();
+        results.push(result(
+            "client-boundary",
+            &format!(
+                "Client code {problem}; reached from a 'use client' file: {}.",
+                chain.join(" → ")
+            ),
+            &violation.file.display().to_string(),
+        ));
+    }
+    for unused in &report.analysis.css_modules.unused {
+        results.push(result(
+            "css-class-unused",
+            &format!("CSS module class `.{}` is never read.", unused.class),
+            &unused.file.display().to_string(),
+        ));
+    }
+    for missing in &report.analysis.css_modules.missing {
+        results.push(result(
+            "css-class-missing",
+            &format!(
+                "Reads class `.{}`, which {} does not define.",
+                missing.class,
+                missing.module.display()
+            ),
+            &missing.file.display().to_string(),
+        ));
+    }
 
     json!({
         "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
@@ -78,8 +269,25 @@ pub fn to_sarif(report: &FullReport) -> Value {
                         rule("export-only", "Symbol used in its own file only — export may be unnecessary"),
                         rule("declared-not-rendered", "Angular entity wired up but never rendered"),
                         rule("orphan-file", "File with no incoming dependencies"),
+                        rule("orphan-asset", "Template, stylesheet or asset nothing references"),
+                        rule("unused-module-import", "NgModule import contributes nothing"),
+                        rule("reexport-module", "NgModule only re-exports other modules"),
                         rule("circular-dependency", "Circular dependency between files"),
                         rule("boundary-violation", "NX tag boundary rule violation"),
+                        rule("selector-prefix", "Selector does not use the project prefix"),
+                        rule("selector-collision", "Selector matched by more than one entity"),
+                        rule("provider-unused", "Provider nothing in its scope injects"),
+                        rule("provider-duplicate", "Root service provided again in a narrower scope"),
+                        rule("member-unused", "Component member no template or class code reads"),
+                        rule("member-missing", "Template reads a member the component does not declare"),
+                        rule("translation-unused", "Translation key nothing looks up"),
+                        rule("translation-missing", "Translation key looked up but absent from a language"),
+                        rule("ngrx-action", "NgRx action nothing dispatches"),
+                        rule("ngrx-handler", "Reducer or effect handler for actions nothing dispatches"),
+                        rule("ngrx-selector", "NgRx selector nothing selects"),
+                        rule("client-boundary", "Server-only code imported into a client component"),
+                        rule("css-class-unused", "CSS module class nothing reads"),
+                        rule("css-class-missing", "Read of a class the CSS module does not define"),
                     ]
                 }
             },
@@ -1,3 +1,5 @@
+use crate::analyses::render_tree::{RenderNode, RenderTreeReport, RenderVia};
+use crate::analyses::stats::DependencyKind;
 use crate::report::FullReport;
 
 /// `project` narrows the output to rows involving that project.
@@ -30,7 +32,14 @@ pub fn print_stats(report: &FullReport, project: Option<&str>) {
             continue;
         }
         let lazy = if dep.lazy { " [lazy]" } else { "" };
-        println!("  {} → {} ({} refs){}", dep.from, dep.to, dep.count, lazy);
+        let style = match dep.kind {
+            DependencyKind::Code => "",
+            DependencyKind::Style => " [style]",
+        };
+        println!(
+            "  {} → {} ({} refs){}{}",
+            dep.from, dep.to, dep.count, lazy, style
+        );
         for symbol in &dep.symbols {
             println!("      {} ×{}", symbol.name, symbol.count);
         }
@@ -176,6 +185,58 @@ pub fn print_unused(report: &FullReport, project: Option<&str>, kinds: &[String]
         for file in orphans {
             println!("  {}", file.display());
         }
+
+        let module_imports: Vec<_> = unused
+            .unused_module_imports
+            .iter()
+            .filter(|import| project.is_none_or(|name| name == import.project))
+            .collect();
+        if !module_imports.is_empty() {
+            println!(
+                "\n🧳 NgModule imports contributing nothing ({}):",
+                module_imports.len()
+            );
+            for import in module_imports {
+                println!(
+                    "  {} imports {} — {}",
+                    import.module,
+                    import.import,
+                    import.file.display()
+                );
+            }
+        }
+
+        let reexports: Vec<_> = unused
+            .reexport_modules
+            .iter()
+            .filter(|module| project.is_none_or(|name| name == module.project))
+            .collect();
+        if !reexports.is_empty() {
+            println!("\n🪞 Re-export-only NgModules ({}):", reexports.len());
+            for module in reexports {
+                println!(
+                    "  {} → [{}] — {}",
+                    module.module,
+                    module.reexports.join(", "),
+                    module.file.display()
+                );
+            }
+        }
+
+        let assets: Vec<_> = unused
+            .orphan_assets
+            .iter()
+            .filter(|asset| project.is_none_or(|name| name == asset.project))
+            .collect();
+        if !assets.is_empty() {
+            println!(
+                "\n🖼️  Orphan templates, styles and assets ({}):",
+                assets.len()
+            );
+            for asset in assets {
+                println!("  {:?} {}", asset.kind, asset.file.display());
+            }
+        }
     }
 }
 
@@ -229,13 +290,397 @@ pub fn print_boundaries(report: &FullReport) {
         report.analysis.boundary_violations.len()
     );
     for violation in &report.analysis.boundary_violations {
+        let style = match violation.kind {
+            DependencyKind::Code => "",
+            DependencyKind::Style => " (style)",
+        };
         println!(
-            "  {} → {} — tag `{}` allows only [{}], target has [{}]",
+            "  {} → {}{} — tag `{}` allows only [{}], target has [{}]",
             violation.from,
             violation.to,
+            style,
             violation.source_tag,
             violation.allowed_tags.join(", "),
             violation.to_tags.join(", ")
         );
     }
 }
+
+pub fn print_selectors(report: &FullReport) {
+    let selectors = &report.analysis.selectors;
+
+    println!(
+        "🏷️  Selector prefix violations ({}):",
+        selectors.prefix_violations.len()
+    );
+    for violation in &selectors.prefix_violations {
+        println!(
+            "  {} [{}] `{}` — expected prefix `{}` ({})",
+            violation.name,
+            violation.kind,
+            violation.selector,
+            violation.expected_prefix,
+            violation.file.display()
+        );
+    }
+
+    println!("\n💥 Selector collisions ({}):", selectors.collisions.len());
+    for collision in &selectors.collisions {
+        let relation = if collision.exact {
+            "same selector"
+        } else {
+            "overlapping"
+        };
+        println!(
+            "  {} `{}` ({}) ⇄ {} `{}` ({}) — {}",
+            collision.first.name,
+            collision.first.selector,
+            collision.first.project,
+            collision.second.name,
+            collision.second.selector,
+            collision.second.project,
+            relation
+        );
+    }
+}
+
+pub fn print_providers(report: &FullReport) {
+    let providers = &report.analysis.providers;
+
+    println!("💉 Providers nothing injects ({}):", providers.unused.len());
+    for provider in &providers.unused {
+        println!(
+            "  {} — {:?} `{}` ({})",
+            provider.provider,
+            provider.scope,
+            provider.owner,
+            provider.file.display()
+        );
+    }
+
+    println!(
+        "\n👯 Root services provided again ({}):",
+        providers.duplicates.len()
+    );
+    for duplicate in &providers.duplicates {
+        println!(
+            "  {} (providedIn: '{}') — also in {:?} `{}` ({})",
+            duplicate.service,
+            duplicate.provided_in,
+            duplicate.scope,
+            duplicate.owner,
+            duplicate.file.display()
+        );
+    }
+}
+
+pub fn print_template_members(report: &FullReport) {
+    let members = &report.analysis.template_members;
+
+    println!("🧹 Members no template uses ({}):", members.unused.len());
+    for member in &members.unused {
+        println!(
+            "  {}.{} — {:?} {:?} [{:?}] ({})",
+            member.component,
+            member.member,
+            member.visibility,
+            member.kind,
+            member.confidence,
+            member.file.display()
+        );
+    }
+
+    println!(
+        "\n❓ Template reads of missing members ({}):",
+        members.missing.len()
+    );
+    for missing in &members.missing {
+        println!(
+            "  {}.{} ({})",
+            missing.component,
+            missing.member,
+            missing.file.display()
+        );
+    }
+}
+
+/// `project` narrows the report to catalogs owned by that project.
+pub fn print_translations(report: &FullReport, project: Option<&str>) {
+    let translations = &report.analysis.translations;
+    let shown = |owner: &str| project.is_none_or(|name| name == owner);
+
+    let unused: Vec<_> = translations
+        .unused
+        .iter()
+        .filter(|t| shown(&t.project))
+        .collect();
+    println!("🌐 Translation keys nothing looks up ({}):", unused.len());
+    for unused in unused {
+        println!(
+            "  {} [{}] ({})",
+            unused.key,
+            unused.languages.join(", "),
+            unused.file.display()
+        );
+    }
+
+    let missing: Vec<_> = translations
+        .missing
+        .iter()
+        .filter(|t| shown(&t.project))
+        .collect();
+    println!("\n❓ Keys missing from a language ({}):", missing.len());
+    for missing in missing {
+        println!(
+            "  {} — no {} in {} (used in {})",
+            missing.key,
+            missing.language,
+            missing.file.display(),
+            missing.used_in.display()
+        );
+    }
+
+    let dynamic: Vec<_> = translations
+        .dynamic_only
+        .iter()
+        .filter(|t| shown(&t.project))
+        .collect();
+    println!(
+        "\n🔀 Keys matched only by runtime-built keys ({}):",
+        dynamic.len()
+    );
+    for dynamic in dynamic {
+        println!(
+            "  {} ← '{}…' in {} ({})",
+            dynamic.key,
+            dynamic.prefix,
+            dynamic.used_in.display(),
+            dynamic.file.display()
+        );
+    }
+}
+
+/// `project` narrows the report to findings in that project.
+pub fn print_ngrx(report: &FullReport, project: Option<&str>) {
+    let ngrx = &report.analysis.ngrx;
+    let shown = |owner: &str| project.is_none_or(|name| name == owner);
+
+    let actions: Vec<_> = ngrx
+        .undispatched_actions
+        .iter()
+        .filter(|a| shown(&a.project))
+        .collect();
+    println!("📭 Actions nothing dispatches ({}):", actions.len());
+    for action in actions {
+        println!("  {} ({})", action.name, action.file.display());
+    }
+
+    let handlers: Vec<_> = ngrx
+        .dead_handlers
+        .iter()
+        .filter(|h| shown(&h.project))
+        .collect();
+    println!("\n💤 Handlers that never run ({}):", handlers.len());
+    for handler in handlers {
+        println!(
+            "  {} — {:?}({}) ({})",
+            handler.owner,
+            handler.kind,
+            handler.actions.join(", "),
+            handler.file.display()
+        );
+    }
+
+    let selectors: Vec<_> = ngrx
+        .unselected_selectors
+        .iter()
+        .filter(|s| shown(&s.project))
+        .collect();
+    println!("\n🔍 Selectors nothing selects ({}):", selectors.len());
+    for selector in selectors {
+        println!("  {} ({})", selector.name, selector.file.display());
+    }
+}
+
+/// `project` narrows the report to violations in that project.
+pub fn print_client_boundaries(report: &FullReport, project: Option<&str>) {
+    let violations: Vec<_> = report
+        .analysis
+        .client_boundaries
+        .iter()
+        .filter(|v| project.is_none_or(|name| name == v.project))
+        .collect();
+    println!("🧱 Server code in client bundles ({}):", violations.len());
+    for violation in violations {
+        println!(
+            "  {:?} `{}` ({})",
+            violation.kind,
+            violation.target,
+            violation.file.display()
+        );
+        for (depth, file) in violation.chain.iter().enumerate() {
+            println!("    {}{}", "  ".repeat(depth), file.display());
+        }
+    }
+}
+
+/// `project` narrows the inventory to routes of that project.
+pub fn print_nest_routes(report: &FullReport, project: Option<&str>) {
+    let routes: Vec<_> = report
+        .analysis
+        .nest_routes
+        .iter()
+        .filter(|r| project.is_none_or(|name| name == r.project))
+        .collect();
+    println!("🛣️  HTTP routes ({}):", routes.len());
+    for route in routes {
+        let module = match &route.module {
+            Some(module) => module.clone(),
+            None => "not registered in any module".to_string(),
+        };
+        println!(
+            "  {:<7} {:<32} {}.{} ({})",
+            route.method, route.path, route.controller, route.handler, module
+        );
+    }
+}
+
+/// `project` narrows the report to modules and scripts of that project.
+pub fn print_css_modules(report: &FullReport, project: Option<&str>) {
+    let css_modules = &report.analysis.css_modules;
+    let shown = |owner: &str| project.is_none_or(|name| name == owner);
+
+    let unused: Vec<_> = css_modules
+        .unused
+        .iter()
+        .filter(|c| shown(&c.project))
+        .collect();
+    println!("🎨 CSS module classes nothing reads ({}):", unused.len());
+    for unused in unused {
+        println!("  .{} ({})", unused.class, unused.file.display());
+    }
+
+    let missing: Vec<_> = css_modules
+        .missing
+        .iter()
+        .filter(|c| shown(&c.project))
+        .collect();
+    println!("\n❓ Reads of classes a module lacks ({}):", missing.len());
+    for missing in missing {
+        println!(
+            "  .{} — not in {} (read in {})",
+            missing.class,
+            missing.module.display(),
+            missing.file.display()
+        );
+    }
+}
+
+/// `project` narrows adoption rows and plan steps to that project.
+pub fn print_migration(report: &FullReport, project: Option<&str>) {
+    let migration = &report.analysis.migration;
+
+    println!("🧭 Standalone adoption:");
+    for adoption in &migration.adoption {
+        if project.is_some_and(|name| name != adoption.project) {
+            continue;
+        }
+        println!(
+            "  {:<28} {:>4} standalone, {:>4} in NgModules ({:.0}%)",
+            adoption.project,
+            adoption.standalone,
+            adoption.non_standalone,
+            adoption.ratio * 100.0
+        );
+    }
+
+    let steps: Vec<_> = migration
+        .plan
+        .iter()
+        .filter(|step| project.is_none_or(|name| name == step.project))
+        .collect();
+    println!(
+        "\n🪜 Migration plan, leaves first ({} modules):",
+        steps.len()
+    );
+    for step in steps {
+        let verdict = if step.deletable {
+            "deletable after migration"
+        } else {
+            "kept"
+        };
+        println!(
+            "  {}. {} ({}) — {}",
+            step.step, step.module, step.project, verdict
+        );
+        if !step.depends_on.is_empty() {
+            println!("      after: {}", step.depends_on.join(", "));
+        }
+        for declarable in &step.declarables {
+            let flag = if declarable.needs_standalone {
+                "make standalone"
+            } else {
+                "already standalone"
+            };
+            print!("      {} [{}] — {}", declarable.name, declarable.kind, flag);
+            if declarable.imports.is_empty() {
+                println!();
+            } else {
+                println!("; imports: [{}]", declarable.imports.join(", "));
+            }
+        }
+        for blocker in &step.blockers {
+            println!("      ⛔ {}", blocker);
+        }
+    }
+}
+
+pub fn print_render_tree(tree: &RenderTreeReport) {
+    fn print_node(node: &RenderNode, indent: usize) {
+        let via = match (&node.via, &node.route_path) {
+            (RenderVia::Bootstrap, _) => " [bootstrap]".to_string(),
+            (RenderVia::Route, Some(path)) => format!(" [route /{}]", path),
+            (RenderVia::LazyRoute, Some(path)) => format!(" [lazy route /{}]", path),
+            (RenderVia::LazyRoute, None) => " [lazy route]".to_string(),
+            _ => String::new(),
+        };
+        let recursive = if node.recursive {
+            " ↺ (recursive)"
+        } else if node.repeated {
+            " ↑ (expanded above)"
+        } else {
+            ""
+        };
+        println!(
+            "{}{}{}{}",
+            "  ".repeat(indent + 1),
+            node.component,
+            via,
+            recursive
+        );
+        for child in &node.children {
+            print_node(child, indent + 1);
+        }
+    }
+
+    println!("🌳 Render tree ({} roots):", tree.roots.len());
+    for root in &tree.roots {
+        println!("  {} — {}", root.app, root.node.file.display());
+        print_node(&root.node, 1);
+    }
+
+    if !tree.unreachable.is_empty() {
+        println!(
+            "\n🪦 Rendered only from unreachable components ({}):",
+            tree.unreachable.len()
+        );
+        for component in &tree.unreachable {
+            println!(
+                "  {} ({}) — rendered by {}",
+                component.name,
+                component.project,
+                component.rendered_by.join(", ")
+            );
+        }
+    }
+}
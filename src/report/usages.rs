@@ -1,4 +1,5 @@
 use crate::analyses::project_map::{is_test_file, ProjectCatalog};
+use crate::analyses::react_usage::JsxResolver;
 use crate::report::FullReport;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -91,6 +92,7 @@ pub fn symbol_usages(
         }
     }
 
+    let jsx_resolver = JsxResolver::new(&report.results);
     let mut result = Vec::new();
 
     for (decl_file, kind) in declarations {
@@ -124,20 +126,15 @@ pub fn symbol_usages(
                     });
                 }
             }
-            // JSX renders, resolved through this file's imports.
+            // JSX renders, resolved to the component they render.
             for jsx in &file.jsx_usages {
-                if jsx.component == symbol {
-                    let resolved_here = file.imports.iter().any(|import| {
-                        import.imported_item.name == symbol && import.resolved_path == decl_file
-                    }) || file.path == decl_file;
-                    if resolved_here {
-                        usages.push(SymbolUsage {
-                            file: file.path.clone(),
-                            project: project_of(&file.path),
-                            via: UsageVia::Jsx,
-                            test: is_test_file(&file.path),
-                        });
-                    }
+                if jsx_resolver.resolve(&jsx.component, file) == Some((&decl_file, symbol)) {
+                    usages.push(SymbolUsage {
+                        file: file.path.clone(),
+                        project: project_of(&file.path),
+                        via: UsageVia::Jsx,
+                        test: is_test_file(&file.path),
+                    });
                 }
             }
         }
@@ -0,0 +1,15 @@
+import { Component } from '@angular/core';
+import { DsCardComponent } from '@fix/ds';
+import { CartComponent } from '@fix/shop';
+
+@Component({
+  selector: 'admin-root',
+  template: `
+    <ds-card title="Admin" (closed)="log()"></ds-card>
+    <shop-cart></shop-cart>
+  `,
+  imports: [DsCardComponent, CartComponent],
+})
+export class AdminComponent {
+  log() {}
+}
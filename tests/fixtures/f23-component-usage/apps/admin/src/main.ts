@@ -0,0 +1,3 @@
+import { AdminComponent } from './app/admin.component';
+
+console.log(AdminComponent);
@@ -0,0 +1 @@
+export * from './lib/card.component';
@@ -0,0 +1,15 @@
+import { Component, EventEmitter, Input, Output, input, model, output } from '@angular/core';
+
+@Component({
+  selector: 'ds-card',
+  template: '<section><ng-content /></section>',
+})
+export class DsCardComponent {
+  @Input() title = '';
+  elevation = input(1);
+  variant = input<'flat' | 'raised'>('flat');
+  open = model(false);
+  closed = output<void>();
+  @Output() openChange = new EventEmitter<boolean>();
+  @Output() pinned = new EventEmitter<void>();
+}
@@ -0,0 +1,16 @@
+import { Component } from '@angular/core';
+import { DsCardComponent } from '@fix/ds';
+
+@Component({
+  selector: 'shop-cart',
+  template: `
+    <ds-card title="Cart" [elevation]="2" (closed)="onClose()"></ds-card>
+    <ds-card [title]="summary" [(open)]="expanded"></ds-card>
+  `,
+  imports: [DsCardComponent],
+})
+export class CartComponent {
+  summary = 'Summary';
+  expanded = false;
+  onClose() {}
+}
@@ -0,0 +1,7 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-admin',
+  template: '<h2>Admin</h2>',
+})
+export class AdminComponent {}
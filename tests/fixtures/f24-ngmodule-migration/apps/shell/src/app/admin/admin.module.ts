@@ -0,0 +1,10 @@
+import { NgModule } from '@angular/core';
+import { AdminComponent } from './admin.component';
+import { AdminRoutingModule } from './admin-routing.module';
+
+// Nothing but declarations — yet the app's routes load it lazily.
+@NgModule({
+  declarations: [AdminComponent],
+  imports: [AdminRoutingModule],
+})
+export class AdminModule {}
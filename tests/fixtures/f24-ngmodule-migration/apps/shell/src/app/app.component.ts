@@ -0,0 +1,7 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'shell-root',
+  template: '<orders-list></orders-list>',
+})
+export class AppComponent {}
@@ -0,0 +1 @@
+export * from './lib/orders.module';
@@ -0,0 +1,9 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'orders-row',
+  template: '<span sharedHighlight>{{ total | format }}</span><ui-button>Open</ui-button>',
+})
+export class OrderRowComponent {
+  total = 10;
+}
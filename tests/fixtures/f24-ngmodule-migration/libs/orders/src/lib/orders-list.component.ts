@@ -0,0 +1,7 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'orders-list',
+  template: '<orders-row></orders-row>',
+})
+export class OrdersListComponent {}
@@ -0,0 +1,15 @@
+import { CommonModule } from '@angular/common';
+import { NgModule } from '@angular/core';
+import { SharedModule } from '@fix/shared';
+import { UiButtonComponent } from '@fix/ui';
+import { OrderRowComponent } from './order-row.component';
+import { OrdersListComponent } from './orders-list.component';
+import { OrdersStore } from './orders.store';
+
+@NgModule({
+  declarations: [OrdersListComponent, OrderRowComponent],
+  imports: [CommonModule, SharedModule, UiButtonComponent],
+  exports: [OrdersListComponent],
+  providers: [OrdersStore],
+})
+export class OrdersModule {}
@@ -0,0 +1,4 @@
+export * from './lib/shared.module';
+export * from './lib/highlight.directive';
+export * from './lib/format.pipe';
+export * from './lib/shared-forms.module';
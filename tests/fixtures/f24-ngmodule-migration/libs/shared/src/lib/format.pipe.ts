@@ -0,0 +1,8 @@
+import { Pipe, PipeTransform } from '@angular/core';
+
+@Pipe({ name: 'format' })
+export class FormatPipe implements PipeTransform {
+  transform(value: unknown): string {
+    return String(value);
+  }
+}
@@ -0,0 +1,4 @@
+import { Directive } from '@angular/core';
+
+@Directive({ selector: '[sharedHighlight]' })
+export class HighlightDirective {}
@@ -0,0 +1,11 @@
+import { CommonModule } from '@angular/common';
+import { NgModule } from '@angular/core';
+import { FormsModule } from '@angular/forms';
+
+// Declares nothing, but every importer gets CommonModule and FormsModule
+// through it.
+@NgModule({
+  imports: [CommonModule, FormsModule],
+  exports: [CommonModule, FormsModule],
+})
+export class SharedFormsModule {}
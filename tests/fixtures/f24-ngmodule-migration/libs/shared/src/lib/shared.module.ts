@@ -0,0 +1,9 @@
+import { NgModule } from '@angular/core';
+import { FormatPipe } from './format.pipe';
+import { HighlightDirective } from './highlight.directive';
+
+@NgModule({
+  declarations: [HighlightDirective, FormatPipe],
+  exports: [HighlightDirective, FormatPipe],
+})
+export class SharedModule {}
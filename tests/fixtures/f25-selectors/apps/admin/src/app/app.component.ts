@@ -0,0 +1,11 @@
+import { Component } from '@angular/core';
+import { ElevatedDirective, UiCardComponent } from '@fix/ui';
+
+// Every app has its own `app-root`; no template can hold two of them.
+@Component({
+  selector: 'app-root',
+  standalone: true,
+  imports: [UiCardComponent, ElevatedDirective],
+  template: '<ui-card elevated></ui-card>',
+})
+export class AppComponent {}
@@ -0,0 +1,12 @@
+import { Component } from '@angular/core';
+import { LegacyButtonComponent } from '@fix/legacy-ui';
+import { UiButtonComponent } from '@fix/ui';
+
+// Both button libs are reachable from here: their `ui-button`s collide.
+@Component({
+  selector: 'app-root',
+  standalone: true,
+  imports: [UiButtonComponent, LegacyButtonComponent],
+  template: '<ui-button></ui-button>',
+})
+export class AppComponent {}
@@ -0,0 +1,3 @@
+export * from './lib/badge.component';
+export * from './lib/button.component';
+export * from './lib/tooltip-button.directive';
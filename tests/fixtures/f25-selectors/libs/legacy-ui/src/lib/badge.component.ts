@@ -0,0 +1,4 @@
+import { Component } from '@angular/core';
+
+@Component({ selector: 'lg-badge, [lgBadge]', template: '<span></span>', standalone: true })
+export class LgBadgeComponent {}
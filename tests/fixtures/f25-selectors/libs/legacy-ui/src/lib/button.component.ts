@@ -0,0 +1,5 @@
+import { Component } from '@angular/core';
+
+// Copy-pasted from ui: same element selector in a second lib.
+@Component({ selector: 'ui-button', template: '<button></button>', standalone: true })
+export class LegacyButtonComponent {}
@@ -0,0 +1,5 @@
+import { Directive } from '@angular/core';
+
+// Narrower than [uiTooltip]: every <button lgTooltipButton uiTooltip> is matched by both.
+@Directive({ selector: 'button[lgTooltipButton][uiTooltip]', standalone: true })
+export class LgTooltipButtonDirective {}
@@ -0,0 +1,9 @@
+export * from './lib/button.component';
+export * from './lib/card.component';
+export * from './lib/elevated.directive';
+export * from './lib/highlight.directive';
+export * from './lib/plain-card.component';
+export * from './lib/primary-button.component';
+export * from './lib/surface.component';
+export * from './lib/tooltip-compat.directive';
+export * from './lib/tooltip.directive';
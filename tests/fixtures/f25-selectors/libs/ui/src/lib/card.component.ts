@@ -0,0 +1,5 @@
+import { Component } from '@angular/core';
+
+// Violates the `ui` prefix.
+@Component({ selector: 'fancy-card', template: '<section></section>', standalone: true })
+export class FancyCardComponent {}
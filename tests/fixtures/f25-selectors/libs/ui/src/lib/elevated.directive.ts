@@ -0,0 +1,5 @@
+import { Directive } from '@angular/core';
+
+// Refines the card component's own selector — both apply, by design.
+@Directive({ selector: 'ui-card[elevated]', standalone: true })
+export class ElevatedDirective {}
@@ -0,0 +1,5 @@
+import { Directive } from '@angular/core';
+
+// Violates the `ui` prefix; `uikit` would too — the prefix must end a word.
+@Directive({ selector: '[highlight], [uikitHighlight]', standalone: true })
+export class HighlightDirective {}
@@ -0,0 +1,5 @@
+import { Component } from '@angular/core';
+
+// No hyphen at all: still an element selector without the `ui-` prefix.
+@Component({ selector: 'uicard', template: '<section></section>', standalone: true })
+export class PlainCardComponent {}
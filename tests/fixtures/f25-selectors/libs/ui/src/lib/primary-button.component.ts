@@ -0,0 +1,5 @@
+import { Component } from '@angular/core';
+
+// A second component on `<ui-button class="primary">`: Angular refuses it.
+@Component({ selector: 'ui-button.primary', template: '<button></button>', standalone: true })
+export class UiPrimaryButtonComponent {}
@@ -0,0 +1,4 @@
+import { Component } from '@angular/core';
+
+@Component({ selector: 'ui-card', template: '<section><ng-content /></section>', standalone: true })
+export class UiCardComponent {}
@@ -0,0 +1,5 @@
+import { Directive } from '@angular/core';
+
+// Same selector as UiTooltipDirective: every `uiTooltip` gets both.
+@Directive({ selector: '[uiTooltip]', standalone: true })
+export class UiTooltipCompatDirective {}
@@ -0,0 +1,4 @@
+import { Directive } from '@angular/core';
+
+@Directive({ selector: '[uiTooltip]', standalone: true })
+export class UiTooltipDirective {}
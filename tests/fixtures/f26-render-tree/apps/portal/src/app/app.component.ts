@@ -0,0 +1,11 @@
+import { Component } from '@angular/core';
+import { RouterOutlet } from '@angular/router';
+import { ShellComponent } from '@fix/ui';
+
+@Component({
+  selector: 'fix-root',
+  standalone: true,
+  imports: [RouterOutlet, ShellComponent],
+  template: `<fix-shell></fix-shell><router-outlet></router-outlet>`,
+})
+export class AppComponent {}
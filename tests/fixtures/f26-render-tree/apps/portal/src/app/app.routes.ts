@@ -0,0 +1,16 @@
+import { HomeComponent } from './home.component';
+import { OrderDetailComponent } from './orders/order-detail.component';
+
+export const routes = [
+  { path: '', component: HomeComponent },
+  {
+    path: 'orders',
+    loadComponent: () =>
+      import('./orders/orders.component').then((m) => m.OrdersComponent),
+    children: [{ path: ':id', component: OrderDetailComponent }],
+  },
+  {
+    path: 'admin',
+    loadChildren: () => import('@fix/admin').then((m) => m.adminRoutes),
+  },
+];
@@ -0,0 +1,10 @@
+import { Component } from '@angular/core';
+import { TreeNodeComponent } from '@fix/ui';
+
+@Component({
+  selector: 'fix-home',
+  standalone: true,
+  imports: [TreeNodeComponent],
+  template: `<fix-tree-node></fix-tree-node>`,
+})
+export class HomeComponent {}
@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-order-detail',
+  standalone: true,
+  template: `<h3>Order</h3>`,
+})
+export class OrderDetailComponent {}
@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-orders',
+  standalone: true,
+  template: `<h2>Orders</h2>`,
+})
+export class OrdersComponent {}
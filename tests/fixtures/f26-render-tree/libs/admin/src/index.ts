@@ -0,0 +1 @@
+export * from './lib/admin.routes';
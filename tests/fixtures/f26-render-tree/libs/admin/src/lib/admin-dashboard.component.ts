@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-admin-dashboard',
+  standalone: true,
+  template: `<h2>Admin</h2>`,
+})
+export class AdminDashboardComponent {}
@@ -0,0 +1,3 @@
+import { AdminDashboardComponent } from './admin-dashboard.component';
+
+export const adminRoutes = [{ path: '', component: AdminDashboardComponent }];
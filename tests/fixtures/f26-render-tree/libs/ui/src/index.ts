@@ -0,0 +1,5 @@
+export * from './lib/shell.component';
+export * from './lib/nav.component';
+export * from './lib/tree-node.component';
+export * from './lib/legacy-panel.component';
+export * from './lib/legacy-row.component';
@@ -0,0 +1,11 @@
+import { Component } from '@angular/core';
+import { LegacyRowComponent } from './legacy-row.component';
+
+// Exported, but no reachable template renders it.
+@Component({
+  selector: 'fix-legacy-panel',
+  standalone: true,
+  imports: [LegacyRowComponent],
+  template: `<fix-legacy-row></fix-legacy-row>`,
+})
+export class LegacyPanelComponent {}
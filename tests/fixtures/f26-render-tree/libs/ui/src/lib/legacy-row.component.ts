@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-legacy-row',
+  standalone: true,
+  template: `<span>row</span>`,
+})
+export class LegacyRowComponent {}
@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-nav',
+  standalone: true,
+  template: `<nav>menu</nav>`,
+})
+export class NavComponent {}
@@ -0,0 +1,10 @@
+import { Component } from '@angular/core';
+import { NavComponent } from './nav.component';
+
+@Component({
+  selector: 'fix-shell',
+  standalone: true,
+  imports: [NavComponent],
+  template: `<header><fix-nav></fix-nav></header>`,
+})
+export class ShellComponent {}
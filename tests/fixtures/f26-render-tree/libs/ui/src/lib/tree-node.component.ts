@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-tree-node',
+  standalone: true,
+  template: `<ul><li><fix-tree-node></fix-tree-node></li></ul>`,
+})
+export class TreeNodeComponent {}
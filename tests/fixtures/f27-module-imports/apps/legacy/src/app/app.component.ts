@@ -0,0 +1,10 @@
+import { Component } from '@angular/core';
+import { DataService } from '@fix/shared';
+
+@Component({
+  selector: 'fix-root',
+  template: '<fix-button>Load</fix-button>',
+})
+export class AppComponent {
+  constructor(private readonly data: DataService) {}
+}
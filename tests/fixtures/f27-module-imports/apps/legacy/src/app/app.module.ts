@@ -0,0 +1,11 @@
+import { NgModule } from '@angular/core';
+import { BrowserModule } from '@angular/platform-browser';
+import { DataModule, SharedModule, UtilsModule } from '@fix/shared';
+import { AppComponent } from './app.component';
+
+@NgModule({
+  imports: [BrowserModule, SharedModule, UtilsModule, DataModule],
+  declarations: [AppComponent],
+  bootstrap: [AppComponent],
+})
+export class AppModule {}
@@ -0,0 +1,10 @@
+import { NgModule } from '@angular/core';
+import { RouterModule } from '@angular/router';
+import { OrdersComponent } from './orders.component';
+
+// Registers routes through `forChild(...)`: that is its whole contribution.
+@NgModule({
+  imports: [RouterModule.forChild([{ path: '', component: OrdersComponent }])],
+  exports: [RouterModule],
+})
+export class OrdersRoutingModule {}
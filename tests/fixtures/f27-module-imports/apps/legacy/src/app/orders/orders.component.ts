@@ -0,0 +1,10 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-orders',
+  template: '<input *ngIf="editable" [(ngModel)]="filter" />',
+})
+export class OrdersComponent {
+  editable = true;
+  filter = '';
+}
@@ -0,0 +1,10 @@
+import { NgModule } from '@angular/core';
+import { FormsBundleModule } from '@fix/shared';
+import { OrdersComponent } from './orders.component';
+import { OrdersRoutingModule } from './orders-routing.module';
+
+@NgModule({
+  imports: [FormsBundleModule, OrdersRoutingModule],
+  declarations: [OrdersComponent],
+})
+export class OrdersModule {}
@@ -0,0 +1,9 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-reports',
+  template: '<p>{{ title | fmt }}</p>',
+})
+export class ReportsComponent {
+  title = ' Reports ';
+}
@@ -0,0 +1,10 @@
+import { NgModule } from '@angular/core';
+import { UiBundleModule } from '@fix/shared';
+import { ReportsComponent } from './reports.component';
+
+@NgModule({
+  imports: [UiBundleModule],
+  declarations: [ReportsComponent],
+  exports: [ReportsComponent],
+})
+export class ReportsModule {}
@@ -0,0 +1,8 @@
+export * from './lib/button.component';
+export * from './lib/format.pipe';
+export * from './lib/data.service';
+export * from './lib/shared.module';
+export * from './lib/utils.module';
+export * from './lib/data.module';
+export * from './lib/ui-bundle.module';
+export * from './lib/forms-bundle.module';
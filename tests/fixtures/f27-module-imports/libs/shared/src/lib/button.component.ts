@@ -0,0 +1,7 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-button',
+  template: '<button><ng-content></ng-content></button>',
+})
+export class ButtonComponent {}
@@ -0,0 +1,8 @@
+import { Injectable } from '@angular/core';
+
+@Injectable()
+export class DataService {
+  load(): string[] {
+    return [];
+  }
+}
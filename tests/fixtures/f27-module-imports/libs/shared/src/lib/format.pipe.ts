@@ -0,0 +1,8 @@
+import { Pipe, PipeTransform } from '@angular/core';
+
+@Pipe({ name: 'fmt' })
+export class FormatPipe implements PipeTransform {
+  transform(value: string): string {
+    return value.trim();
+  }
+}
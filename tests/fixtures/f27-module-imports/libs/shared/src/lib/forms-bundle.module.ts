@@ -0,0 +1,11 @@
+import { NgModule } from '@angular/core';
+import { CommonModule } from '@angular/common';
+import { FormsModule } from '@angular/forms';
+
+// Re-exports third-party modules only: what its importers use from it is
+// unknown, so it is never reported.
+@NgModule({
+  imports: [CommonModule, FormsModule],
+  exports: [CommonModule, FormsModule],
+})
+export class FormsBundleModule {}
@@ -0,0 +1,10 @@
+import { NgModule } from '@angular/core';
+import { SharedModule } from './shared.module';
+import { UtilsModule } from './utils.module';
+
+// A "shared module" that only bundles other modules.
+@NgModule({
+  imports: [SharedModule, UtilsModule],
+  exports: [SharedModule, UtilsModule],
+})
+export class UiBundleModule {}
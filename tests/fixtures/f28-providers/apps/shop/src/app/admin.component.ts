@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-admin',
+  standalone: true,
+  template: `<h2>Admin</h2>`,
+})
+export class AdminComponent {}
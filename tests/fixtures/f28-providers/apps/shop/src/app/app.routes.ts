@@ -0,0 +1,19 @@
+import { MetricsService } from '@fix/core';
+import { AdminComponent } from './admin.component';
+import { CartComponent } from './cart.component';
+import { ReportsComponent } from './reports.component';
+import { REPORT_PAGE_SIZE } from './tokens';
+
+export const routes = [
+  { path: '', component: CartComponent },
+  {
+    path: 'reports',
+    component: ReportsComponent,
+    providers: [{ provide: REPORT_PAGE_SIZE, useValue: 25 }],
+  },
+  { path: 'admin', component: AdminComponent, providers: [MetricsService] },
+  {
+    path: 'legacy',
+    loadChildren: () => import('./legacy.module').then((m) => m.LegacyModule),
+  },
+];
@@ -0,0 +1,13 @@
+import { Injectable } from '@angular/core';
+import { HttpHandler, HttpInterceptor, HttpRequest } from '@angular/common/http';
+
+@Injectable()
+export class AuthInterceptor implements HttpInterceptor {
+  intercept(request: HttpRequest<unknown>, next: HttpHandler) {
+    return next.handle(request);
+  }
+
+  handleError(error: unknown): void {
+    console.error(error);
+  }
+}
@@ -0,0 +1,11 @@
+import { Component, inject } from '@angular/core';
+import { CartService } from '@fix/core';
+
+@Component({
+  selector: 'fix-cart-line',
+  standalone: true,
+  template: `<span>line</span>`,
+})
+export class CartLineComponent {
+  private readonly cart = inject(CartService);
+}
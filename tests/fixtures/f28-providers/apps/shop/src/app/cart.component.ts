@@ -0,0 +1,17 @@
+import { Component } from '@angular/core';
+import { AuditService, CartService, LoggerService, SessionService } from '@fix/core';
+import { CartLineComponent } from './cart-line.component';
+import { QuantityInputComponent } from './quantity-input.component';
+
+// SessionService is already a root singleton — providing it here creates a
+// second instance. AuditService is provided but never injected.
+@Component({
+  selector: 'fix-cart',
+  standalone: true,
+  imports: [CartLineComponent, QuantityInputComponent],
+  providers: [CartService, LoggerService, SessionService, AuditService],
+  template: `<fix-cart-line></fix-cart-line><fix-quantity-input></fix-quantity-input>`,
+})
+export class CartComponent {
+  constructor(private readonly session: SessionService) {}
+}
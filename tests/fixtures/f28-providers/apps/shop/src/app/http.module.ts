@@ -0,0 +1,18 @@
+import { ErrorHandler, NgModule } from '@angular/core';
+import { HTTP_INTERCEPTORS } from '@angular/common/http';
+import { SessionService } from '@fix/core';
+import { AuthInterceptor } from './auth.interceptor';
+import { PLUGINS } from './tokens';
+
+// Interceptors and the error handler are collected by Angular itself;
+// multi-provided workspace tokens are read as a whole list elsewhere. An
+// eager module's SessionService is the root one, not a second instance.
+@NgModule({
+  providers: [
+    { provide: HTTP_INTERCEPTORS, useClass: AuthInterceptor, multi: true },
+    { provide: ErrorHandler, useClass: AuthInterceptor },
+    { provide: PLUGINS, useValue: 'audit', multi: true },
+    SessionService,
+  ],
+})
+export class HttpModule {}
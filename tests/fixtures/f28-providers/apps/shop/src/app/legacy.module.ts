@@ -0,0 +1,9 @@
+import { NgModule } from '@angular/core';
+import { SessionService } from '@fix/core';
+
+// Lazy-loaded: its injector is a child of the root one, so this is a
+// second SessionService.
+@NgModule({
+  providers: [SessionService],
+})
+export class LegacyModule {}
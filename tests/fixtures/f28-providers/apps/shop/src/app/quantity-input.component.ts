@@ -0,0 +1,22 @@
+import { Component, forwardRef } from '@angular/core';
+import { ControlValueAccessor, NG_VALUE_ACCESSOR } from '@angular/forms';
+
+// Forms read NG_VALUE_ACCESSOR from the element injector — nothing in the
+// workspace injects it.
+@Component({
+  selector: 'fix-quantity-input',
+  standalone: true,
+  providers: [
+    {
+      provide: NG_VALUE_ACCESSOR,
+      useExisting: forwardRef(() => QuantityInputComponent),
+      multi: true,
+    },
+  ],
+  template: `<input type="number" />`,
+})
+export class QuantityInputComponent implements ControlValueAccessor {
+  writeValue(): void {}
+  registerOnChange(): void {}
+  registerOnTouched(): void {}
+}
@@ -0,0 +1,11 @@
+import { Component, Inject } from '@angular/core';
+import { REPORT_PAGE_SIZE } from './tokens';
+
+@Component({
+  selector: 'fix-reports',
+  standalone: true,
+  template: `<h2>Reports</h2>`,
+})
+export class ReportsComponent {
+  constructor(@Inject(REPORT_PAGE_SIZE) readonly pageSize: number) {}
+}
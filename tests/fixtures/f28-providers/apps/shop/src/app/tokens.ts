@@ -0,0 +1,5 @@
+import { InjectionToken } from '@angular/core';
+
+export const REPORT_PAGE_SIZE = new InjectionToken<number>('report.pageSize');
+
+export const PLUGINS = new InjectionToken<string[]>('plugins');
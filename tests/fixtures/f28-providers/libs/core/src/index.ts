@@ -0,0 +1,5 @@
+export * from './lib/session.service';
+export * from './lib/cart.service';
+export * from './lib/audit.service';
+export * from './lib/logger.service';
+export * from './lib/metrics.service';
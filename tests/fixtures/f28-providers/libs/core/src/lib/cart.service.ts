@@ -0,0 +1,7 @@
+import { Injectable } from '@angular/core';
+import { LoggerService } from './logger.service';
+
+@Injectable()
+export class CartService {
+  constructor(private readonly logger: LoggerService) {}
+}
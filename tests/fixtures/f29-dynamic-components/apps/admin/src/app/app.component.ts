@@ -0,0 +1,12 @@
+import { Component } from '@angular/core';
+import { DashboardComponent } from './dashboard.component';
+import { HostComponent } from './host.component';
+import { OrdersComponent } from './orders.component';
+
+@Component({
+  selector: 'fix-root',
+  standalone: true,
+  imports: [DashboardComponent, HostComponent, OrdersComponent],
+  template: `<fix-orders></fix-orders><fix-host></fix-host><fix-dashboard></fix-dashboard>`,
+})
+export class AppComponent {}
@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-chart',
+  standalone: true,
+  template: `<canvas></canvas>`,
+})
+export class ChartComponent {}
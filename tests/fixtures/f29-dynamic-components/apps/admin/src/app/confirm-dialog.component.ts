@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-confirm-dialog',
+  standalone: true,
+  template: `<p>Are you sure?</p>`,
+})
+export class ConfirmDialogComponent {}
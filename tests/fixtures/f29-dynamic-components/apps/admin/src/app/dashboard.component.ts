@@ -0,0 +1,13 @@
+import { NgComponentOutlet } from '@angular/common';
+import { Component } from '@angular/core';
+import { SummaryCardComponent } from './summary-card.component';
+
+@Component({
+  selector: 'fix-dashboard',
+  standalone: true,
+  imports: [NgComponentOutlet, SummaryCardComponent],
+  template: `<ng-container *ngComponentOutlet="card"></ng-container>`,
+})
+export class DashboardComponent {
+  card = SummaryCardComponent;
+}
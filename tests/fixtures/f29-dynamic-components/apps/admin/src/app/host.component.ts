@@ -0,0 +1,16 @@
+import { AfterViewInit, Component, ViewChild, ViewContainerRef } from '@angular/core';
+import { ChartComponent } from './chart.component';
+
+@Component({
+  selector: 'fix-host',
+  standalone: true,
+  imports: [ChartComponent],
+  template: `<ng-template #slot></ng-template>`,
+})
+export class HostComponent implements AfterViewInit {
+  @ViewChild('slot', { read: ViewContainerRef }) slot!: ViewContainerRef;
+
+  ngAfterViewInit() {
+    this.slot.createComponent(ChartComponent);
+  }
+}
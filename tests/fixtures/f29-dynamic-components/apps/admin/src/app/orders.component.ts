@@ -0,0 +1,25 @@
+import { Component } from '@angular/core';
+import { MatDialog } from '@angular/material/dialog';
+import { ConfirmDialogComponent } from './confirm-dialog.component';
+import { openSidePanel } from './panels';
+import { SidePanelComponent } from './side-panel.component';
+
+// Both components are only ever created in code: the dialog through
+// MatDialog, the side panel through a custom opener from nx-analyzer.json.
+@Component({
+  selector: 'fix-orders',
+  standalone: true,
+  imports: [ConfirmDialogComponent, SidePanelComponent],
+  template: `<button (click)="cancel()">Cancel order</button>`,
+})
+export class OrdersComponent {
+  constructor(private readonly dialog: MatDialog) {}
+
+  cancel() {
+    this.dialog.open(ConfirmDialogComponent, { width: '400px' });
+  }
+
+  details() {
+    openSidePanel(SidePanelComponent);
+  }
+}
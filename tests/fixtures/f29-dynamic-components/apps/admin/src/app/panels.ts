@@ -0,0 +1,3 @@
+export function openSidePanel(component: unknown): void {
+  console.log('opening', component);
+}
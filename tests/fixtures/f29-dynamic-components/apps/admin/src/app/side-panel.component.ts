@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-side-panel',
+  standalone: true,
+  template: `<aside>Details</aside>`,
+})
+export class SidePanelComponent {}
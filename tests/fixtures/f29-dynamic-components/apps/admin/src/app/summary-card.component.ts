@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-summary-card',
+  standalone: true,
+  template: `<p>Summary</p>`,
+})
+export class SummaryCardComponent {}
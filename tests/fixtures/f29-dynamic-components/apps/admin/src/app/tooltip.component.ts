@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-tooltip',
+  standalone: true,
+  template: `<span>Tip</span>`,
+})
+export class TooltipComponent {}
@@ -0,0 +1,13 @@
+import { Overlay } from '@angular/cdk/overlay';
+import { ComponentPortal } from '@angular/cdk/portal';
+import { Injectable } from '@angular/core';
+import { TooltipComponent } from './tooltip.component';
+
+@Injectable({ providedIn: 'root' })
+export class TooltipService {
+  constructor(private readonly overlay: Overlay) {}
+
+  show() {
+    this.overlay.create().attach(new ComponentPortal(TooltipComponent));
+  }
+}
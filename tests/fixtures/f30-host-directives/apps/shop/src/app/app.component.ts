@@ -0,0 +1,12 @@
+import { Component } from '@angular/core';
+import { ButtonComponent } from '@fix/ui';
+
+@Component({
+  selector: 'fix-root',
+  standalone: true,
+  imports: [ButtonComponent],
+  template: `<ui-button tone="primary" logLevel="debug" (tracked)="onTracked()">Buy</ui-button>`,
+})
+export class AppComponent {
+  onTracked() {}
+}
@@ -0,0 +1,2 @@
+export * from './lib/button.component';
+export * from './lib/card.component';
@@ -0,0 +1,16 @@
+import { Component } from '@angular/core';
+import { HighlightDirective } from './highlight.directive';
+import { TrackClickDirective } from './track-click.directive';
+
+// `tone` on <ui-button> binds HighlightDirective's `color`; TrackClick
+// forwards LogDirective's `logLevel`, which the button forwards again.
+@Component({
+  selector: 'ui-button',
+  standalone: true,
+  hostDirectives: [
+    { directive: HighlightDirective, inputs: ['color: tone'] },
+    { directive: TrackClickDirective, inputs: ['logLevel'], outputs: ['tracked'] },
+  ],
+  template: `<button><ng-content></ng-content></button>`,
+})
+export class ButtonComponent {}
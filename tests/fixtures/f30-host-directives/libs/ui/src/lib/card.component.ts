@@ -0,0 +1,12 @@
+import { Component } from '@angular/core';
+import { FocusDirective } from './focus.directive';
+
+// Exported, but no template renders <ui-card> — so FocusDirective is wired
+// up without ever being applied.
+@Component({
+  selector: 'ui-card',
+  standalone: true,
+  hostDirectives: [FocusDirective],
+  template: `<ng-content></ng-content>`,
+})
+export class CardComponent {}
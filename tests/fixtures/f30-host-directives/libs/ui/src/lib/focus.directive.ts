@@ -0,0 +1,7 @@
+import { Directive } from '@angular/core';
+
+@Directive({
+  selector: '[uiFocus]',
+  standalone: true,
+})
+export class FocusDirective {}
@@ -0,0 +1,9 @@
+import { Directive, Input } from '@angular/core';
+
+@Directive({
+  selector: '[uiHighlight]',
+  standalone: true,
+})
+export class HighlightDirective {
+  @Input() color = 'yellow';
+}
@@ -0,0 +1,9 @@
+import { Directive, Input } from '@angular/core';
+
+@Directive({
+  selector: '[uiLog]',
+  standalone: true,
+})
+export class LogDirective {
+  @Input() logLevel = 'info';
+}
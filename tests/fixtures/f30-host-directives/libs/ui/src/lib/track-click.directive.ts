@@ -0,0 +1,11 @@
+import { Directive, EventEmitter, Output } from '@angular/core';
+import { LogDirective } from './log.directive';
+
+@Directive({
+  selector: '[uiTrackClick]',
+  standalone: true,
+  hostDirectives: [{ directive: LogDirective, inputs: ['logLevel'] }],
+})
+export class TrackClickDirective {
+  @Output() tracked = new EventEmitter<void>();
+}
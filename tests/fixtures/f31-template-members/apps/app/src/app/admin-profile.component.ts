@@ -0,0 +1,10 @@
+import { Component } from '@angular/core';
+import { ProfileComponent } from './profile.component';
+
+// Members come from the base class — not checked.
+@Component({
+  selector: 'fix-admin-profile',
+  standalone: true,
+  template: `<p>{{ name() }} ({{ role }})</p>`,
+})
+export class AdminProfileComponent extends ProfileComponent {}
@@ -0,0 +1,49 @@
+import {
+  ChangeDetectorRef,
+  Component,
+  HostListener,
+  Input,
+  OnInit,
+  signal,
+} from '@angular/core';
+
+@Component({
+  selector: 'fix-profile',
+  standalone: true,
+  templateUrl: './profile.component.html',
+  host: { '[class.active]': 'active' },
+})
+export class ProfileComponent implements OnInit {
+  @Input() userId = '';
+  readonly name = signal('Ada');
+  active = true;
+  showAvatar = true;
+  tags: string[] = [];
+  protected editing = false;
+  // The banner was removed from the template; the field stayed.
+  protected legacyBanner = 'Welcome back';
+  private renders = 0;
+
+  constructor(private readonly cdr: ChangeDetectorRef) {}
+
+  ngOnInit() {
+    this.reset();
+  }
+
+  reset() {
+    this.editing = false;
+    this.renders++;
+    this.cdr.markForCheck();
+  }
+
+  toggle() {
+    this.editing = !this.editing;
+  }
+
+  oldHelper() {
+    return this.tags.length;
+  }
+
+  @HostListener('window:resize')
+  onResize() {}
+}
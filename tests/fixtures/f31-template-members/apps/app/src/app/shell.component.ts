@@ -0,0 +1,11 @@
+import { Component } from '@angular/core';
+import { AdminProfileComponent } from './admin-profile.component';
+import { ProfileComponent } from './profile.component';
+
+@Component({
+  selector: 'fix-shell',
+  standalone: true,
+  imports: [AdminProfileComponent, ProfileComponent],
+  template: `<fix-profile userId="1"></fix-profile><fix-admin-profile></fix-admin-profile>`,
+})
+export class ShellComponent {}
@@ -0,0 +1,4 @@
+import { bootstrapApplication } from '@angular/platform-browser';
+import { ShellComponent } from './app/shell.component';
+
+bootstrapApplication(ShellComponent);
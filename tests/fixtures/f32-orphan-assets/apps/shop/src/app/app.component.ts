@@ -0,0 +1,12 @@
+import { Component } from '@angular/core';
+import heroUrl from './hero.webp';
+
+@Component({
+  selector: 'fix-root',
+  standalone: true,
+  templateUrl: './app.component.html',
+  styleUrl: './app.component.scss',
+})
+export class AppComponent {
+  protected readonly hero = heroUrl;
+}
@@ -0,0 +1,10 @@
+import { Component } from '@angular/core';
+import { ButtonComponent } from '@fix/ui';
+
+@Component({
+  selector: 'fix-root',
+  standalone: true,
+  imports: [ButtonComponent],
+  template: `<fix-button>Buy</fix-button>`,
+})
+export class AppComponent {}
@@ -0,0 +1,9 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-button',
+  standalone: true,
+  template: `<button><ng-content></ng-content></button>`,
+  styleUrl: './button.component.scss',
+})
+export class ButtonComponent {}
@@ -0,0 +1,29 @@
+import { Component, inject } from '@angular/core';
+import { TranslateModule, TranslateService } from '@ngx-translate/core';
+import { TranslocoDirective } from '@jsverse/transloco';
+import { ProfileComponent } from '@fix/profile';
+
+@Component({
+  selector: 'fix-root',
+  standalone: true,
+  imports: [TranslateModule, TranslocoDirective, ProfileComponent],
+  template: `
+    <h1>{{ 'home.title' | translate }}</h1>
+    <p [translate]="'home.subtitle'"></p>
+    <ng-container *transloco="let t; read: 'cart'">
+      <span>{{ t('total') }}</span>
+    </ng-container>
+    <fix-profile />
+  `,
+})
+export class AppComponent {
+  private translate = inject(TranslateService);
+
+  greet(): string {
+    return this.translate.instant('greeting');
+  }
+
+  error(code: string): string {
+    return this.translate.instant(`errors.${code}`);
+  }
+}
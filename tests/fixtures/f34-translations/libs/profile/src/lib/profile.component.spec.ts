@@ -0,0 +1,7 @@
+import { translate } from '@jsverse/transloco';
+
+describe('ProfileComponent', () => {
+  it('renders the legacy banner', () => {
+    expect(translate('legacy.banner')).toBeTruthy();
+  });
+});
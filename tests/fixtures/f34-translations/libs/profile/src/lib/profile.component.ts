@@ -0,0 +1,12 @@
+import { Component } from '@angular/core';
+import { translate } from '@jsverse/transloco';
+
+@Component({
+  selector: 'fix-profile',
+  standalone: true,
+  template: `<label>{{ nameLabel }}</label>`,
+})
+export class ProfileComponent {
+  nameLabel = translate('profile.name');
+  avatarLabel = translate('profile.avatar');
+}
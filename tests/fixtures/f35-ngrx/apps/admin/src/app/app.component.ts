@@ -0,0 +1,27 @@
+import { AsyncPipe } from '@angular/common';
+import { Component, inject } from '@angular/core';
+import { Store } from '@ngrx/store';
+import { UsersActions, resetFilter, selectUserAt, selectUserCount } from '@fix/users-state';
+
+@Component({
+  selector: 'fix-root',
+  standalone: true,
+  imports: [AsyncPipe],
+  template: `
+    <p>{{ count() }} users, first: {{ first$ | async }}</p>
+    <button (click)="reset()">Reset</button>
+  `,
+})
+export class AppComponent {
+  private store = inject(Store);
+  count = this.store.selectSignal(selectUserCount);
+  first$ = this.store.select(selectUserAt(0));
+
+  constructor() {
+    this.store.dispatch(UsersActions.loadUsers());
+  }
+
+  reset(): void {
+    this.store.dispatch(resetFilter());
+  }
+}
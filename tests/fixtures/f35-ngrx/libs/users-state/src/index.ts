@@ -0,0 +1,4 @@
+export * from './lib/users.actions';
+export * from './lib/users.effects';
+export * from './lib/users.reducer';
+export * from './lib/users.selectors';
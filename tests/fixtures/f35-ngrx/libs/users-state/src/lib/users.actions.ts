@@ -0,0 +1,14 @@
+import { createAction, createActionGroup, emptyProps, props } from '@ngrx/store';
+
+export const UsersActions = createActionGroup({
+  source: 'Users',
+  events: {
+    'Load Users': emptyProps(),
+    'Load Users Success': props<{ users: string[] }>(),
+    'Load Users Failure': props<{ error: string }>(),
+    'Clear Users': emptyProps(),
+  },
+});
+
+export const resetFilter = createAction('[Users] Reset Filter');
+export const legacyRefresh = createAction('[Users] Legacy Refresh');
@@ -0,0 +1,28 @@
+import { Injectable, inject } from '@angular/core';
+import { Actions, createEffect, ofType } from '@ngrx/effects';
+import { catchError, map, of, switchMap } from 'rxjs';
+import { UsersActions, legacyRefresh } from './users.actions';
+
+@Injectable()
+export class UsersEffects {
+  private actions$ = inject(Actions);
+
+  load$ = createEffect(() =>
+    this.actions$.pipe(
+      ofType(UsersActions.loadUsers),
+      switchMap(() =>
+        of(['ada', 'grace']).pipe(
+          map((users) => UsersActions.loadUsersSuccess({ users })),
+          catchError((error) => of(UsersActions.loadUsersFailure({ error: String(error) }))),
+        ),
+      ),
+    ),
+  );
+
+  refresh$ = createEffect(() =>
+    this.actions$.pipe(
+      ofType(legacyRefresh),
+      map(() => UsersActions.loadUsers()),
+    ),
+  );
+}
@@ -0,0 +1,11 @@
+import { Store } from '@ngrx/store';
+import { UsersActions } from './users.actions';
+import { selectError } from './users.selectors';
+
+describe('usersReducer', () => {
+  it('clears users', () => {
+    const store = {} as Store;
+    store.dispatch(UsersActions.clearUsers());
+    store.select(selectError);
+  });
+});
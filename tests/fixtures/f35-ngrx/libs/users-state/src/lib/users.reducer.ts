@@ -0,0 +1,18 @@
+import { createReducer, on } from '@ngrx/store';
+import { UsersActions, resetFilter } from './users.actions';
+
+export interface UsersState {
+  users: string[];
+  filter: string;
+  error: string | null;
+}
+
+export const initialState: UsersState = { users: [], filter: '', error: null };
+
+export const usersReducer = createReducer(
+  initialState,
+  on(UsersActions.loadUsersSuccess, (state, { users }) => ({ ...state, users })),
+  on(UsersActions.loadUsersFailure, (state, { error }) => ({ ...state, error })),
+  on(UsersActions.clearUsers, (state) => ({ ...state, users: [] })),
+  on(resetFilter, (state) => ({ ...state, filter: '' })),
+);
@@ -0,0 +1,10 @@
+import { createFeatureSelector, createSelector } from '@ngrx/store';
+import { UsersState } from './users.reducer';
+
+export const selectUsersState = createFeatureSelector<UsersState>('users');
+export const selectUsers = createSelector(selectUsersState, (state) => state.users);
+export const selectUserCount = createSelector(selectUsers, (users) => users.length);
+export const selectUserAt = (index: number) =>
+  createSelector(selectUsers, (users) => users[index]);
+export const selectFilter = createSelector(selectUsersState, (state) => state.filter);
+export const selectError = createSelector(selectUsersState, (state) => state.error);
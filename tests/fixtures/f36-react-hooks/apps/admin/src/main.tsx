@@ -0,0 +1,6 @@
+import { useCart as useBasket } from '@fix/hooks';
+
+export function Orders() {
+  const basket = useBasket();
+  return <ul>{basket.items.map((item) => <li key={item}>{item}</li>)}</ul>;
+}
@@ -0,0 +1,16 @@
+import { useBreakpoint, useCart } from '@fix/hooks';
+
+export function useTitle(): string {
+  return 'Shop';
+}
+
+export function App() {
+  const cart = useCart();
+  const layout = useBreakpoint();
+  return (
+    <main className={layout}>
+      <h1>{useTitle()}</h1>
+      <p>{cart.items.length} items</p>
+    </main>
+  );
+}
@@ -0,0 +1,5 @@
+import { App } from './app/app';
+
+export function mount(): unknown {
+  return <App />;
+}
@@ -0,0 +1,4 @@
+export * from './lib/use-cart';
+export * from './lib/use-breakpoint';
+export * from './lib/use-debounce';
+export * from './lib/use-storage';
@@ -0,0 +1,10 @@
+import { useEffect, useState } from 'react';
+
+export function useMediaQuery(query: string): boolean {
+  const [matches, setMatches] = useState(false);
+  useEffect(() => setMatches(window.matchMedia(query).matches), [query]);
+  return matches;
+}
+
+export const useBreakpoint = (): 'mobile' | 'desktop' =>
+  useMediaQuery('(max-width: 600px)') ? 'mobile' : 'desktop';
@@ -0,0 +1,6 @@
+import { useState } from 'react';
+
+export function useCart(): { items: string[]; add: (item: string) => void } {
+  const [items, setItems] = useState<string[]>([]);
+  return { items, add: (item) => setItems([...items, item]) };
+}
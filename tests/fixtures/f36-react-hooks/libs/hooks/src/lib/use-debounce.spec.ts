@@ -0,0 +1,7 @@
+import { useDebounce } from './use-debounce';
+
+describe('useDebounce', () => {
+  it('returns the value', () => {
+    expect(useDebounce(1)).toBe(1);
+  });
+});
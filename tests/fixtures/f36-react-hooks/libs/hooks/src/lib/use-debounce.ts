@@ -0,0 +1,3 @@
+export function useDebounce<T>(value: T): T {
+  return value;
+}
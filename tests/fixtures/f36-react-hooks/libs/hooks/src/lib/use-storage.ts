@@ -0,0 +1,7 @@
+export function useStorage(key: string): string | null {
+  return localStorage.getItem(key);
+}
+
+export function useLegacyStorage(): string | null {
+  return useStorage('legacy');
+}
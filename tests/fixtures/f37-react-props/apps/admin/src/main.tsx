@@ -0,0 +1,10 @@
+import { Avatar, Button } from '@fix/ui';
+
+export function mount(): unknown {
+  return (
+    <div>
+      <Avatar alt="Admin" />
+      <Button variant="ghost" />
+    </div>
+  );
+}
@@ -0,0 +1,17 @@
+import { Avatar, Badge, Button, Dialog, Panel, Tag } from '@fix/ui';
+
+const user = { src: 'me.png', alt: 'Me' };
+
+export function App() {
+  return (
+    <Panel heading="Cart">
+      <Avatar {...user} />
+      <Badge count={3} />
+      <Tag text="new" />
+      <Dialog title="Checkout">
+        <p>Ready?</p>
+      </Dialog>
+      <Button label="Buy" onClick={() => undefined} />
+    </Panel>
+  );
+}
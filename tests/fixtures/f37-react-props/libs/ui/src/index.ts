@@ -0,0 +1,6 @@
+export * from './lib/avatar';
+export * from './lib/badge';
+export * from './lib/button';
+export * from './lib/dialog';
+export * from './lib/panel';
+export * from './lib/tag';
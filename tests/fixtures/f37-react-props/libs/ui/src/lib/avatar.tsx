@@ -0,0 +1,11 @@
+import React from 'react';
+
+type AvatarProps = {
+  src: string;
+  alt?: string;
+  size?: number;
+};
+
+export const Avatar: React.FC<AvatarProps> = ({ src, alt, size }) => (
+  <img src={src} alt={alt} width={size} />
+);
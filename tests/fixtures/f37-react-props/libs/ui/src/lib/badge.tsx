@@ -0,0 +1,3 @@
+export function Badge({ count, max = 99 }: { count: number; max: number }) {
+  return <span>{count > max ? `${max}+` : count}</span>;
+}
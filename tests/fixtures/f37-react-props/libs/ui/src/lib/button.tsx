@@ -0,0 +1,14 @@
+export interface ButtonProps {
+  label: string;
+  variant?: 'primary' | 'ghost';
+  onClick?: () => void;
+  disabled?: boolean;
+}
+
+export function Button({ label, variant = 'primary', onClick, disabled }: ButtonProps) {
+  return (
+    <button className={variant} onClick={onClick} disabled={disabled}>
+      {label}
+    </button>
+  );
+}
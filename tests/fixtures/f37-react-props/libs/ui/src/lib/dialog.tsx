@@ -0,0 +1,17 @@
+import { forwardRef, ReactNode } from 'react';
+
+interface BaseProps {
+  id?: string;
+}
+
+interface DialogProps extends BaseProps {
+  title: string;
+  children: ReactNode;
+}
+
+export const Dialog = forwardRef<HTMLDivElement, DialogProps>((props, ref) => (
+  <div id={props.id} ref={ref}>
+    <h2>{props.title}</h2>
+    {props.children}
+  </div>
+));
@@ -0,0 +1,5 @@
+import { PanelProps } from './panel.types';
+
+export function Panel({ heading }: PanelProps) {
+  return <section>{heading}</section>;
+}
@@ -0,0 +1,4 @@
+export interface PanelProps {
+  heading: string;
+  collapsed?: boolean;
+}
@@ -0,0 +1 @@
+export const Tag = ({ text, tone }) => <em className={tone}>{text}</em>;
@@ -0,0 +1,21 @@
+import * as UI from '@fix/ui';
+import { Card as Panel, Form, Tabs } from '@fix/ui';
+
+const motion = { div: 'div' };
+
+export function App() {
+  return (
+    <Tabs>
+      <Tabs.Tab label="Account" />
+      <Form>
+        <Form.Field name="email" />
+        <Form.Label text="Email" />
+        <UI.Form.Field name="password" />
+      </Form>
+      <Panel.Header />
+      <Panel.Body />
+      <UI.Button variant="primary" />
+      <motion.div />
+    </Tabs>
+  );
+}
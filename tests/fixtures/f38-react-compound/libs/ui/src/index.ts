@@ -0,0 +1,4 @@
+export * from './lib/button';
+export * from './lib/card';
+export * from './lib/form';
+export * from './lib/tabs';
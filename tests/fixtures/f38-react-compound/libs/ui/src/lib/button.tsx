@@ -0,0 +1,3 @@
+export function Button({ variant }: { variant: string }) {
+  return <button className={variant} />;
+}
@@ -0,0 +1,9 @@
+function CardHeader() {
+  return <header />;
+}
+
+function CardBody() {
+  return <main />;
+}
+
+export const Card = { Header: CardHeader, Body: CardBody };
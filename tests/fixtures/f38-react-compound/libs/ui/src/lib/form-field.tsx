@@ -0,0 +1,3 @@
+export function FormField({ name }: { name: string }) {
+  return <input name={name} />;
+}
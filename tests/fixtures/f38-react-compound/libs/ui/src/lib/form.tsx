@@ -0,0 +1,12 @@
+import { ReactNode } from 'react';
+import { FormField } from './form-field';
+
+function FormRoot({ children }: { children: ReactNode }) {
+  return <form>{children}</form>;
+}
+
+function FormLabel({ text }: { text: string }) {
+  return <label>{text}</label>;
+}
+
+export const Form = Object.assign(FormRoot, { Field: FormField, Label: FormLabel });
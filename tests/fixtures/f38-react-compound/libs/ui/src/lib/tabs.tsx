@@ -0,0 +1,11 @@
+import { ReactNode } from 'react';
+
+export function Tabs({ children }: { children: ReactNode }) {
+  return <div role="tablist">{children}</div>;
+}
+
+function Tab({ label }: { label: string }) {
+  return <button role="tab">{label}</button>;
+}
+
+Tabs.Tab = Tab;
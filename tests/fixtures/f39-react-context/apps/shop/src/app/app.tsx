@@ -0,0 +1,16 @@
+import { LocaleContext, ThemeProvider } from '@fix/state';
+import { Header } from './header';
+import { Page } from './page';
+import { Sidebar } from './sidebar';
+
+export function App() {
+  return (
+    <LocaleContext value="fr">
+      <Header />
+      <ThemeProvider>
+        <Page />
+      </ThemeProvider>
+      <Sidebar />
+    </LocaleContext>
+  );
+}
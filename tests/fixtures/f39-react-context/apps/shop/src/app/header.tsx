@@ -0,0 +1,7 @@
+import { use } from 'react';
+import { SessionContext } from '@fix/state';
+
+export function Header() {
+  const session = use(SessionContext);
+  return <header>{session?.user}</header>;
+}
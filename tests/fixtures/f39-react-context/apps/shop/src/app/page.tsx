@@ -0,0 +1,19 @@
+import { ThemeContext, useTheme } from '@fix/state';
+
+function Card() {
+  const theme = useTheme();
+  return <div className={theme} />;
+}
+
+function Footer() {
+  return <ThemeContext.Consumer>{(theme) => <footer className={theme} />}</ThemeContext.Consumer>;
+}
+
+export function Page() {
+  return (
+    <main>
+      <Card />
+      <Footer />
+    </main>
+  );
+}
@@ -0,0 +1,6 @@
+import { useTheme } from '@fix/state';
+
+export function Sidebar() {
+  const theme = useTheme();
+  return <aside className={theme} />;
+}
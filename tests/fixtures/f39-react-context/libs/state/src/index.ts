@@ -0,0 +1,3 @@
+export * from './lib/locale';
+export * from './lib/session';
+export * from './lib/theme';
@@ -0,0 +1,7 @@
+import { createContext } from 'react';
+
+export interface Session {
+  user: string;
+}
+
+export const SessionContext = createContext<Session | null>(null);
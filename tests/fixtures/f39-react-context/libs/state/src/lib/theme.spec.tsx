@@ -0,0 +1,8 @@
+import { ThemeContext } from './theme';
+import { Sidebar } from '../../../../apps/shop/src/app/sidebar';
+
+export const rendered = (
+  <ThemeContext.Provider value="test">
+    <Sidebar />
+  </ThemeContext.Provider>
+);
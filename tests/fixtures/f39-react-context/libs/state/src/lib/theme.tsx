@@ -0,0 +1,11 @@
+import { createContext, ReactNode, useContext } from 'react';
+
+export const ThemeContext = createContext('light');
+
+export function ThemeProvider({ children }: { children: ReactNode }) {
+  return <ThemeContext.Provider value="dark">{children}</ThemeContext.Provider>;
+}
+
+export function useTheme() {
+  return useContext(ThemeContext);
+}
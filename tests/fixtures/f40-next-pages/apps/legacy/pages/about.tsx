@@ -0,0 +1,7 @@
+export async function getServerSideProps() {
+  return { props: {} };
+}
+
+export default function About() {
+  return <p>About</p>;
+}
@@ -0,0 +1,7 @@
+export function reportWebVitals(metric: unknown) {
+  console.log(metric);
+}
+
+export default function App({ Component, pageProps }: { Component: any; pageProps: object }) {
+  return <Component {...pageProps} />;
+}
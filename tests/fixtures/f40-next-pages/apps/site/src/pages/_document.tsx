@@ -0,0 +1,3 @@
+export default function Document() {
+  return <html lang="en" />;
+}
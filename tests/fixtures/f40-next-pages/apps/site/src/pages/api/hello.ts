@@ -0,0 +1,5 @@
+export const config = { api: { bodyParser: false } };
+
+export default function handler(req: unknown, res: { status(code: number): { json(body: object): void } }) {
+  res.status(200).json({ req: String(req) });
+}
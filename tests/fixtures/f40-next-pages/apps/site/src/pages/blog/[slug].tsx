@@ -0,0 +1,11 @@
+export async function getStaticPaths() {
+  return { paths: [], fallback: 'blocking' };
+}
+
+export async function getStaticProps({ params }: { params: { slug: string } }) {
+  return { props: { slug: params.slug } };
+}
+
+export default function Post({ slug }: { slug: string }) {
+  return <article>{slug}</article>;
+}
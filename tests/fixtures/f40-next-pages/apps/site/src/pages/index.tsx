@@ -0,0 +1,15 @@
+import { Button } from '@fix/ui';
+
+export async function getStaticProps() {
+  return { props: { title: formatTitle('home') } };
+}
+
+export function formatTitle(name: string) {
+  return name.toUpperCase();
+}
+
+export const unusedHelper = () => 1;
+
+export default function Home({ title }: { title: string }) {
+  return <Button label={title} />;
+}
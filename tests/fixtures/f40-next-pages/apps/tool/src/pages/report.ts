@@ -0,0 +1,3 @@
+export function buildReport() {
+  return 'report';
+}
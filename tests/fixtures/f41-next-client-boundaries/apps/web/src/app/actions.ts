@@ -0,0 +1,7 @@
+'use server';
+
+import { saveToDisk } from './lib/db';
+
+export async function saveNote(text: string) {
+  await saveToDisk(text);
+}
@@ -0,0 +1,16 @@
+'use client';
+
+import { Chart } from '@fix/charts';
+import { saveNote } from '../actions';
+import { formatDate } from '../lib/format';
+import type { User } from '../lib/db';
+
+export function Dashboard({ user, count }: { user: User; count: number }) {
+  return (
+    <section>
+      <h1>{formatDate(user.joined)}</h1>
+      <Chart points={count} />
+      <button onClick={() => saveNote(user.name)}>Save</button>
+    </section>
+  );
+}
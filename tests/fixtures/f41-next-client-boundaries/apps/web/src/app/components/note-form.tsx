@@ -0,0 +1,9 @@
+'use client';
+
+export function NoteForm() {
+  async function save(data: FormData) {
+    'use server';
+    console.log(data);
+  }
+  return <form action={save} />;
+}
@@ -0,0 +1,6 @@
+import 'server-only';
+import { readFileSync } from 'node:fs';
+
+export function locale() {
+  return readFileSync('locale.txt', 'utf8');
+}
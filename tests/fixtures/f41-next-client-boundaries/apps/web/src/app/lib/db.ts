@@ -0,0 +1,15 @@
+import 'server-only';
+import { writeFile } from 'fs/promises';
+
+export interface User {
+  name: string;
+  joined: Date;
+}
+
+export async function getUser(): Promise<User> {
+  return { name: 'ada', joined: new Date() };
+}
+
+export async function saveToDisk(text: string) {
+  await writeFile('notes.txt', text);
+}
@@ -0,0 +1,5 @@
+import { locale } from './config';
+
+export function formatDate(date: Date) {
+  return date.toLocaleDateString(locale());
+}
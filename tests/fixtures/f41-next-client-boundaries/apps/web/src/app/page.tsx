@@ -0,0 +1,16 @@
+import { readdirSync } from 'fs';
+import { Dashboard } from './components/dashboard';
+import { NoteForm } from './components/note-form';
+import { getUser } from './lib/db';
+
+// A server component: Node built-ins are fine here.
+export default async function Page() {
+  const user = await getUser();
+  const files = readdirSync('.');
+  return (
+    <main>
+      <Dashboard user={user} count={files.length} />
+      <NoteForm />
+    </main>
+  );
+}
@@ -0,0 +1,8 @@
+import path from 'path';
+import type { Stats } from 'fs';
+import { log } from './logger';
+
+export function Chart({ points, stats }: { points: number; stats?: Stats }) {
+  log(path.join('chart', String(points)));
+  return <svg data-size={stats?.size} />;
+}
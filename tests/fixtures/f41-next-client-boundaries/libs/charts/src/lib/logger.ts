@@ -0,0 +1,5 @@
+import { execSync } from 'child_process';
+
+export function log(message: string) {
+  execSync(`logger ${message}`);
+}
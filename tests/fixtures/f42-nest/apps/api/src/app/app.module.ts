@@ -0,0 +1,18 @@
+import { Controller, Get, Module } from '@nestjs/common';
+import { AuthModule } from '@fix/auth';
+import { HealthController } from './health/health.controller';
+import { UsersModule } from './users/users.module';
+
+@Controller({ path: 'status' })
+export class StatusController {
+  @Get()
+  status() {
+    return 'up';
+  }
+}
+
+@Module({
+  imports: [AuthModule.forRoot({ secret: 'x' }), UsersModule],
+  controllers: [HealthController, StatusController],
+})
+export class AppModule {}
@@ -0,0 +1,10 @@
+import { All, Controller } from '@nestjs/common';
+
+// Never added to a module: Nest does not serve it.
+@Controller('legacy')
+export class LegacyController {
+  @All('*')
+  fallback() {
+    return 'gone';
+  }
+}
@@ -0,0 +1,29 @@
+import { Body, Controller, Delete, Get, Param, Post, UseGuards } from '@nestjs/common';
+import { AuthGuard } from '@fix/auth';
+import { UsersService } from './users.service';
+
+@Controller('users')
+@UseGuards(AuthGuard)
+export class UsersController {
+  constructor(private readonly users: UsersService) {}
+
+  @Get()
+  findAll() {
+    return this.users.findAll();
+  }
+
+  @Get(':id')
+  findOne(@Param('id') id: string) {
+    return this.users.findOne(id);
+  }
+
+  @Post()
+  create(@Body() body: { name: string }) {
+    return this.users.create(body.name);
+  }
+
+  @Delete([':id', ':id/hard'])
+  remove(@Param('id') id: string) {
+    return this.users.remove(id);
+  }
+}
@@ -0,0 +1,14 @@
+import { Module } from '@nestjs/common';
+import { UsersController } from './users.controller';
+import { UsersService } from './users.service';
+import { USERS_REPOSITORY, InMemoryUsersRepository } from './users.repository';
+
+@Module({
+  controllers: [UsersController],
+  providers: [
+    UsersService,
+    { provide: USERS_REPOSITORY, useClass: InMemoryUsersRepository },
+  ],
+  exports: [UsersService],
+})
+export class UsersModule {}
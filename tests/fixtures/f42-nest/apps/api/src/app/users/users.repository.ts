@@ -0,0 +1,33 @@
+import { Injectable } from '@nestjs/common';
+
+export const USERS_REPOSITORY = 'USERS_REPOSITORY';
+
+export interface User {
+  id: string;
+  name: string;
+}
+
+export interface UsersRepository {
+  all(): User[];
+  add(name: string): User;
+  remove(id: string): void;
+}
+
+@Injectable()
+export class InMemoryUsersRepository implements UsersRepository {
+  private users: User[] = [];
+
+  all() {
+    return this.users;
+  }
+
+  add(name: string) {
+    const user = { id: String(this.users.length + 1), name };
+    this.users.push(user);
+    return user;
+  }
+
+  remove(id: string) {
+    this.users = this.users.filter((user) => user.id !== id);
+  }
+}
@@ -0,0 +1,23 @@
+import { Inject, Injectable } from '@nestjs/common';
+import { USERS_REPOSITORY, UsersRepository } from './users.repository';
+
+@Injectable()
+export class UsersService {
+  constructor(@Inject(USERS_REPOSITORY) private readonly repository: UsersRepository) {}
+
+  findAll() {
+    return this.repository.all();
+  }
+
+  findOne(id: string) {
+    return this.repository.all().find((user) => user.id === id);
+  }
+
+  create(name: string) {
+    return this.repository.add(name);
+  }
+
+  remove(id: string) {
+    return this.repository.remove(id);
+  }
+}
@@ -0,0 +1,4 @@
+export * from './lib/auth.module';
+export * from './lib/auth.guard';
+export * from './lib/token.service';
+export * from './lib/audit.service';
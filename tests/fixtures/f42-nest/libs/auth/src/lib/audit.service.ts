@@ -0,0 +1,9 @@
+import { Injectable } from '@nestjs/common';
+
+// Never registered in a module: Nest cannot inject it.
+@Injectable()
+export class AuditService {
+  log(message: string) {
+    return message;
+  }
+}
@@ -0,0 +1,11 @@
+import { CanActivate, Injectable } from '@nestjs/common';
+import { TokenService } from './token.service';
+
+@Injectable()
+export class AuthGuard implements CanActivate {
+  constructor(private readonly tokens: TokenService) {}
+
+  canActivate() {
+    return this.tokens.valid();
+  }
+}
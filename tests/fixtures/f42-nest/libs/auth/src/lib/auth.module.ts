@@ -0,0 +1,17 @@
+import { DynamicModule, Global, Module } from '@nestjs/common';
+import { AuthGuard } from './auth.guard';
+import { TokenService } from './token.service';
+
+@Global()
+@Module({
+  providers: [TokenService, AuthGuard],
+  exports: [TokenService, AuthGuard],
+})
+export class AuthModule {
+  static forRoot(options: { secret: string }): DynamicModule {
+    return {
+      module: AuthModule,
+      providers: [{ provide: 'AUTH_OPTIONS', useValue: options }],
+    };
+  }
+}
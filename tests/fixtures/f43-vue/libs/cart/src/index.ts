@@ -0,0 +1,3 @@
+export * from './lib/use-cart';
+export * from './lib/product-store';
+export * from './lib/format';
@@ -0,0 +1,5 @@
+import { defineStore } from 'pinia';
+
+export const useProductStore = defineStore('products', {
+  state: () => ({ products: [] as { id: string; name: string }[] }),
+});
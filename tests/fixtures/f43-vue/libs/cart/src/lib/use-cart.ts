@@ -0,0 +1,7 @@
+export function useCart() {
+  return { items: [] as string[] };
+}
+
+export function useWishlist() {
+  return { items: [] as string[] };
+}
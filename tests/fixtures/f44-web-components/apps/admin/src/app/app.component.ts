@@ -0,0 +1,12 @@
+import { Component, CUSTOM_ELEMENTS_SCHEMA } from '@angular/core';
+
+@Component({
+  selector: 'admin-root',
+  standalone: true,
+  schemas: [CUSTOM_ELEMENTS_SCHEMA],
+  template: `
+    <ui-button label="Save"></ui-button>
+    <ui-card><ui-button label="Cancel"></ui-button></ui-card>
+  `,
+})
+export class AppComponent {}
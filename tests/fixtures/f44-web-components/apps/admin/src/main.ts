@@ -0,0 +1,5 @@
+import { bootstrapApplication } from '@angular/platform-browser';
+import '@fix/ui-kit';
+import { AppComponent } from './app/app.component';
+
+bootstrapApplication(AppComponent, {});
@@ -0,0 +1,7 @@
+export function App() {
+  return (
+    <ui-card>
+      <ui-button label="Go"></ui-button>
+    </ui-card>
+  );
+}
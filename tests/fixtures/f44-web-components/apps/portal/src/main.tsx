@@ -0,0 +1,5 @@
+import { createRoot } from 'react-dom/client';
+import '@fix/ui-kit';
+import { App } from './app';
+
+createRoot(document.getElementById('root')!).render(<App />);
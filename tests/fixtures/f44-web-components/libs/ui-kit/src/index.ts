@@ -0,0 +1,4 @@
+export * from './lib/ui-badge';
+export * from './lib/ui-button';
+export * from './lib/ui-card';
+export * from './lib/ui-legacy';
@@ -0,0 +1,7 @@
+export class UiBadge extends HTMLElement {
+  connectedCallback() {
+    this.textContent = this.getAttribute('count');
+  }
+}
+
+customElements.define('ui-badge', UiBadge);
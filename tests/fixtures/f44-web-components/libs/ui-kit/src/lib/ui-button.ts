@@ -0,0 +1,11 @@
+import { LitElement, html } from 'lit';
+import { customElement, property } from 'lit/decorators.js';
+
+@customElement('ui-button')
+export class UiButton extends LitElement {
+  @property() label = '';
+
+  render() {
+    return html`<button>${this.label}</button>`;
+  }
+}
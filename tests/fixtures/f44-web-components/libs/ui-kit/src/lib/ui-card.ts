@@ -0,0 +1,9 @@
+import { LitElement, html } from 'lit';
+import { customElement } from 'lit/decorators.js';
+
+@customElement('ui-card')
+export class UiCard extends LitElement {
+  render() {
+    return html`<section><ui-badge count=${3}></ui-badge><slot></slot></section>`;
+  }
+}
@@ -0,0 +1,5 @@
+import { LitElement } from 'lit';
+import { customElement } from 'lit/decorators.js';
+
+@customElement('ui-legacy')
+export class UiLegacy extends LitElement {}
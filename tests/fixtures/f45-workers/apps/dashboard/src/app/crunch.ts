@@ -0,0 +1,7 @@
+export function crunch(values: number[]): number[] {
+  return [...values].sort((a, b) => a - b);
+}
+
+export function unusedSummary(values: number[]): number {
+  return values.length;
+}
@@ -0,0 +1,8 @@
+export function startDashboard() {
+  const worker = new Worker(new URL('./heavy.worker', import.meta.url), { type: 'module' });
+  worker.postMessage([3, 1, 2]);
+
+  const logo = document.createElement('img');
+  logo.src = new URL('../assets/logo.svg', import.meta.url).href;
+  document.body.append(logo);
+}
@@ -0,0 +1,5 @@
+import { crunch } from './crunch';
+
+addEventListener('message', ({ data }) => {
+  postMessage(crunch(data));
+});
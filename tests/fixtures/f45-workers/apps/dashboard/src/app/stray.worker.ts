@@ -0,0 +1 @@
+addEventListener('message', ({ data }) => postMessage(data));
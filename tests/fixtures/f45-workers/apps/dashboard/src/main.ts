@@ -0,0 +1,5 @@
+import { startDashboard } from './app/dashboard';
+
+navigator.serviceWorker.register(new URL('./sw', import.meta.url), { type: 'module' });
+
+startDashboard();
@@ -0,0 +1 @@
+export { Chart } from './lib/chart';
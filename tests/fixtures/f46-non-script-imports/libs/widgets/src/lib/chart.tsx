@@ -0,0 +1,12 @@
+import defaults from './data/defaults.json';
+import Logo from './logo.svg?react';
+import shader from './data/grid.svg?raw';
+import styles from './chart.module.css';
+
+export function Chart() {
+  return (
+    <figure className={styles.chart} data-grid={shader} data-size={defaults.size}>
+      <Logo />
+    </figure>
+  );
+}
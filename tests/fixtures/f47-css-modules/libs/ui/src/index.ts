@@ -0,0 +1,3 @@
+export { Card } from './lib/card';
+export { Button } from './lib/button';
+export { Badge } from './lib/badge';
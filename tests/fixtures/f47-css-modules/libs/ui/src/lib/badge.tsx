@@ -0,0 +1,5 @@
+import { badge } from './badge.module.css';
+
+export function Badge() {
+  return <span className={badge} />;
+}
@@ -0,0 +1,6 @@
+import * as styles from './button.module.css';
+
+export function Button({ variant }: { variant: 'primary' | 'ghost' }) {
+  const classes = { ...styles };
+  return <button className={classes[variant]} />;
+}
@@ -0,0 +1,13 @@
+import clsx from 'clsx';
+import styles from './card.module.scss';
+
+export function Card({ active, title }: { active: boolean; title: string }) {
+  return (
+    <section className={clsx(styles.card, active && styles['is-active'])}>
+      <h2 className={styles.title}>{title}</h2>
+      <span className={styles['sr-only']}>{title}</span>
+      <footer className={styles.cardFooter} />
+      <aside className={styles.sidebar} />
+    </section>
+  );
+}
@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'shop-a',
+  standalone: true,
+  template: `<p>a</p>`,
+})
+export class AComponent {}
@@ -0,0 +1,9 @@
+import { AComponent } from './a.component';
+
+export const routes = [
+  { path: '', component: AComponent },
+  {
+    path: 'b',
+    loadChildren: () => import('./b.routes').then((m) => m.routes),
+  },
+];
@@ -0,0 +1,10 @@
+export const appRoutes = [
+  {
+    path: 'a',
+    loadChildren: () => import('./a.routes').then((m) => m.routes),
+  },
+  {
+    path: 'c',
+    loadChildren: () => import('./c').then((m) => m.routes),
+  },
+];
@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'shop-b',
+  standalone: true,
+  template: `<p>b</p>`,
+})
+export class BComponent {}
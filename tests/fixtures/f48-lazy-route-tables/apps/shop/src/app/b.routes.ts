@@ -0,0 +1,10 @@
+import { BComponent } from './b.component';
+
+// Loads its own parent table back: a cycle.
+export const routes = [
+  { path: '', component: BComponent },
+  {
+    path: 'a',
+    loadChildren: () => import('./a.routes').then((m) => m.routes),
+  },
+];
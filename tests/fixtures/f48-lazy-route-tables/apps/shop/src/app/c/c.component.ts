@@ -0,0 +1,8 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'shop-c',
+  standalone: true,
+  template: `<p>c</p>`,
+})
+export class CComponent {}
@@ -0,0 +1,3 @@
+import { CComponent } from './c.component';
+
+export const routes = [{ path: '', component: CComponent }];
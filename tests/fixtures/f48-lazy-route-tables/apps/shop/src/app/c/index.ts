@@ -0,0 +1 @@
+export { routes } from './c.routes';
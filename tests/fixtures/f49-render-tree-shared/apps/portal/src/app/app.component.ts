@@ -0,0 +1,11 @@
+import { Component } from '@angular/core';
+import { LeftComponent } from './left.component';
+import { RightComponent } from './right.component';
+
+@Component({
+  selector: 'fix-app',
+  standalone: true,
+  imports: [LeftComponent, RightComponent],
+  template: `<fix-left></fix-left><fix-right></fix-right>`,
+})
+export class AppComponent {}
@@ -0,0 +1,9 @@
+import { Component } from '@angular/core';
+
+@Component({
+  selector: 'fix-leaf',
+  standalone: true,
+  imports: [],
+  template: `<span>leaf</span>`,
+})
+export class LeafComponent {}
@@ -0,0 +1,10 @@
+import { Component } from '@angular/core';
+import { SharedComponent } from './shared.component';
+
+@Component({
+  selector: 'fix-right',
+  standalone: true,
+  imports: [SharedComponent],
+  template: `<fix-shared></fix-shared>`,
+})
+export class RightComponent {}
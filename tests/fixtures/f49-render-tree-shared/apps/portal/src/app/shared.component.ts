@@ -0,0 +1,10 @@
+import { Component } from '@angular/core';
+import { LeafComponent } from './leaf.component';
+
+@Component({
+  selector: 'fix-shared',
+  standalone: true,
+  imports: [LeafComponent],
+  template: `<fix-leaf></fix-leaf>`,
+})
+export class SharedComponent {}
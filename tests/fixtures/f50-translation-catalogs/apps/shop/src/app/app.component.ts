@@ -0,0 +1,17 @@
+import { Component } from '@angular/core';
+import { TranslateModule } from '@ngx-translate/core';
+import { DialogComponent } from '@fix/ui';
+
+// `dialog.close` lives in the lib's catalog only; the app's catalog
+// overrides `dialog.title`.
+@Component({
+  selector: 'fix-root',
+  standalone: true,
+  imports: [TranslateModule, DialogComponent],
+  template: `
+    <h1>{{ 'app.title' | translate }}</h1>
+    <button>{{ 'dialog.close' | translate }}</button>
+    <fix-dialog />
+  `,
+})
+export class AppComponent {}
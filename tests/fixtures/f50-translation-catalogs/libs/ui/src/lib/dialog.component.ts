@@ -0,0 +1,14 @@
+import { Component } from '@angular/core';
+import { TranslateModule } from '@ngx-translate/core';
+
+@Component({
+  selector: 'fix-dialog',
+  standalone: true,
+  imports: [TranslateModule],
+  template: `
+    <h2>{{ 'dialog.title' | translate }}</h2>
+    <button>{{ 'dialog.close' | translate }}</button>
+    <button>{{ 'dialog.retry' | translate }}</button>
+  `,
+})
+export class DialogComponent {}
@@ -1508,3 +1508,1371 @@ fn f01_unresolved_internal_imports_are_reported_apart_from_external_ones() {
         "a bare specifier with no tsconfig alias and no node_modules is external, got: {external:?}"
     );
 }
+
+#[test]
+fn f23_component_usage_counts_bound_inputs_and_outputs() {
+    let report = run_fixture("f23-component-usage");
+
+    let usage = report["analysis"]["component_usage"].as_array().unwrap();
+    let card = usage
+        .iter()
+        .find(|u| u["component"] == "DsCardComponent")
+        .expect("DsCardComponent in component_usage");
+
+    assert_eq!(card["usage_count"], 3);
+    assert_eq!(card["by_project"]["shop"], 2);
+    assert_eq!(card["by_project"]["admin"], 1);
+
+    let counts = |key: &str| -> Vec<(String, i64)> {
+        card[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| {
+                (
+                    c["name"].as_str().unwrap().to_string(),
+                    c["count"].as_i64().unwrap(),
+                )
+            })
+            .collect()
+    };
+    // Static `title="..."` and `[title]` both bind the input.
+    assert!(counts("inputs").contains(&("title".to_string(), 3)));
+    assert!(counts("inputs").contains(&("elevation".to_string(), 1)));
+    // `[(open)]` binds the input and its `openChange` output.
+    assert!(counts("inputs").contains(&("open".to_string(), 1)));
+    assert!(counts("outputs").contains(&("openChange".to_string(), 1)));
+    assert!(counts("outputs").contains(&("closed".to_string(), 2)));
+
+    assert_eq!(card["unbound_inputs"], serde_json::json!(["variant"]));
+    assert_eq!(card["unbound_outputs"], serde_json::json!(["pinned"]));
+}
+
+#[test]
+fn f23_unrendered_component_has_no_removal_candidates() {
+    let report = run_fixture("f23-component-usage");
+
+    let admin = report["analysis"]["component_usage"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|u| u["component"] == "AdminComponent")
+        .unwrap();
+    assert_eq!(admin["usage_count"], 0);
+    assert!(admin["unbound_inputs"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn f24_migration_plan_is_ordered_leaves_first() {
+    let report = run_fixture("f24-ngmodule-migration");
+
+    let plan = report["analysis"]["migration"]["plan"].as_array().unwrap();
+    let order: Vec<&str> = plan
+        .iter()
+        .map(|step| step["module"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            "AdminRoutingModule",
+            "SharedFormsModule",
+            "SharedModule",
+            "AdminModule",
+            "OrdersModule",
+            "AppModule"
+        ]
+    );
+
+    // Only declarations keep SharedModule; re-exported modules, providers
+    // and bootstrap block the others.
+    let step = |name: &str| plan.iter().find(|step| step["module"] == name).unwrap();
+    assert_eq!(step("SharedFormsModule")["deletable"], false);
+    assert_eq!(
+        step("SharedFormsModule")["blockers"],
+        serde_json::json!([
+            "re-exports: CommonModule, FormsModule (add to every importer's imports)"
+        ])
+    );
+    assert_eq!(step("SharedModule")["deletable"], true);
+    assert_eq!(step("OrdersModule")["deletable"], false);
+    assert_eq!(step("AppModule")["deletable"], false);
+    assert_eq!(
+        step("OrdersModule")["depends_on"],
+        serde_json::json!(["SharedModule"])
+    );
+}
+
+#[test]
+fn f24_lazy_loaded_and_routing_modules_are_kept() {
+    let report = run_fixture("f24-ngmodule-migration");
+    let plan = report["analysis"]["migration"]["plan"].as_array().unwrap();
+    let blockers = |name: &str| -> Vec<String> {
+        let step = plan.iter().find(|step| step["module"] == name).unwrap();
+        assert_eq!(step["deletable"], false, "{name} must not be deletable");
+        step["blockers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b.as_str().unwrap().to_string())
+            .collect()
+    };
+
+    // AdminModule declares a single component, but AppModule's routes
+    // load it with `loadChildren`.
+    let admin = blockers("AdminModule");
+    assert_eq!(admin.len(), 1);
+    assert!(admin[0].starts_with("loadChildren target in "));
+    assert!(admin[0].contains("apps/shell/src/app/app.module.ts"));
+
+    assert!(blockers("AdminRoutingModule").contains(
+        &"forRoot/forChild imports: RouterModule (move to provideRouter or importProvidersFrom)"
+            .to_string()
+    ));
+    assert!(blockers("AppModule")
+        .iter()
+        .any(|b| b.starts_with("forRoot/forChild imports: RouterModule")));
+}
+
+#[test]
+fn f24_migration_derives_standalone_imports_from_templates() {
+    let report = run_fixture("f24-ngmodule-migration");
+
+    let orders = report["analysis"]["migration"]["plan"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|step| step["module"] == "OrdersModule")
+        .unwrap();
+    let row = orders["declarables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["name"] == "OrderRowComponent")
+        .unwrap();
+    assert_eq!(row["needs_standalone"], true);
+    assert_eq!(
+        row["imports"],
+        serde_json::json!(["FormatPipe", "HighlightDirective", "UiButtonComponent"])
+    );
+
+    let adoption = report["analysis"]["migration"]["adoption"]
+        .as_array()
+        .unwrap();
+    let ui = adoption.iter().find(|a| a["project"] == "ui").unwrap();
+    assert_eq!(ui["ratio"], 1.0);
+}
+
+#[test]
+fn cli_migration_prints_plan() {
+    let (code, stdout, _) = run_cli("f24-ngmodule-migration", &["migration"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("3. SharedModule (shared) — deletable after migration"));
+    assert!(stdout.contains("providers: OrdersStore"));
+}
+
+#[test]
+fn f25_selector_prefix_violations() {
+    let report = run_fixture("f25-selectors");
+
+    let violations: Vec<(&str, &str)> = report["analysis"]["selectors"]["prefix_violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| {
+            (
+                v["name"].as_str().unwrap(),
+                v["expected_prefix"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        violations,
+        vec![
+            ("LegacyButtonComponent", "lg"),
+            ("FancyCardComponent", "ui"),
+            ("HighlightDirective", "ui"),
+            ("PlainCardComponent", "ui"),
+        ]
+    );
+}
+
+#[test]
+fn f25_selector_collisions_exact_and_overlapping() {
+    let report = run_fixture("f25-selectors");
+
+    let collisions: Vec<(&str, &str, bool)> = report["analysis"]["selectors"]["collisions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| {
+            (
+                c["first"]["name"].as_str().unwrap(),
+                c["second"]["name"].as_str().unwrap(),
+                c["exact"].as_bool().unwrap(),
+            )
+        })
+        .collect();
+    // The two apps' `app-root`s never share a template, and a directive
+    // refining a selector (`ui-card[elevated]`, `button[...][uiTooltip]`) is
+    // meant to apply next to the component or directive it refines.
+    assert_eq!(
+        collisions,
+        vec![
+            ("LegacyButtonComponent", "UiButtonComponent", true),
+            ("LegacyButtonComponent", "UiPrimaryButtonComponent", false),
+            ("UiButtonComponent", "UiPrimaryButtonComponent", false),
+            ("UiTooltipCompatDirective", "UiTooltipDirective", true),
+        ]
+    );
+}
+
+#[test]
+fn cli_fail_on_selectors_exits_2() {
+    let (code, _, stderr) = run_cli("f25-selectors", &["selectors", "--fail-on", "selectors"]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("selector-collision:"));
+    assert!(stderr.contains("selector-prefix:"));
+}
+
+#[test]
+fn f26_render_tree_from_bootstrap_and_routes() {
+    let report = run_fixture("f26-render-tree");
+    let roots = report["analysis"]["render_tree"]["roots"]
+        .as_array()
+        .unwrap();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0]["app"], "portal");
+    assert_eq!(roots[0]["component"], "AppComponent");
+    assert_eq!(roots[0]["via"], "Bootstrap");
+
+    let children: Vec<(&str, &str)> = roots[0]["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| (c["component"].as_str().unwrap(), c["via"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        children,
+        vec![
+            ("ShellComponent", "Template"),
+            ("HomeComponent", "Route"),
+            ("OrdersComponent", "LazyRoute"),
+            ("AdminDashboardComponent", "LazyRoute"),
+        ]
+    );
+
+    let orders = &roots[0]["children"][2];
+    assert_eq!(orders["children"][0]["component"], "OrderDetailComponent");
+    assert_eq!(orders["children"][0]["route_path"], ":id");
+
+    // A self-rendering component is cut off, not expanded forever.
+    let tree_node = &roots[0]["children"][1]["children"][0];
+    assert_eq!(tree_node["component"], "TreeNodeComponent");
+    assert_eq!(tree_node["children"][0]["recursive"], true);
+    assert!(tree_node["children"][0]["children"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn f26_render_tree_flags_dead_branches() {
+    let report = run_fixture("f26-render-tree");
+    let unreachable = report["analysis"]["render_tree"]["unreachable"]
+        .as_array()
+        .unwrap();
+    assert_eq!(unreachable.len(), 1);
+    assert_eq!(unreachable[0]["name"], "LegacyRowComponent");
+    assert_eq!(unreachable[0]["rendered_by"][0], "LegacyPanelComponent");
+}
+
+#[test]
+fn f48_lazy_route_tables_resolve_per_import_and_stop_on_cycles() {
+    let report = run_fixture("f48-lazy-route-tables");
+    let roots = report["analysis"]["render_tree"]["roots"]
+        .as_array()
+        .unwrap();
+    assert_eq!(roots.len(), 1);
+
+    // Three tables export `routes`; each `loadChildren` picks the one its
+    // import resolves to (through the `c/index.ts` re-export for `c`), and
+    // `b.routes` loading `a.routes` back is cut off instead of recursing.
+    let children: Vec<(&str, &str)> = roots[0]["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| {
+            (
+                c["component"].as_str().unwrap(),
+                c["file"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(children.len(), 3);
+    assert_eq!(children[0].0, "AComponent");
+    assert_eq!(children[1].0, "BComponent");
+    assert_eq!(children[2].0, "CComponent");
+    assert!(children[2].1.ends_with("app/c/c.component.ts"));
+}
+
+#[test]
+fn f49_shared_subtrees_are_expanded_once() {
+    let report = run_fixture("f49-render-tree-shared");
+    let root = &report["analysis"]["render_tree"]["roots"][0];
+
+    // Left and Right both render Shared: the second occurrence refers back
+    // instead of expanding Leaf again.
+    let left_shared = &root["children"][0]["children"][0];
+    assert_eq!(left_shared["component"], "SharedComponent");
+    assert!(left_shared.get("repeated").is_none());
+    assert_eq!(left_shared["children"][0]["component"], "LeafComponent");
+
+    let right_shared = &root["children"][1]["children"][0];
+    assert_eq!(right_shared["component"], "SharedComponent");
+    assert_eq!(right_shared["repeated"], true);
+    assert!(right_shared["children"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn cli_render_tree_depth_and_mermaid() {
+    let (code, stdout, _) = run_cli(
+        "f26-render-tree",
+        &["render-tree", "--format", "mermaid", "--depth", "1"],
+    );
+    assert_eq!(code, 0);
+    assert!(stdout.starts_with("graph TD"));
+    assert!(stdout.contains("\"ShellComponent\""));
+    assert!(stdout.contains("-. \"/orders\" .->"));
+    assert!(!stdout.contains("NavComponent"));
+
+    let (_, stdout, _) = run_cli(
+        "f26-render-tree",
+        &["render-tree", "--format", "json", "--project", "ui"],
+    );
+    let tree: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(tree["roots"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn f27_unused_ngmodule_imports() {
+    let report = run_fixture("f27-module-imports");
+    let unused: Vec<(&str, &str)> = report["analysis"]["unused"]["unused_module_imports"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|u| (u["module"].as_str().unwrap(), u["import"].as_str().unwrap()))
+        .collect();
+    // SharedModule (rendered selector), DataModule (injected provider) and
+    // UiBundleModule (re-exported pipe used by ReportsComponent) all
+    // contribute; CommonModule/BrowserModule are third-party and not judged.
+    // FormsBundleModule re-exports third-party modules and
+    // OrdersRoutingModule registers `RouterModule.forChild(...)`: both are
+    // kept.
+    assert_eq!(unused, vec![("AppModule", "UtilsModule")]);
+}
+
+#[test]
+fn f27_configured_imports_are_recorded() {
+    let report = run_fixture("f27-module-imports");
+    let routing = report["modules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|m| m["name"] == "OrdersRoutingModule")
+        .unwrap();
+    assert_eq!(
+        routing["imports_idents"],
+        serde_json::json!(["RouterModule"])
+    );
+    assert_eq!(
+        routing["configured_imports"],
+        serde_json::json!(["RouterModule"])
+    );
+}
+
+#[test]
+fn f27_reexport_only_modules() {
+    let report = run_fixture("f27-module-imports");
+    let reexports = report["analysis"]["unused"]["reexport_modules"]
+        .as_array()
+        .unwrap();
+    assert_eq!(reexports.len(), 1);
+    assert_eq!(reexports[0]["module"], "UiBundleModule");
+    assert_eq!(
+        reexports[0]["reexports"],
+        serde_json::json!(["SharedModule", "UtilsModule"])
+    );
+}
+
+#[test]
+fn f28_unused_providers_per_scope() {
+    let report = run_fixture("f28-providers");
+    let unused: Vec<(&str, &str, &str)> = report["analysis"]["providers"]["unused"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| {
+            (
+                p["provider"].as_str().unwrap(),
+                p["scope"].as_str().unwrap(),
+                p["owner"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    // CartService is injected by a child component, LoggerService only by
+    // CartService, and the route token via `@Inject(REPORT_PAGE_SIZE)`.
+    // Angular's own tokens (NG_VALUE_ACCESSOR, HTTP_INTERCEPTORS,
+    // ErrorHandler) and multi-provided PLUGINS are not flagged.
+    assert_eq!(
+        unused,
+        vec![
+            ("MetricsService", "Route", "admin"),
+            ("AuditService", "Component", "CartComponent"),
+        ]
+    );
+}
+
+#[test]
+fn f28_root_service_provided_again() {
+    let report = run_fixture("f28-providers");
+    let duplicates = report["analysis"]["providers"]["duplicates"]
+        .as_array()
+        .unwrap();
+    // HttpModule provides SessionService too, but an eagerly imported
+    // module's providers are the root injector's; lazy LegacyModule's are not.
+    assert_eq!(duplicates.len(), 2);
+    assert_eq!(duplicates[0]["service"], "SessionService");
+    assert_eq!(duplicates[0]["provided_in"], "root");
+    assert_eq!(duplicates[0]["owner"], "CartComponent");
+    assert_eq!(duplicates[1]["owner"], "LegacyModule");
+    assert_eq!(duplicates[1]["scope"], "Module");
+}
+
+#[test]
+fn cli_fail_on_providers_exits_2() {
+    let (code, _, stderr) = run_cli("f28-providers", &["providers", "--fail-on", "providers"]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("provider-unused:"));
+    assert!(stderr.contains("provider-duplicate:"));
+}
+
+#[test]
+fn f29_dynamic_creation_counts_as_render() {
+    let report = run_fixture("f29-dynamic-components");
+    let mut dynamic: Vec<(&str, &str)> = report["template_usages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|usage| usage["via"] == "Dynamic")
+        .map(|usage| {
+            (
+                usage["component"].as_str().unwrap(),
+                usage["target"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    dynamic.sort();
+    // dialog.open, createComponent, ngComponentOutlet, ComponentPortal and
+    // the `openSidePanel` opener configured in nx-analyzer.json.
+    assert_eq!(
+        dynamic,
+        vec![
+            ("DashboardComponent", "SummaryCardComponent"),
+            ("HostComponent", "ChartComponent"),
+            ("OrdersComponent", "ConfirmDialogComponent"),
+            ("OrdersComponent", "SidePanelComponent"),
+            ("TooltipService", "TooltipComponent"),
+        ]
+    );
+    assert!(unused_names(&report, "declared_not_rendered").is_empty());
+    assert!(!unused_names(&report, "unused_exports")
+        .iter()
+        .any(|name| name.ends_with("Component")));
+}
+
+#[test]
+fn f29_dynamic_children_in_render_tree() {
+    let (code, stdout, stderr) = run_cli("f29-dynamic-components", &["render-tree"]);
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert!(stdout.contains("ConfirmDialogComponent"));
+    assert!(stdout.contains("ChartComponent"));
+    // Created by a service: neither in the tree nor reported unreachable.
+    assert!(!stdout.contains("TooltipComponent"));
+}
+
+#[test]
+fn f30_host_directives_follow_host_renders() {
+    let report = run_fixture("f30-host-directives");
+    let composed: Vec<(&str, &Value)> = report["template_usages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|usage| usage["via"] == "HostDirective")
+        .map(|usage| (usage["target"].as_str().unwrap(), &usage["bindings"][0]))
+        .collect();
+    let names: Vec<&str> = composed.iter().map(|(name, _)| *name).collect();
+    assert_eq!(
+        names,
+        vec!["HighlightDirective", "LogDirective", "TrackClickDirective"]
+    );
+    // `tone` is forwarded as HighlightDirective's `color`; LogDirective's
+    // `logLevel` is forwarded through TrackClickDirective.
+    assert_eq!(composed[0].1["inputs"], serde_json::json!(["color"]));
+    assert_eq!(composed[1].1["inputs"], serde_json::json!(["logLevel"]));
+    assert_eq!(composed[2].1["outputs"], serde_json::json!(["tracked"]));
+
+    // Applied only by a component nothing renders.
+    assert_eq!(
+        unused_names(&report, "declared_not_rendered"),
+        vec!["FocusDirective"]
+    );
+}
+
+#[test]
+fn f31_template_members_unused_and_missing() {
+    let report = run_fixture("f31-template-members");
+    let members = &report["analysis"]["template_members"];
+    let unused: Vec<(&str, &str)> = members["unused"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| {
+            (
+                m["member"].as_str().unwrap(),
+                m["confidence"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    // Inputs, lifecycle hooks, @HostListener methods, `host` bindings,
+    // members read through `this` and private fields are all left alone.
+    assert_eq!(
+        unused,
+        vec![("legacyBanner", "High"), ("oldHelper", "Medium")]
+    );
+
+    let missing: Vec<(&str, &str)> = members["missing"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| {
+            (
+                m["component"].as_str().unwrap(),
+                m["member"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    // `tag`, `box` are template locals; AdminProfileComponent inherits.
+    assert_eq!(
+        missing,
+        vec![
+            ("ProfileComponent", "avatarUrl"),
+            ("ProfileComponent", "rename")
+        ]
+    );
+}
+
+#[test]
+fn cli_fail_on_members_exits_2() {
+    let (code, stdout, stderr) =
+        run_cli("f31-template-members", &["members", "--fail-on", "members"]);
+    assert_eq!(code, 2);
+    assert!(stdout.contains("ProfileComponent.avatarUrl"));
+    assert!(stderr.contains("member-missing:"));
+    assert!(stderr.contains("member-unused:"));
+}
+
+#[test]
+fn f32_orphan_assets_follow_references() {
+    let report = run_fixture("f32-orphan-assets");
+    let orphans: Vec<(String, &str)> = report["analysis"]["unused"]["orphan_assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|asset| {
+            let file = asset["file"].as_str().unwrap();
+            let file = file.split("apps/shop/src/").nth(1).unwrap().to_string();
+            (file, asset["kind"].as_str().unwrap())
+        })
+        .collect();
+    // Kept alive: index/styles/assets from project.json, the `_index` and
+    // partial chain under styles.scss, `url()` in a partial, templateUrl,
+    // styleUrl, `src`/`srcset` in a referenced template and a script import.
+    // The partial and font below are referenced only by an orphan.
+    assert_eq!(
+        orphans,
+        vec![
+            ("app/_legacy-vars.scss".to_string(), "Style"),
+            ("app/legacy.scss".to_string(), "Style"),
+            ("app/promo-banner.component.html".to_string(), "Template"),
+            ("fonts/retired.woff2".to_string(), "Font"),
+            ("images/old-promo.png".to_string(), "Image"),
+        ]
+    );
+}
+
+#[test]
+fn f32_orphan_assets_gate_unused() {
+    let (code, stdout, stderr) = run_cli("f32-orphan-assets", &["unused", "--fail-on", "unused"]);
+    assert_eq!(code, 2);
+    assert!(stdout.contains("Orphan templates, styles and assets (5)"));
+    assert!(stderr.contains("orphan-asset:"));
+}
+
+#[test]
+fn f33_style_imports_are_style_dependencies() {
+    let report = run_fixture("f33-style-dependencies");
+    let deps: Vec<(&str, &str, &str)> = report["analysis"]["stats"]["dependencies"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| {
+            (
+                d["from"].as_str().unwrap(),
+                d["to"].as_str().unwrap(),
+                d["kind"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    // Relative, includePaths, `styleAliases` and tsconfig `paths` edges;
+    // `sass:math` is a built-in and `@fix/ui` a TypeScript entry point,
+    // neither a style dependency.
+    assert_eq!(
+        deps,
+        vec![
+            ("brand", "theme", "Style"),
+            ("shop", "brand", "Style"),
+            ("shop", "theme", "Style"),
+            ("shop", "ui", "Code"),
+            ("ui", "theme", "Style"),
+            ("ui", "tokens", "Style"),
+        ]
+    );
+
+    let violations = report["analysis"]["boundary_violations"]
+        .as_array()
+        .unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0]["to"], "tokens");
+    assert_eq!(violations[0]["kind"], "Style");
+
+    // Partials reached through includePaths and aliases are not orphans.
+    assert!(report["analysis"]["unused"]["orphan_assets"].is_null());
+}
+
+#[test]
+fn f34_translation_keys_against_catalogs() {
+    let report = run_fixture("f34-translations");
+    let translations = &report["analysis"]["translations"];
+    let keys = |list: &str| -> Vec<String> {
+        translations[list]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| match t["language"].as_str() {
+                Some(language) => format!("{}:{language}", t["key"].as_str().unwrap()),
+                None => t["key"].as_str().unwrap().to_string(),
+            })
+            .collect()
+    };
+    // Pipe, `[translate]`, `*transloco` with `read:`, service and `translate()`
+    // lookups — the lib's keys count because the app depends on it. The spec
+    // file's lookup does not keep `legacy.banner` alive.
+    assert_eq!(keys("unused"), vec!["legacy.banner"]);
+    assert_eq!(
+        keys("missing"),
+        vec!["profile.avatar:en", "home.subtitle:fr", "profile.avatar:fr"]
+    );
+    assert_eq!(
+        keys("dynamic_only"),
+        vec!["errors.forbidden", "errors.notFound"]
+    );
+}
+
+#[test]
+fn f50_keys_defined_in_any_catalog_in_scope_are_not_missing() {
+    let report = run_fixture("f50-translation-catalogs");
+    let missing: Vec<(&str, &str, &str)> = report["analysis"]["translations"]["missing"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| {
+            (
+                m["key"].as_str().unwrap(),
+                m["language"].as_str().unwrap(),
+                m["project"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    // The app's catalog lacks `dialog.close`, the lib's defines it: both load
+    // into the app. `dialog.retry` has no French anywhere — reported once,
+    // against the lib's catalog.
+    assert_eq!(missing, vec![("dialog.retry", "fr", "ui")]);
+}
+
+#[test]
+fn f34_translations_gate() {
+    let (code, stdout, stderr) = run_cli(
+        "f34-translations",
+        &["translations", "--fail-on", "translations"],
+    );
+    assert_eq!(code, 2);
+    assert!(stdout.contains("Translation keys nothing looks up (1)"));
+    assert!(stderr.contains("translation-missing:"));
+    assert!(!stderr.contains("errors.notFound"));
+}
+
+#[test]
+fn f35_ngrx_dispatch_handlers_and_selectors() {
+    let report = run_fixture("f35-ngrx");
+    let ngrx = &report["analysis"]["ngrx"];
+    let names = |list: &str, field: &str| -> Vec<String> {
+        ngrx[list]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry[field].as_str().unwrap().to_string())
+            .collect()
+    };
+    // Dispatched by the component, or emitted by an effect; the spec file's
+    // dispatch and select do not count.
+    assert_eq!(
+        names("undispatched_actions", "name"),
+        vec!["UsersActions.clearUsers", "legacyRefresh"]
+    );
+    assert_eq!(
+        names("dead_handlers", "owner"),
+        vec!["UsersEffects.refresh$", "usersReducer"]
+    );
+    // `selectUsers` and `selectUsersState` feed selected selectors;
+    // `selectUserAt` is a factory called inside `select(...)`.
+    assert_eq!(
+        names("unselected_selectors", "name"),
+        vec!["selectError", "selectFilter"]
+    );
+}
+
+#[test]
+fn f35_ngrx_gate() {
+    let (code, stdout, stderr) = run_cli("f35-ngrx", &["ngrx", "--fail-on", "ngrx"]);
+    assert_eq!(code, 2);
+    assert!(stdout.contains("Handlers that never run (2)"));
+    assert!(stderr.contains("ngrx-selector:"));
+}
+
+#[test]
+fn f36_react_hook_usage() {
+    let report = run_fixture("f36-react-hooks");
+    let hooks = report["analysis"]["react_hooks"].as_array().unwrap();
+    let hook = |name: &str| hooks.iter().find(|h| h["hook"] == name).unwrap();
+
+    // Imported through the barrel, renamed in admin, same-file in shop.
+    assert_eq!(hook("useCart")["call_count"], 2);
+    assert_eq!(hook("useCart")["by_project"]["admin"], 1);
+    assert_eq!(hook("useCart")["by_project"]["shop"], 1);
+    assert_eq!(hook("useTitle")["call_count"], 1);
+    // Called from a live hook.
+    assert_eq!(hook("useMediaQuery")["call_count"], 1);
+    assert!(hook("useMediaQuery")["only_from_unused_hooks"].is_null());
+    // Spec calls are not adoption; a hook called only by an unused hook is
+    // dead with it.
+    assert_eq!(hook("useDebounce")["call_count"], 0);
+    assert_eq!(hook("useLegacyStorage")["call_count"], 0);
+    assert_eq!(hook("useStorage")["only_from_unused_hooks"], true);
+
+    // Built-in hooks are not recorded as calls.
+    let files = report["source_files"].as_array().unwrap();
+    assert!(files
+        .iter()
+        .flat_map(|f| f["hook_calls"].as_array().into_iter().flatten())
+        .all(|call| call["hook"] != "useState"));
+
+    let unused: Vec<(&str, &str)> = report["analysis"]["unused"]["unused_exports"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|u| (u["name"].as_str().unwrap(), u["kind"].as_str().unwrap()))
+        .collect();
+    assert!(unused.contains(&("useLegacyStorage", "ReactHook")));
+}
+
+#[test]
+fn f37_react_declared_props() {
+    let report = run_fixture("f37-react-props");
+    let props = |name: &str| -> Vec<(String, bool)> {
+        find(&report, "react_components", name)["props"]
+            .as_array()
+            .unwrap_or_else(|| panic!("{name} has props"))
+            .iter()
+            .map(|p| {
+                (
+                    p["name"].as_str().unwrap().to_string(),
+                    p["optional"].as_bool().unwrap(),
+                )
+            })
+            .collect()
+    };
+
+    // Interface, with a default value making `variant` optional.
+    assert!(props("Button").contains(&("label".into(), false)));
+    assert!(props("Button").contains(&("variant".into(), true)));
+    // Inline type literal; `max` has a default.
+    assert_eq!(
+        props("Badge"),
+        vec![("count".into(), false), ("max".into(), true)]
+    );
+    // React.FC<Alias>, forwardRef<_, Interface> with a local base.
+    assert!(props("Avatar").contains(&("src".into(), false)));
+    assert!(props("Dialog").contains(&("id".into(), true)));
+    // Untyped destructuring: names only.
+    assert_eq!(
+        props("Tag"),
+        vec![("text".into(), true), ("tone".into(), true)]
+    );
+    // Type imported from another file: unknown.
+    assert!(find(&report, "react_components", "Panel")["props"].is_null());
+
+    let usage = report["analysis"]["react_usage"].as_array().unwrap();
+    let usage = |name: &str| usage.iter().find(|u| u["component"] == name).unwrap();
+    let unpassed = |name: &str| -> Vec<&str> {
+        usage(name)["unpassed_props"]
+            .as_array()
+            .map(|props| props.iter().map(|p| p.as_str().unwrap()).collect())
+            .unwrap_or_default()
+    };
+    assert_eq!(unpassed("Button"), vec!["disabled"]);
+    assert_eq!(unpassed("Badge"), vec!["max"]);
+    // Children between the tags pass `children`.
+    assert_eq!(unpassed("Dialog"), vec!["id"]);
+    // A spread may pass anything.
+    assert!(unpassed("Avatar").is_empty());
+    assert!(unpassed("Panel").is_empty());
+
+    let missing = |name: &str| -> Vec<(&str, &str)> {
+        usage(name)["missing_required_props"]
+            .as_array()
+            .map(|missing| {
+                missing
+                    .iter()
+                    .map(|m| (m["prop"].as_str().unwrap(), m["file"].as_str().unwrap()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    assert_eq!(missing("Button").len(), 1);
+    assert_eq!(missing("Button")[0].0, "label");
+    assert!(missing("Button")[0].1.ends_with("apps/admin/src/main.tsx"));
+    // The spread usage is skipped, the admin one is not.
+    assert_eq!(missing("Avatar").len(), 1);
+    assert!(missing("Badge").is_empty());
+    assert!(missing("Dialog").is_empty());
+}
+
+#[test]
+fn f38_react_member_tags_resolve_to_declaring_components() {
+    let report = run_fixture("f38-react-compound");
+    let usage = report["analysis"]["react_usage"].as_array().unwrap();
+    let count = |name: &str| {
+        usage
+            .iter()
+            .find(|u| u["component"] == name)
+            .unwrap_or_else(|| panic!("{name} in react_usage"))["usage_count"]
+            .as_i64()
+            .unwrap()
+    };
+
+    // Object.assign: `<Form>` renders the root, members their components —
+    // also through a namespace import (`<UI.Form.Field>`).
+    assert_eq!(count("FormRoot"), 1);
+    assert_eq!(count("FormField"), 2);
+    assert_eq!(count("FormLabel"), 1);
+    // Static property.
+    assert_eq!(count("Tabs"), 1);
+    assert_eq!(count("Tab"), 1);
+    // Object literal, imported under an alias.
+    assert_eq!(count("CardHeader"), 1);
+    assert_eq!(count("CardBody"), 1);
+    // Namespace import.
+    assert_eq!(count("Button"), 1);
+
+    // Lowercase members are DOM elements.
+    let app = report["source_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["path"].as_str().unwrap().ends_with("app.tsx"))
+        .unwrap();
+    assert!(app["jsx_usages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|u| u["component"] != "motion.div"));
+
+    let (code, stdout, _) = run_cli("f38-react-compound", &["usages", "FormField", "--json"]);
+    assert_eq!(code, 0);
+    let usage: Value = serde_json::from_str(&stdout).unwrap();
+    // 2 JSX renders + the import in form.tsx.
+    assert_eq!(usage["declarations"][0]["total_usages"], 3);
+}
+
+#[test]
+fn f39_react_context_providers_and_consumers() {
+    let report = run_fixture("f39-react-context");
+    let contexts = report["analysis"]["react_contexts"].as_array().unwrap();
+    let context = |name: &str| contexts.iter().find(|c| c["context"] == name).unwrap();
+    let names = |value: &Value| -> Vec<String> {
+        value
+            .as_array()
+            .map(|sites| {
+                sites
+                    .iter()
+                    .map(|s| s["component"].as_str().unwrap().to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    // Provided by a wrapper component; read directly, through a custom
+    // hook and with `<X.Consumer>`. The spec file's provider does not count.
+    let theme = context("ThemeContext");
+    assert_eq!(names(&theme["providers"]), vec!["ThemeProvider"]);
+    assert_eq!(
+        names(&theme["consumers"]),
+        vec!["Card", "Footer", "Sidebar"]
+    );
+    // Sidebar renders next to `<ThemeProvider>`, not inside it.
+    assert_eq!(names(&theme["unprovided_consumers"]), vec!["Sidebar"]);
+    assert!(theme["never_consumed"].is_null());
+
+    // React 19 `<X value>` provides; nothing reads it.
+    let locale = context("LocaleContext");
+    assert_eq!(names(&locale["providers"]), vec!["App"]);
+    assert_eq!(locale["never_consumed"], true);
+
+    // `use(X)` with no provider anywhere.
+    let session = context("SessionContext");
+    assert_eq!(names(&session["unprovided_consumers"]), vec!["Header"]);
+}
+
+#[test]
+fn f40_nextjs_pages_router_files_are_entry_points() {
+    let report = run_fixture("f40-next-pages");
+
+    // Detected through next.config.js (site) and package.json (legacy);
+    // `tool` has a pages/ directory but is not a Next.js app.
+    let orphans: Vec<&str> = report["analysis"]["unused"]["orphan_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f.as_str().unwrap())
+        .collect();
+    assert_eq!(orphans.len(), 1, "got: {orphans:?}");
+    assert!(orphans[0].ends_with("apps/tool/src/pages/report.ts"));
+
+    // Default exports, data loaders, `config` and `reportWebVitals` are read
+    // by Next.js; a helper exported from a page is not.
+    let unused = unused_names(&report, "unused_exports");
+    assert_eq!(unused, vec!["unusedHelper", "buildReport"]);
+    // Used by getStaticProps in its own file.
+    assert_eq!(unused_names(&report, "export_only"), vec!["formatTitle"]);
+}
+
+#[test]
+fn f41_next_client_boundaries() {
+    let report = run_fixture("f41-next-client-boundaries");
+    let directive = |name: &str| {
+        report["source_files"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|file| file["path"].as_str().unwrap().ends_with(name))
+            .unwrap()["directive"]
+            .clone()
+    };
+    assert_eq!(directive("components/dashboard.tsx"), "use client");
+    assert_eq!(directive("app/actions.ts"), "use server");
+    assert!(directive("app/page.tsx").is_null());
+
+    let violations = report["analysis"]["client_boundaries"].as_array().unwrap();
+    let found: Vec<(&str, &str)> = violations
+        .iter()
+        .map(|v| (v["kind"].as_str().unwrap(), v["target"].as_str().unwrap()))
+        .collect();
+    // The server page may use `fs`; the `'use server'` file and the type-only
+    // imports (`User` from db.ts, `Stats` from fs) keep server code out of
+    // the bundle; `path` has a browser fallback.
+    assert_eq!(
+        found,
+        vec![
+            ("InlineServerAction", "NoteForm"),
+            ("ServerOnly", "server-only"),
+            ("NodeBuiltin", "node:fs"),
+            ("NodeBuiltin", "child_process"),
+        ]
+    );
+    let chain = |index: usize| -> Vec<String> {
+        violations[index]["chain"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|file| {
+                let file = file.as_str().unwrap();
+                file.rsplit('/').next().unwrap().to_string()
+            })
+            .collect()
+    };
+    assert_eq!(chain(0), vec!["note-form.tsx"]);
+    assert_eq!(chain(1), vec!["dashboard.tsx", "format.ts", "config.ts"]);
+    assert_eq!(chain(3), vec!["dashboard.tsx", "chart.tsx", "logger.ts"]);
+    assert_eq!(violations[3]["project"], "charts");
+}
+
+#[test]
+fn f41_client_boundaries_gate() {
+    let (code, stdout, stderr) = run_cli(
+        "f41-next-client-boundaries",
+        &["client-boundaries", "--fail-on", "client-boundaries"],
+    );
+    assert_eq!(code, 2);
+    assert!(stdout.contains("Server code in client bundles (4)"));
+    assert!(stderr.contains("client-boundary:"));
+}
+
+#[test]
+fn f42_nest_modules_controllers_and_routes() {
+    let report = run_fixture("f42-nest");
+
+    let users = find(&report, "nest_modules", "UsersModule");
+    assert_eq!(users["controllers"], serde_json::json!(["UsersController"]));
+    assert_eq!(
+        users["providers"],
+        serde_json::json!([
+            { "token": "UsersService" },
+            { "token": "USERS_REPOSITORY", "class": "InMemoryUsersRepository" }
+        ])
+    );
+    let app = find(&report, "nest_modules", "AppModule");
+    // `AuthModule.forRoot(...)` imports AuthModule.
+    assert_eq!(
+        app["imports_idents"],
+        serde_json::json!(["AuthModule", "UsersModule"])
+    );
+    assert_eq!(find(&report, "nest_modules", "AuthModule")["global"], true);
+
+    let routes: Vec<String> = report["analysis"]["nest_routes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| {
+            format!(
+                "{} {} {}.{} {}",
+                r["method"].as_str().unwrap(),
+                r["path"].as_str().unwrap(),
+                r["controller"].as_str().unwrap(),
+                r["handler"].as_str().unwrap(),
+                r["module"].as_str().unwrap_or("-")
+            )
+        })
+        .collect();
+    assert_eq!(
+        routes,
+        vec![
+            "GET /health HealthController.check AppModule",
+            "ALL /legacy/* LegacyController.fallback -",
+            "GET /status StatusController.status AppModule",
+            "GET /users UsersController.findAll UsersModule",
+            "POST /users UsersController.create UsersModule",
+            "DELETE /users/:id UsersController.remove UsersModule",
+            "GET /users/:id UsersController.findOne UsersModule",
+            "DELETE /users/:id/hard UsersController.remove UsersModule",
+        ]
+    );
+
+    // Registered in a module: alive, even when only its own file names it.
+    // An injectable no module provides, and a controller no module lists,
+    // are dead.
+    assert_eq!(
+        unused_names(&report, "unused_exports"),
+        vec!["LegacyController", "AuditService"]
+    );
+    assert!(!unused_names(&report, "export_only").contains(&"StatusController".to_string()));
+    let legacy = &report["analysis"]["unused"]["unused_exports"][0];
+    assert_eq!(legacy["kind"], "Controller");
+}
+
+#[test]
+fn f43_vue_single_file_components() {
+    let report = run_fixture("f43-vue");
+
+    let app = find(&report, "vue_components", "App");
+    assert_eq!(app["setup"], true);
+    assert_eq!(
+        app["template_components"],
+        serde_json::json!(["CartSummary", "ProductList"])
+    );
+    // Options API: the anonymous `export default` takes the file's name.
+    let summary = find(&report, "vue_components", "CartSummary");
+    assert!(summary.get("setup").is_none());
+
+    // `<cart-summary>` and `<ProductList>` resolve through the imports;
+    // `<product-card>` is never imported and resolves by name.
+    let renders: Vec<String> = report["template_usages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|usage| usage["via"] == "Tag")
+        .map(|usage| {
+            format!(
+                "{} -> {}",
+                usage["component"].as_str().unwrap(),
+                usage["target"].as_str().unwrap()
+            )
+        })
+        .collect();
+    assert_eq!(
+        renders,
+        vec![
+            "App -> CartSummary",
+            "App -> ProductList",
+            "ProductList -> ProductCard"
+        ]
+    );
+
+    // Composables and stores imported by SFCs are used — `formatPrice` only
+    // by the template.
+    let app_file = report["source_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|file| file["path"].as_str().unwrap().ends_with("src/App.vue"))
+        .unwrap();
+    assert!(app_file["used_import_names"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!("formatPrice")));
+    assert_eq!(
+        unused_names(&report, "unused_exports"),
+        vec!["LegacyBanner", "useWishlist"]
+    );
+    assert_eq!(
+        report["analysis"]["unused"]["unused_exports"][0]["kind"],
+        "VueComponent"
+    );
+    let orphans = report["analysis"]["unused"]["orphan_files"]
+        .as_array()
+        .unwrap();
+    assert_eq!(orphans.len(), 1);
+    assert!(orphans[0].as_str().unwrap().ends_with("LegacyBanner.vue"));
+}
+
+#[test]
+fn f44_web_components_across_frameworks() {
+    let report = run_fixture("f44-web-components");
+
+    // `@customElement('ui-button')` and `customElements.define('ui-badge', …)`.
+    assert_eq!(
+        find(&report, "custom_elements", "UiButton")["tag"],
+        "ui-button"
+    );
+    assert_eq!(
+        find(&report, "custom_elements", "UiBadge")["tag"],
+        "ui-badge"
+    );
+
+    let usage = |tag: &str| {
+        report["analysis"]["custom_element_usage"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|usage| usage["tag"] == tag)
+            .unwrap()
+            .clone()
+    };
+    let button = usage("ui-button");
+    assert_eq!(button["usage_count"], 3);
+    assert_eq!(
+        button["by_framework"],
+        serde_json::json!({ "angular": 2, "jsx": 1 })
+    );
+    assert_eq!(
+        button["by_project"],
+        serde_json::json!({ "admin": 2, "portal": 1 })
+    );
+    // Rendered by another element's Lit template.
+    assert_eq!(
+        usage("ui-badge")["by_framework"],
+        serde_json::json!({ "lit": 1 })
+    );
+    assert_eq!(usage("ui-legacy")["usage_count"], 0);
+
+    // Used by tag only — never imported by name — yet alive.
+    assert_eq!(unused_names(&report, "unused_exports"), vec!["UiLegacy"]);
+    assert_eq!(
+        report["analysis"]["unused"]["unused_exports"][0]["kind"],
+        "CustomElement"
+    );
+}
+
+#[test]
+fn f45_workers_and_url_references_are_lazy_edges() {
+    let report = run_fixture("f45-workers");
+    let file_name = |path: &Value| {
+        path.as_str()
+            .unwrap()
+            .rsplit('/')
+            .next()
+            .unwrap()
+            .to_string()
+    };
+
+    // The worker, the service worker and its `import.meta.url` asset are
+    // referenced; their dependencies are reached through them.
+    let orphans: Vec<String> = report["analysis"]["unused"]["orphan_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(file_name)
+        .collect();
+    assert_eq!(orphans, vec!["stray.worker.ts"]);
+    let orphan_assets: Vec<String> = report["analysis"]["unused"]["orphan_assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|asset| file_name(&asset["file"]))
+        .collect();
+    assert_eq!(orphan_assets, vec!["unused.svg"]);
+    assert_eq!(
+        unused_names(&report, "unused_exports"),
+        vec!["unusedSummary"]
+    );
+
+    let dashboard = report["source_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|file| file["path"].as_str().unwrap().ends_with("app/dashboard.ts"))
+        .unwrap();
+    let kinds: Vec<(&str, &str)> = dashboard["url_imports"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|import| {
+            (
+                import["source"].as_str().unwrap(),
+                import["imported_item"]["import_kind"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![("./heavy.worker", "Worker"), ("../assets/logo.svg", "Url")]
+    );
+
+    // A distinct edge kind in the graph: listed under `url` as well as `to`.
+    let edge = report["import_graph"]["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|edge| edge["from"].as_str().unwrap().ends_with("src/main.ts"))
+        .unwrap();
+    let url: Vec<String> = edge["url"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(file_name)
+        .collect();
+    assert_eq!(url, vec!["sw.ts"]);
+    assert_eq!(edge["to"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn f46_non_script_imports_are_graph_nodes() {
+    let report = run_fixture("f46-non-script-imports");
+    let file_name = |path: &Value| {
+        path.as_str()
+            .unwrap()
+            .rsplit('/')
+            .next()
+            .unwrap()
+            .to_string()
+    };
+
+    // `?react` / `?raw` are stripped: nothing is left unresolved.
+    let chart = report["source_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|file| file["path"].as_str().unwrap().ends_with("chart.tsx"))
+        .unwrap();
+    assert!(chart["unresolved_imports"].is_null());
+    assert_eq!(chart["imports"].as_array().unwrap().len(), 4);
+
+    let nodes: Vec<String> = report["import_graph"]["non_script_nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(file_name)
+        .collect();
+    assert_eq!(
+        nodes,
+        vec!["chart.module.css", "defaults.json", "grid.svg", "logo.svg"]
+    );
+
+    // The only unreferenced module is the JSON nobody imports. Translation
+    // catalogs and tool configuration are not JSON modules.
+    let orphans: Vec<(String, &str)> = report["analysis"]["unused"]["orphan_assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|asset| (file_name(&asset["file"]), asset["kind"].as_str().unwrap()))
+        .collect();
+    assert_eq!(orphans, vec![("legacy.json".to_string(), "Json")]);
+}
+
+#[test]
+fn f47_css_module_classes() {
+    let report = run_fixture("f47-css-modules");
+    let file_name = |path: &Value| {
+        path.as_str()
+            .unwrap()
+            .rsplit('/')
+            .next()
+            .unwrap()
+            .to_string()
+    };
+    let css_modules = &report["analysis"]["css_modules"];
+
+    // `.base` is composed, `.card-footer` read as `cardFooter`, `.sr-only`
+    // comes from a `@use`d partial; `button.module.css` is spread, so any
+    // of its classes may be read.
+    let unused: Vec<(String, &str)> = css_modules["unused"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| (file_name(&c["file"]), c["class"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        unused,
+        vec![
+            ("badge.module.css".to_string(), "dot"),
+            ("card.module.scss".to_string(), "card__body"),
+            ("card.module.scss".to_string(), "unused"),
+        ]
+    );
+
+    let missing = css_modules["missing"].as_array().unwrap();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0]["class"], "sidebar");
+    assert_eq!(file_name(&missing[0]["file"]), "card.tsx");
+    assert_eq!(file_name(&missing[0]["module"]), "card.module.scss");
+}
+
+#[test]
+fn f47_css_modules_gate() {
+    let (code, stdout, stderr) = run_cli(
+        "f47-css-modules",
+        &["css-modules", "--fail-on", "css-modules"],
+    );
+    assert_eq!(code, 2);
+    assert!(stdout.contains("CSS module classes nothing reads (3)"));
+    assert!(stderr.contains("css-class-missing:"));
+}